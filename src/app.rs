@@ -1,23 +1,49 @@
 use std::{
-    sync::{atomic::AtomicBool, Arc},
-    thread, time,
+    sync::{atomic::AtomicBool, Arc, Mutex},
+    thread,
 };
 
 use anyhow::{Context as _, Result};
 use crossbeam::channel::{bounded, Receiver, Sender};
 
+use ratatui::layout::Direction;
+
 use crate::{
-    cmd::Command,
+    cmd::{Command, SplitDirection},
+    config::Config,
+    features::preset::Presets,
     message::Message,
-    workers::{KubeWorker, Render, Tick, UserInput},
+    session::Session,
+    workers::{
+        interval::IntervalConfig, kube::KubeWorkerConfig, KubeWorker, Render, Tick, UserInput,
+    },
 };
 
 pub struct App;
 
 impl App {
-    pub fn run(cmd: Command) -> Result<()> {
-        let split_direction = cmd.split_direction();
-        let kube_worker_config = cmd.kube_worker_config();
+    /// 終了したときのセッションを返す
+    pub fn run(
+        cmd: Command,
+        config: Config,
+        presets: Presets,
+        session: Session,
+    ) -> Result<Session> {
+        // コマンドライン引数で一時的に指定した値はセッションに書き戻さない
+        let split_direction = cmd
+            .split_direction
+            .or(session.split_direction)
+            .or(config.split_direction)
+            .map(SplitDirection::to_direction)
+            .unwrap_or(Direction::Vertical);
+
+        let session = Arc::new(Mutex::new(session));
+
+        let kube_worker_config = KubeWorkerConfig {
+            context_namespaces: config.namespaces.clone(),
+            session: session.clone(),
+            ..cmd.kube_worker_config()
+        };
 
         let (tx_input, rx_main): (Sender<Message>, Receiver<Message>) = bounded(128);
         let (tx_main, rx_kube): (Sender<Message>, Receiver<Message>) = bounded(256);
@@ -37,7 +63,7 @@ impl App {
 
         let tick = Tick::new(
             tx_tick.clone(),
-            time::Duration::from_millis(200),
+            IntervalConfig::global().tick,
             is_terminated.clone(),
         );
 
@@ -46,6 +72,9 @@ impl App {
             rx_main.clone(),
             is_terminated.clone(),
             split_direction,
+            config,
+            presets,
+            session.clone(),
         );
 
         thread::scope(|s| {
@@ -92,6 +121,8 @@ impl App {
             anyhow::Ok(())
         })?;
 
-        Ok(())
+        let session = session.lock().expect("Failed to lock session").clone();
+
+        Ok(session)
     }
 }
@@ -1,4 +1,5 @@
 mod args;
 mod command;
 
+pub use self::args::{parse_duration, IntervalOverride, SplitDirection};
 pub use self::command::*;
@@ -1,5 +1,7 @@
 mod all_namespaces;
+mod interval;
 mod split_direction;
 
 pub use all_namespaces::*;
+pub use interval::*;
 pub use split_direction::*;
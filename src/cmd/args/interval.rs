@@ -0,0 +1,179 @@
+use std::{str::FromStr, time::Duration};
+
+use crate::workers::interval::IntervalConfig;
+
+/// ポーリング間隔の上書き（NAME=DURATION）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntervalOverride {
+    pub name: IntervalName,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalName {
+    Pod,
+    Event,
+    Network,
+    Config,
+    Workload,
+    Api,
+    ApiDiscovery,
+    Description,
+    Log,
+    LogCollect,
+    Tick,
+    MaxBackoff,
+}
+
+impl IntervalName {
+    const NAMES: [(&'static str, Self); 12] = [
+        ("pod", Self::Pod),
+        ("event", Self::Event),
+        ("network", Self::Network),
+        ("config", Self::Config),
+        ("workload", Self::Workload),
+        ("api", Self::Api),
+        ("api-discovery", Self::ApiDiscovery),
+        ("description", Self::Description),
+        ("log", Self::Log),
+        ("log-collect", Self::LogCollect),
+        ("tick", Self::Tick),
+        ("max-backoff", Self::MaxBackoff),
+    ];
+}
+
+impl FromStr for IntervalName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::NAMES
+            .iter()
+            .find(|(name, _)| *name == s)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::NAMES.iter().map(|(name, _)| *name).collect();
+
+                format!(
+                    "unknown interval name '{}' (possible values: {})",
+                    s,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+impl FromStr for IntervalOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, duration)) = s.split_once('=') else {
+            return Err(format!("invalid format '{}' (expected NAME=DURATION)", s));
+        };
+
+        Ok(Self {
+            name: name.trim().parse()?,
+            duration: parse_duration(duration.trim())?,
+        })
+    }
+}
+
+impl IntervalOverride {
+    pub fn apply(&self, config: &mut IntervalConfig) {
+        let target = match self.name {
+            IntervalName::Pod => &mut config.pod,
+            IntervalName::Event => &mut config.event,
+            IntervalName::Network => &mut config.network,
+            IntervalName::Config => &mut config.config,
+            IntervalName::Workload => &mut config.workload,
+            IntervalName::Api => &mut config.api,
+            IntervalName::ApiDiscovery => &mut config.api_discovery,
+            IntervalName::Description => &mut config.description,
+            IntervalName::Log => &mut config.log,
+            IntervalName::LogCollect => &mut config.log_collect,
+            IntervalName::Tick => &mut config.tick,
+            IntervalName::MaxBackoff => &mut config.max_backoff,
+        };
+
+        *target = self.duration;
+    }
+}
+
+/// "200ms"、"3s"、"1m" 形式の文字列をDurationに変換する
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let index = s
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| format!("missing unit in '{}' (ms, s, m)", s))?;
+
+    let (value, unit) = s.split_at(index);
+
+    let value: u64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration '{}'", s))?;
+
+    let duration = match unit {
+        "ms" => Duration::from_millis(value),
+        "s" => Duration::from_secs(value),
+        "m" => Duration::from_secs(value * 60),
+        _ => return Err(format!("unknown unit '{}' in '{}' (ms, s, m)", unit, s)),
+    };
+
+    if duration.is_zero() {
+        return Err(format!("duration must be greater than 0: '{}'", s));
+    }
+
+    Ok(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("200ms", Duration::from_millis(200))]
+    #[case("3s", Duration::from_secs(3))]
+    #[case("1m", Duration::from_secs(60))]
+    fn 単位付きの文字列をdurationに変換する(
+        #[case] input: &str,
+        #[case] expected: Duration,
+    ) {
+        assert_eq!(parse_duration(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::no_unit("3")]
+    #[case::unknown_unit("3h")]
+    #[case::no_value("s")]
+    #[case::zero("0s")]
+    #[case::negative("-1s")]
+    fn 不正な文字列のときエラーを返す(#[case] input: &str) {
+        assert!(parse_duration(input).is_err());
+    }
+
+    #[test]
+    fn name_durationの形式をパースして設定に反映する() {
+        let actual: IntervalOverride = "api-discovery=30s".parse().unwrap();
+
+        assert_eq!(
+            actual,
+            IntervalOverride {
+                name: IntervalName::ApiDiscovery,
+                duration: Duration::from_secs(30),
+            }
+        );
+
+        let mut config = IntervalConfig::default();
+        actual.apply(&mut config);
+
+        assert_eq!(config.api_discovery, Duration::from_secs(30));
+    }
+
+    #[rstest]
+    #[case::no_separator("pod")]
+    #[case::unknown_name("hoge=1s")]
+    #[case::invalid_duration("pod=1")]
+    fn 不正な上書き指定のときエラーを返す(#[case] input: &str) {
+        assert!(input.parse::<IntervalOverride>().is_err());
+    }
+}
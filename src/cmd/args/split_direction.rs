@@ -1,4 +1,5 @@
 use ratatui::layout::Direction;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use strum::EnumString;
 
 #[derive(Debug, Default, EnumString, Clone, Copy, PartialEq, Eq)]
@@ -28,3 +29,31 @@ impl SplitDirection {
         }
     }
 }
+
+impl Serialize for SplitDirection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match self {
+            SplitDirection::Vertical => "vertical",
+            SplitDirection::Horizontal => "horizontal",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for SplitDirection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        s.parse().map_err(|_| {
+            D::Error::custom(format!(
+                "unknown split direction '{}' (possible values: v, h, vertical, horizontal)",
+                s
+            ))
+        })
+    }
+}
@@ -2,22 +2,16 @@ use clap::Parser;
 use ratatui::layout::Direction;
 use std::path::PathBuf;
 
-use crate::workers::kube::KubeWorkerConfig;
+use crate::workers::{interval::IntervalConfig, kube::KubeWorkerConfig};
 
-use super::args::{AllNamespaces, SplitDirection};
+use super::args::{AllNamespaces, IntervalOverride, SplitDirection};
 
 #[derive(Parser, Debug, Clone)]
 #[command(author, version, about, long_about = None)]
 pub struct Command {
-    /// Window split direction
-    #[arg(
-        short,
-        long,
-        value_name = "v|h",
-        default_value = "v",
-        display_order = 1000
-    )]
-    pub split_direction: SplitDirection,
+    /// Window split direction [default: v]
+    #[arg(short, long, value_name = "v|h", display_order = 1000)]
+    pub split_direction: Option<SplitDirection>,
 
     /// Namespaces (e.g. -n val1,val2,val3 | -n val1 -n val2 -n val3)
     #[arg(
@@ -65,6 +59,23 @@ pub struct Command {
     /// Logging
     #[arg(short = 'l', long, display_order = 1000)]
     pub logging: bool,
+
+    /// Config file path [default: $XDG_CONFIG_HOME/kubetui/config.yaml]
+    #[arg(long, value_name = "PATH", display_order = 1000)]
+    pub config: Option<PathBuf>,
+
+    /// Override a polling interval (e.g. --interval pod=2s --interval description=5s).
+    /// NAME: pod, event, network, config, workload, api, api-discovery, description, log, log-collect, tick, max-backoff
+    #[arg(long, value_name = "NAME=DURATION", display_order = 1000)]
+    pub interval: Vec<IntervalOverride>,
+
+    /// Multiplier applied to polling intervals while the terminal is unfocused
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..), display_order = 1000)]
+    pub unfocused_slowdown: Option<u32>,
+
+    /// Start without restoring the last session, and do not save it on exit
+    #[arg(long, display_order = 1000)]
+    pub no_restore: bool,
 }
 
 impl Command {
@@ -72,8 +83,8 @@ impl Command {
         Self::parse()
     }
 
-    pub fn split_direction(&self) -> Direction {
-        self.split_direction.to_direction()
+    pub fn split_direction(&self) -> Option<Direction> {
+        self.split_direction.map(SplitDirection::to_direction)
     }
 
     pub fn kube_worker_config(&self) -> KubeWorkerConfig {
@@ -90,8 +101,22 @@ impl Command {
             target_namespaces: namespaces,
             context,
             all_namespaces: all_namespaces.into(),
+            ..Default::default()
         }
     }
+
+    /// 設定ファイルの値をコマンドライン引数で上書きする
+    pub fn interval_config(&self, mut config: IntervalConfig) -> IntervalConfig {
+        for interval in &self.interval {
+            interval.apply(&mut config);
+        }
+
+        if let Some(multiplier) = self.unfocused_slowdown {
+            config.unfocused_multiplier = multiplier;
+        }
+
+        config
+    }
 }
 
 #[cfg(test)]
@@ -107,7 +132,13 @@ mod tests {
         #[test]
         fn possible_valuesの値であるhを設定したときhorizontalを返す() {
             let cmd = Command::try_parse_from(["kubetui", "-s", "h"]).unwrap();
-            assert_eq!(cmd.split_direction(), Direction::Horizontal)
+            assert_eq!(cmd.split_direction(), Some(Direction::Horizontal))
+        }
+
+        #[test]
+        fn 指定しないときnoneを返す() {
+            let cmd = Command::try_parse_from(["kubetui"]).unwrap();
+            assert_eq!(cmd.split_direction(), None)
         }
 
         #[test]
@@ -192,4 +223,43 @@ mod tests {
             assert_eq!(cmd.unwrap_err().kind(), ErrorKind::ArgumentConflict)
         }
     }
+
+    mod interval {
+        use std::time::Duration;
+
+        use clap::error::ErrorKind;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn 指定した間隔だけ上書きする() {
+            let cmd = Command::try_parse_from([
+                "kubetui",
+                "--interval",
+                "pod=2s",
+                "--interval",
+                "description=500ms",
+                "--unfocused-slowdown",
+                "10",
+            ])
+            .unwrap();
+
+            assert_eq!(
+                cmd.interval_config(IntervalConfig::default()),
+                IntervalConfig {
+                    pod: Duration::from_secs(2),
+                    description: Duration::from_millis(500),
+                    unfocused_multiplier: 10,
+                    ..Default::default()
+                }
+            )
+        }
+
+        #[test]
+        fn 不正な値のときエラーを返す() {
+            let cmd = Command::try_parse_from(["kubetui", "--interval", "pod=2"]);
+            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::ValueValidation)
+        }
+    }
 }
@@ -0,0 +1,270 @@
+mod alert;
+mod keybinding;
+mod list;
+mod theme;
+
+pub use alert::*;
+pub use keybinding::*;
+pub use list::*;
+pub use theme::*;
+
+use std::{
+    collections::BTreeMap,
+    env,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use strum::{EnumIter, IntoEnumIterator as _};
+
+use crate::{
+    cmd::{IntervalOverride, SplitDirection},
+    features::{
+        component_id::{
+            CONFIG_TAB_ID, EVENT_TAB_ID, LIST_TAB_ID, NETWORK_TAB_ID, POD_TAB_ID, WORKLOAD_TAB_ID,
+            YAML_TAB_ID,
+        },
+        pod::kube::LogPrefixType,
+    },
+    workers::interval::IntervalConfig,
+};
+
+/// 設定ファイル
+///
+/// コマンドライン引数で指定した値が優先される
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub split_direction: Option<SplitDirection>,
+    pub initial_tab: Option<InitialTab>,
+    /// コンテキストごとのデフォルトのnamespace
+    pub namespaces: BTreeMap<String, Vec<String>>,
+    pub log: LogSettings,
+    pub keybindings: KeyBindings,
+    pub theme: ThemeConfig,
+    #[serde(deserialize_with = "deserialize_intervals")]
+    pub intervals: Vec<IntervalOverride>,
+    pub unfocused_slowdown: Option<u32>,
+    pub alert: AlertConfig,
+    pub list: ListConfig,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogSettings {
+    /// 未指定のときはnamespaceの数に応じて切り替える
+    pub prefix_type: Option<LogPrefixType>,
+}
+
+/// 起動時に表示するタブ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIter)]
+#[serde(rename_all = "snake_case")]
+pub enum InitialTab {
+    Pod,
+    Config,
+    Network,
+    Event,
+    List,
+    Yaml,
+    Workload,
+}
+
+impl InitialTab {
+    pub fn tab_id(&self) -> &'static str {
+        match self {
+            Self::Pod => POD_TAB_ID,
+            Self::Config => CONFIG_TAB_ID,
+            Self::Network => NETWORK_TAB_ID,
+            Self::Event => EVENT_TAB_ID,
+            Self::List => LIST_TAB_ID,
+            Self::Yaml => YAML_TAB_ID,
+            Self::Workload => WORKLOAD_TAB_ID,
+        }
+    }
+
+    pub fn from_tab_id(id: &str) -> Option<Self> {
+        Self::iter().find(|tab| tab.tab_id() == id)
+    }
+}
+
+impl Config {
+    /// 設定ファイルを読み込む
+    ///
+    /// パスを指定しなかったときはデフォルトのパスを読み込み、ファイルがなければデフォルト値を返す
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let (path, is_explicit) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => match default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if !is_explicit && err.kind() == ErrorKind::NotFound => {
+                return Ok(Self::default())
+            }
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read config file {}", path.display()))
+            }
+        };
+
+        Self::from_yaml(&content).with_context(|| format!("invalid config file {}", path.display()))
+    }
+
+    fn from_yaml(content: &str) -> Result<Self> {
+        if content.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    pub fn interval_config(&self) -> IntervalConfig {
+        let mut config = IntervalConfig::default();
+
+        for interval in &self.intervals {
+            interval.apply(&mut config);
+        }
+
+        if let Some(multiplier) = self.unfocused_slowdown {
+            config.unfocused_multiplier = multiplier;
+        }
+
+        config
+    }
+}
+
+/// $XDG_CONFIG_HOME/kubetui/config.yaml
+/// XDG_CONFIG_HOMEが未設定のときは $HOME/.config/kubetui/config.yaml
+pub fn default_path() -> Option<PathBuf> {
+    Some(config_dir()?.join("config.yaml"))
+}
+
+/// $XDG_CONFIG_HOME/kubetui
+/// XDG_CONFIG_HOMEが未設定のときは $HOME/.config/kubetui
+pub fn config_dir() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_dir.join("kubetui"))
+}
+
+fn deserialize_intervals<'de, D>(deserializer: D) -> Result<Vec<IntervalOverride>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    BTreeMap::<String, String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, duration)| {
+            format!("{}={}", name, duration)
+                .parse()
+                .map_err(|err| format!("invalid interval '{}': {}", name, err))
+        })
+        .collect::<Result<_, String>>()
+        .map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crossterm::event::KeyCode;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Color;
+
+    use crate::message::UserEvent;
+
+    #[test]
+    fn 設定ファイルを読み込む() {
+        let actual = Config::from_yaml(indoc! {r#"
+            split_direction: horizontal
+            initial_tab: workload
+            namespaces:
+              kind-dev: [app, monitoring]
+            log:
+              prefix_type: all
+            keybindings:
+              help: ["f1"]
+            theme:
+              border_active: cyan
+            intervals:
+              pod: 2s
+            unfocused_slowdown: 10
+        "#})
+        .unwrap();
+
+        assert_eq!(actual.split_direction, Some(SplitDirection::Horizontal));
+        assert_eq!(
+            actual.initial_tab.map(|t| t.tab_id()),
+            Some(WORKLOAD_TAB_ID)
+        );
+        assert_eq!(
+            actual.namespaces,
+            BTreeMap::from([(
+                "kind-dev".to_string(),
+                vec!["app".to_string(), "monitoring".to_string()]
+            )])
+        );
+        assert_eq!(actual.log.prefix_type, Some(LogPrefixType::All));
+        assert_eq!(
+            actual.keybindings.keys(Action::Help),
+            vec![UserEvent::from(KeyCode::F(1))]
+        );
+        assert_eq!(actual.theme.border_active, Some(Color::Cyan));
+        assert_eq!(
+            actual.interval_config(),
+            IntervalConfig {
+                pod: Duration::from_secs(2),
+                unfocused_multiplier: 10,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn 空のファイルのときデフォルト値を返す() {
+        assert_eq!(Config::from_yaml("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn 存在しない項目のとき位置を含めたエラーを返す() {
+        let actual = Config::from_yaml(indoc! {r#"
+            log:
+              prefix: all
+        "#});
+
+        let message = actual.unwrap_err().to_string();
+
+        assert!(
+            message.starts_with("log: unknown field `prefix`"),
+            "{}",
+            message
+        );
+        assert!(message.contains("line 2"), "{}", message);
+    }
+
+    #[test]
+    fn 不正な値のとき項目名を含めたエラーを返す() {
+        let actual = Config::from_yaml(indoc! {r#"
+            intervals:
+              pod: 2
+        "#});
+
+        let message = actual.unwrap_err().to_string();
+
+        assert!(
+            message.starts_with("invalid interval 'pod': missing unit in '2'"),
+            "{}",
+            message
+        );
+    }
+}
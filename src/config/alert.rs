@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use anyhow::{Context as _, Result};
+use regex::Regex;
+use serde::{de::Error as _, Deserialize, Deserializer};
+
+use crate::{
+    cmd::parse_duration,
+    features::alert::{
+        AlertRules, NotifyMethod, DEFAULT_EVENT_REASONS, DEFAULT_POD_STATUS, DEFAULT_RATE_LIMIT,
+    },
+};
+
+/// 通知の設定
+///
+/// pod_statusとevent_reasonsは部分一致の正規表現で指定する
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AlertConfig {
+    pub enabled: bool,
+    pub pod_status: Vec<String>,
+    pub restarts: bool,
+    /// Warningイベントのreason
+    pub event_reasons: Vec<String>,
+    pub notify: NotifyMethod,
+    #[serde(deserialize_with = "deserialize_duration")]
+    pub rate_limit: Duration,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        let strings = |values: &[&str]| values.iter().map(ToString::to_string).collect();
+
+        Self {
+            enabled: true,
+            pod_status: strings(DEFAULT_POD_STATUS),
+            restarts: true,
+            event_reasons: strings(DEFAULT_EVENT_REASONS),
+            notify: NotifyMethod::default(),
+            rate_limit: DEFAULT_RATE_LIMIT,
+        }
+    }
+}
+
+impl AlertConfig {
+    pub fn rules(&self) -> Result<AlertRules> {
+        let regexes = |name: &str, patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| Regex::new(p).with_context(|| format!("invalid alert.{} '{}'", name, p)))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        Ok(AlertRules {
+            enabled: self.enabled,
+            pod_status: regexes("pod_status", &self.pod_status)?,
+            restarts: self.restarts,
+            event_reasons: regexes("event_reasons", &self.event_reasons)?,
+            notify: self.notify,
+            rate_limit: self.rate_limit,
+        })
+    }
+}
+
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_duration(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn 指定した項目だけ上書きする() {
+        let config: AlertConfig = serde_yaml::from_str(indoc! {r#"
+            pod_status: ["CrashLoopBackOff"]
+            notify: osc777
+            rate_limit: 1m
+        "#})
+        .unwrap();
+
+        assert_eq!(
+            config,
+            AlertConfig {
+                pod_status: vec!["CrashLoopBackOff".into()],
+                notify: NotifyMethod::Osc777,
+                rate_limit: Duration::from_secs(60),
+                ..Default::default()
+            }
+        );
+
+        let rules = config.rules().unwrap();
+
+        assert!(rules.matches_pod_status("CrashLoopBackOff"));
+        assert!(!rules.matches_pod_status("Error"));
+        assert!(rules.matches_event_reason("BackOff"));
+    }
+
+    #[test]
+    fn 不正な正規表現のとき項目名を含めたエラーを返す() {
+        let config = AlertConfig {
+            event_reasons: vec!["Failed(".into()],
+            ..Default::default()
+        };
+
+        let message = config.rules().unwrap_err().to_string();
+
+        assert_eq!(message, "invalid alert.event_reasons 'Failed('");
+    }
+}
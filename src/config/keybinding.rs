@@ -0,0 +1,305 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{de::Error as _, Deserialize, Deserializer};
+use strum::{Display as StrumDisplay, EnumIter, IntoEnumIterator as _};
+
+use crate::message::UserEvent;
+
+/// キーに割り当てられるウィンドウ全体のアクション
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, StrumDisplay, EnumIter,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Action {
+    /// namespaceを1つ選択する
+    Namespace,
+    /// namespaceを複数選択する
+    MultipleNamespaces,
+    /// contextを切り替える
+    Context,
+    Help,
+    Yaml,
+    /// 選択した行のリソースを`kubectl describe`のように表示する
+    Describe,
+    OwnerTree,
+    /// 通知の一覧を開く
+    Alerts,
+    /// エラーの履歴を開く
+    Errors,
+    /// APIリソースのプリセットを切り替える
+    Presets,
+    /// ポップアップを閉じる、またはアプリケーションを終了する
+    Quit,
+}
+
+impl Action {
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            Self::Namespace => &["n"],
+            Self::MultipleNamespaces => &["N"],
+            Self::Context => &["c"],
+            Self::Help => &["h", "?"],
+            Self::Yaml => &["y"],
+            Self::Describe => &["D"],
+            Self::OwnerTree => &["o"],
+            Self::Alerts => &["A"],
+            Self::Errors => &["E"],
+            Self::Presets => &["p"],
+            Self::Quit => &["q", "esc"],
+        }
+    }
+}
+
+/// "ctrl+a"、"N"、"esc" などの形式で指定するキー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding(KeyEvent);
+
+impl KeyBinding {
+    pub fn to_user_event(self) -> UserEvent {
+        UserEvent::Key(self.0)
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("key must not be empty".into());
+        }
+
+        // "+" 単体や "ctrl++" を扱うため、末尾の1文字はキーとして扱う
+        let (modifiers, key) = match s.strip_suffix('+').filter(|m| m.ends_with('+')) {
+            Some(modifiers) => (modifiers, "+"),
+            None if s == "+" => ("", "+"),
+            None => s.rsplit_once('+').map_or(("", s), |(m, k)| (m, k)),
+        };
+
+        let mut modifier = KeyModifiers::NONE;
+
+        for m in modifiers.split('+').filter(|m| !m.is_empty()) {
+            modifier |= match m.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier '{}' in '{}'", m, s)),
+            };
+        }
+
+        let mut chars = key.chars();
+
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                if c.is_ascii_uppercase() {
+                    modifier |= KeyModifiers::SHIFT;
+                }
+
+                if modifier.contains(KeyModifiers::SHIFT) {
+                    KeyCode::Char(c.to_ascii_uppercase())
+                } else {
+                    KeyCode::Char(c)
+                }
+            }
+            _ => match key.to_ascii_lowercase().as_str() {
+                "esc" => KeyCode::Esc,
+                "enter" => KeyCode::Enter,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" | "bs" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" | "pgup" => KeyCode::PageUp,
+                "pagedown" | "pgdn" => KeyCode::PageDown,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "space" => KeyCode::Char(' '),
+                f if f.starts_with('f') => match f[1..].parse::<u8>() {
+                    Ok(n @ 1..=12) => KeyCode::F(n),
+                    _ => return Err(format!("unknown key '{}' in '{}'", key, s)),
+                },
+                _ => return Err(format!("unknown key '{}' in '{}'", key, s)),
+            },
+        };
+
+        Ok(Self(KeyEvent::new(code, modifier)))
+    }
+}
+
+impl Display for KeyBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let KeyEvent {
+            code, modifiers, ..
+        } = self.0;
+
+        if modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl+")?;
+        }
+
+        if modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "alt+")?;
+        }
+
+        match code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "f{}", n),
+            code => write!(f, "{}", format!("{:?}", code).to_ascii_lowercase()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyBinding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+/// アクションとキーの対応
+///
+/// 設定ファイルで指定したアクションはデフォルトのキーを置き換える
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings(BTreeMap<Action, Vec<KeyBinding>>);
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self(
+            Action::iter()
+                .map(|action| {
+                    let keys = action
+                        .default_keys()
+                        .iter()
+                        .map(|key| key.parse().expect("invalid default key"))
+                        .collect();
+
+                    (action, keys)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let overrides = BTreeMap::<Action, Vec<KeyBinding>>::deserialize(deserializer)?;
+
+        let mut bindings = Self::default();
+        bindings.0.extend(overrides);
+
+        bindings.validate().map_err(D::Error::custom)?;
+
+        Ok(bindings)
+    }
+}
+
+impl KeyBindings {
+    pub fn keys(&self, action: Action) -> Vec<UserEvent> {
+        self.0
+            .get(&action)
+            .into_iter()
+            .flatten()
+            .map(|key| key.to_user_event())
+            .collect()
+    }
+
+    /// 同じキーが複数のアクションに割り当てられていないか確認する
+    fn validate(&self) -> Result<(), String> {
+        let mut assigned: Vec<(KeyBinding, Action)> = Vec::new();
+
+        for (action, keys) in &self.0 {
+            for key in keys {
+                if let Some((_, other)) = assigned.iter().find(|(k, _)| k == key) {
+                    return Err(format!(
+                        "key '{}' is bound to both '{}' and '{}'",
+                        key, other, action
+                    ));
+                }
+
+                assigned.push((*key, *action));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("n", KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE))]
+    #[case("N", KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT))]
+    #[case("shift+n", KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT))]
+    #[case("ctrl+r", KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))]
+    #[case(
+        "ctrl+alt+x",
+        KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL | KeyModifiers::ALT)
+    )]
+    #[case("+", KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE))]
+    #[case("ctrl++", KeyEvent::new(KeyCode::Char('+'), KeyModifiers::CONTROL))]
+    #[case("Esc", KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))]
+    #[case("f5", KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE))]
+    #[case("space", KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE))]
+    fn 文字列をキーに変換する(#[case] input: &str, #[case] expected: KeyEvent) {
+        assert_eq!(input.parse::<KeyBinding>(), Ok(KeyBinding(expected)));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown_modifier("super+a")]
+    #[case::unknown_key("ctrl+hoge")]
+    #[case::out_of_range_function_key("f13")]
+    fn 不正なキーのときエラーを返す(#[case] input: &str) {
+        assert!(input.parse::<KeyBinding>().is_err());
+    }
+
+    #[test]
+    fn 指定したアクションだけデフォルトのキーを置き換える() {
+        let actual: KeyBindings = serde_yaml::from_str(indoc! {r#"
+            help: ["f1"]
+            namespace: ["ctrl+n"]
+        "#})
+        .unwrap();
+
+        assert_eq!(
+            actual.keys(Action::Help),
+            vec![UserEvent::from(KeyCode::F(1))]
+        );
+        assert_eq!(
+            actual.keys(Action::Namespace),
+            vec![UserEvent::Key(KeyEvent::new(
+                KeyCode::Char('n'),
+                KeyModifiers::CONTROL
+            ))]
+        );
+        assert_eq!(actual.keys(Action::Context), vec![UserEvent::from('c')]);
+    }
+
+    #[test]
+    fn 同じキーを複数のアクションに割り当てるとエラーを返す() {
+        let actual = serde_yaml::from_str::<KeyBindings>(indoc! {r#"
+            yaml: ["c"]
+        "#});
+
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "key 'c' is bound to both 'context' and 'yaml'"
+        );
+    }
+}
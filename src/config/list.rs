@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context as _, Result};
+use serde::Deserialize;
+
+use crate::features::{
+    api_resources::kube::{Column, ListColumns},
+    yaml::output::parse_json_path,
+};
+
+/// Listタブの設定
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ListConfig {
+    /// リソース名（`pods`、`deployments.apps`）ごとの列
+    pub columns: BTreeMap<String, Vec<ColumnConfig>>,
+}
+
+/// 列の名前だけのときはサーバーが返す列から選ぶ
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ColumnConfig {
+    Printer(String),
+    JsonPath { name: String, json_path: String },
+}
+
+impl ListConfig {
+    pub fn columns(&self) -> Result<ListColumns> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|(resource, columns)| {
+                let columns = columns
+                    .iter()
+                    .map(|column| match column {
+                        ColumnConfig::Printer(name) => Ok(Column::Printer(name.clone())),
+                        ColumnConfig::JsonPath { name, json_path } => {
+                            parse_json_path(json_path).with_context(|| {
+                                format!("invalid list.columns.{} '{}'", resource, name)
+                            })?;
+
+                            Ok(Column::JsonPath {
+                                name: name.clone(),
+                                path: json_path.clone(),
+                            })
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok((resource.clone(), columns))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(ListColumns::new(columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn 列の名前とjsonpathの列を読み込む() {
+        let config: ListConfig = serde_yaml::from_str(indoc! {r#"
+            columns:
+              pods:
+                - NAME
+                - name: NODE
+                  json_path: .spec.nodeName
+        "#})
+        .unwrap();
+
+        assert_eq!(
+            config.columns().unwrap(),
+            ListColumns::new(BTreeMap::from([(
+                "pods".to_string(),
+                vec![
+                    Column::Printer("NAME".into()),
+                    Column::JsonPath {
+                        name: "NODE".into(),
+                        path: ".spec.nodeName".into(),
+                    },
+                ],
+            )]))
+        );
+    }
+
+    #[test]
+    fn 不正なjsonpathはエラーにする() {
+        let config: ListConfig = serde_yaml::from_str(indoc! {r#"
+            columns:
+              pods:
+                - name: NODE
+                  json_path: .spec[
+        "#})
+        .unwrap();
+
+        assert!(config.columns().is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+
+use crate::ui::theme::{LogPrefixColor, Theme, ThemePreset};
+
+/// 設定ファイルで上書きする色
+///
+/// 色は "red"、"dark-gray"、"#ff0000"、"33"（256色のインデックス）の形式で指定する
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeConfig {
+    /// 元にするテーマ
+    pub preset: ThemePreset,
+    pub error: Option<Color>,
+    pub warning: Option<Color>,
+    pub success: Option<Color>,
+    pub info: Option<Color>,
+    pub muted: Option<Color>,
+    pub selected: Option<Color>,
+    pub border_active: Option<Color>,
+    pub border_inactive: Option<Color>,
+    pub border_mouse_over: Option<Color>,
+    pub tab_mouse_over: Option<Color>,
+    pub log_prefix: Option<Vec<LogPrefixColor>>,
+    pub palette: Option<Vec<Color>>,
+}
+
+impl ThemeConfig {
+    /// NO_COLORが設定されているときは設定ファイルの色より優先して色を使わない
+    pub fn theme(&self, no_color: bool) -> Theme {
+        if no_color {
+            return Theme::no_color();
+        }
+
+        let mut theme = self.preset.theme();
+
+        let colors = [
+            (&mut theme.error, self.error),
+            (&mut theme.warning, self.warning),
+            (&mut theme.success, self.success),
+            (&mut theme.info, self.info),
+            (&mut theme.muted, self.muted),
+        ];
+
+        for (target, color) in colors {
+            if let Some(color) = color {
+                *target = color;
+            }
+        }
+
+        let styles = [
+            (&mut theme.selected, self.selected),
+            (&mut theme.border_active, self.border_active),
+            (&mut theme.border_inactive, self.border_inactive),
+            (&mut theme.border_mouse_over, self.border_mouse_over),
+            (&mut theme.tab_mouse_over, self.tab_mouse_over),
+        ];
+
+        for (style, color) in styles {
+            if let Some(color) = color {
+                *style = style.fg(color);
+            }
+        }
+
+        if let Some(log_prefix) = self.log_prefix.as_ref().filter(|v| !v.is_empty()) {
+            theme.log_prefix = log_prefix.clone();
+        }
+
+        if let Some(palette) = self.palette.as_ref().filter(|v| !v.is_empty()) {
+            theme.palette = palette.clone();
+        }
+
+        theme
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Style;
+
+    #[test]
+    fn 指定した色だけ上書きする() {
+        let config: ThemeConfig = serde_yaml::from_str(indoc! {r##"
+            error: light-red
+            border_active: cyan
+            border_inactive: "#303030"
+            palette: [green, "208"]
+        "##})
+        .unwrap();
+
+        let actual = config.theme(false);
+
+        assert_eq!(
+            actual,
+            Theme {
+                error: Color::LightRed,
+                border_active: Style::default().fg(Color::Cyan),
+                border_inactive: Style::default().fg(Color::Rgb(0x30, 0x30, 0x30)),
+                palette: vec![Color::Green, Color::Indexed(208)],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn プリセットを元に上書きする() {
+        let config: ThemeConfig = serde_yaml::from_str(indoc! {r#"
+            preset: light
+            log_prefix:
+              - pod: blue
+                container: dark-gray
+        "#})
+        .unwrap();
+
+        let actual = config.theme(false);
+
+        assert_eq!(
+            actual,
+            Theme {
+                log_prefix: vec![LogPrefixColor {
+                    pod: Color::Blue,
+                    container: Color::DarkGray,
+                }],
+                ..Theme::light()
+            }
+        );
+    }
+
+    #[test]
+    fn no_colorのときは設定より優先して色を使わない() {
+        let config: ThemeConfig = serde_yaml::from_str(indoc! {r#"
+            preset: high_contrast
+            error: red
+        "#})
+        .unwrap();
+
+        assert_eq!(config.theme(true), Theme::no_color());
+    }
+}
@@ -1,11 +1,17 @@
+pub mod alert;
 pub mod api_resources;
 pub mod component_id;
 pub mod config;
 pub mod context;
+pub mod describe;
+pub mod edit;
 pub mod event;
 pub mod get;
 pub mod help;
 pub mod namespace;
 pub mod network;
 pub mod pod;
+pub mod preset;
+pub mod status;
+pub mod workload;
 pub mod yaml;
@@ -0,0 +1,8 @@
+mod manager;
+mod notify;
+mod rule;
+pub mod view;
+
+pub use manager::*;
+pub use notify::*;
+pub use rule::*;
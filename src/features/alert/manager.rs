@@ -0,0 +1,503 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::kube::table::KubeTableRow;
+
+use super::{AlertRules, NotifyMethod};
+
+/// 画面に残しておく通知の上限
+const MAX_ALERTS: usize = 100;
+
+/// 通知の対象になったオブジェクト
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AlertObject {
+    pub kind: String,
+    pub namespace: String,
+    pub name: String,
+}
+
+impl Display for AlertObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.kind.to_lowercase(), self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alert {
+    pub id: u64,
+    pub object: AlertObject,
+    pub reason: String,
+    pub message: String,
+    pub time: DateTime<Utc>,
+}
+
+impl Alert {
+    pub fn summary(&self) -> String {
+        format!(
+            "{} ({}): {} {}",
+            self.object, self.object.namespace, self.reason, self.message
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PodState {
+    status: String,
+    restarts: u32,
+}
+
+/// PodとイベントのテーブルからAlertRulesに一致する変化を見つけて通知を管理する
+///
+/// 確認（acknowledge）した通知は一覧から取り除き、ミュートしたオブジェクトは通知しない
+#[derive(Debug)]
+pub struct AlertManager {
+    rules: AlertRules,
+    pods: HashMap<AlertObject, PodState>,
+    /// 最初に受け取ったPodの状態は以前からのものなので通知しない
+    pods_observed: bool,
+    events: HashMap<(AlertObject, String), u32>,
+    /// 最初に受け取ったイベントは過去のものなので通知しない
+    events_observed: bool,
+    last_alerted: HashMap<AlertObject, DateTime<Utc>>,
+    muted: BTreeSet<AlertObject>,
+    alerts: Vec<Alert>,
+    next_id: u64,
+}
+
+impl AlertManager {
+    pub fn new(rules: AlertRules) -> Self {
+        Self {
+            rules,
+            pods: HashMap::new(),
+            pods_observed: false,
+            events: HashMap::new(),
+            events_observed: false,
+            last_alerted: HashMap::new(),
+            muted: BTreeSet::new(),
+            alerts: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn notify_method(&self) -> NotifyMethod {
+        self.rules.notify
+    }
+
+    /// namespaceやcontextを切り替えたときに前回の状態を捨てる
+    pub fn reset(&mut self) {
+        self.pods.clear();
+        self.pods_observed = false;
+        self.events.clear();
+        self.events_observed = false;
+    }
+
+    /// 新しい通知を新しい順に返す（未確認の通知は最大100件）
+    pub fn alerts(&self) -> &[Alert] {
+        &self.alerts
+    }
+
+    pub fn muted(&self) -> &BTreeSet<AlertObject> {
+        &self.muted
+    }
+
+    /// Podテーブルの行を受け取り、ステータスの変化と再起動回数の増加を通知する
+    ///
+    /// 行のメタデータのstatusとrestartsを使う。最初に受け取ったときは状態を覚えるだけで、
+    /// それ以降に現れたPodは現れたときのステータスも通知する
+    pub fn observe_pods(&mut self, rows: &[KubeTableRow], now: DateTime<Utc>) -> Vec<Alert> {
+        let mut pods = HashMap::with_capacity(rows.len());
+        let mut raised = Vec::new();
+
+        for row in rows {
+            let Some(metadata) = &row.metadata else {
+                continue;
+            };
+
+            let object = AlertObject {
+                kind: "Pod".into(),
+                namespace: row.namespace.clone(),
+                name: row.name.clone(),
+            };
+
+            let state = PodState {
+                status: metadata.get("status").cloned().unwrap_or_default(),
+                restarts: metadata
+                    .get("restarts")
+                    .and_then(|r| r.parse().ok())
+                    .unwrap_or_default(),
+            };
+
+            let prev = self.pods.get(&object);
+
+            let status_changed = match prev {
+                Some(prev) => prev.status != state.status,
+                None => true,
+            };
+
+            let alert = if status_changed && self.rules.matches_pod_status(&state.status) {
+                Some((
+                    state.status.clone(),
+                    format!("restarts: {}", state.restarts),
+                ))
+            } else {
+                prev.filter(|prev| self.rules.restarts && prev.restarts < state.restarts)
+                    .map(|prev| {
+                        (
+                            "Restarted".to_string(),
+                            format!("restarts: {} -> {}", prev.restarts, state.restarts),
+                        )
+                    })
+            };
+
+            if let Some((reason, message)) = alert.filter(|_| self.pods_observed) {
+                raised.extend(self.raise(object.clone(), reason, message, now));
+            }
+
+            pods.insert(object, state);
+        }
+
+        self.pods = pods;
+        self.pods_observed = true;
+
+        raised
+    }
+
+    /// イベントテーブルの行を受け取り、新しく発生したWarningイベントを通知する
+    ///
+    /// 行のメタデータのtype、kind、reason、count、messageを使う
+    pub fn observe_events(&mut self, rows: &[KubeTableRow], now: DateTime<Utc>) -> Vec<Alert> {
+        let mut events = HashMap::with_capacity(rows.len());
+        let mut raised = Vec::new();
+
+        for row in rows {
+            let Some(metadata) = &row.metadata else {
+                continue;
+            };
+
+            if metadata.get("type").map(String::as_str) != Some("Warning") {
+                continue;
+            }
+
+            let reason = metadata.get("reason").cloned().unwrap_or_default();
+
+            if !self.rules.matches_event_reason(&reason) {
+                continue;
+            }
+
+            let object = AlertObject {
+                kind: metadata.get("kind").cloned().unwrap_or_default(),
+                namespace: row.namespace.clone(),
+                name: row.name.clone(),
+            };
+
+            let count: u32 = metadata
+                .get("count")
+                .and_then(|c| c.parse().ok())
+                .unwrap_or(1);
+
+            let key = (object, reason);
+
+            let is_new = match self.events.get(&key) {
+                Some(prev) => *prev < count,
+                None => true,
+            };
+
+            if self.events_observed && is_new {
+                let message = metadata.get("message").cloned().unwrap_or_default();
+
+                raised.extend(self.raise(key.0.clone(), key.1.clone(), message, now));
+            }
+
+            events.insert(key, count);
+        }
+
+        self.events = events;
+        self.events_observed = true;
+
+        raised
+    }
+
+    pub fn acknowledge(&mut self, id: u64) {
+        self.alerts.retain(|alert| alert.id != id);
+    }
+
+    pub fn acknowledge_all(&mut self) {
+        self.alerts.clear();
+    }
+
+    /// ミュートを切り替える
+    ///
+    /// ミュートしたオブジェクトの通知は一覧からも取り除く
+    pub fn toggle_mute(&mut self, object: &AlertObject) {
+        if !self.muted.remove(object) {
+            self.alerts.retain(|alert| &alert.object != object);
+            self.muted.insert(object.clone());
+        }
+    }
+
+    fn raise(
+        &mut self,
+        object: AlertObject,
+        reason: String,
+        message: String,
+        now: DateTime<Utc>,
+    ) -> Option<Alert> {
+        if !self.rules.enabled || self.muted.contains(&object) {
+            return None;
+        }
+
+        let rate_limit = chrono::Duration::from_std(self.rules.rate_limit).unwrap_or_default();
+
+        if self
+            .last_alerted
+            .get(&object)
+            .is_some_and(|last| now < *last + rate_limit)
+        {
+            return None;
+        }
+
+        self.last_alerted.insert(object.clone(), now);
+
+        let alert = Alert {
+            id: self.next_id,
+            object,
+            reason,
+            message,
+            time: now,
+        };
+
+        self.next_id += 1;
+
+        self.alerts.insert(0, alert.clone());
+        self.alerts.truncate(MAX_ALERTS);
+
+        Some(alert)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, time::Duration};
+
+    use super::*;
+    use chrono::TimeZone as _;
+    use pretty_assertions::assert_eq;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    fn pod(name: &str, status: &str, restarts: u32) -> KubeTableRow {
+        KubeTableRow {
+            namespace: "default".into(),
+            name: name.into(),
+            metadata: Some(BTreeMap::from([
+                ("status".into(), status.into()),
+                ("restarts".into(), restarts.to_string()),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    fn event(name: &str, type_: &str, reason: &str, count: u32) -> KubeTableRow {
+        KubeTableRow {
+            namespace: "default".into(),
+            name: name.into(),
+            metadata: Some(BTreeMap::from([
+                ("type".into(), type_.into()),
+                ("kind".into(), "Pod".into()),
+                ("reason".into(), reason.into()),
+                ("count".into(), count.to_string()),
+                (
+                    "message".into(),
+                    "Back-off restarting failed container".into(),
+                ),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    fn reasons(alerts: &[Alert]) -> Vec<(String, String)> {
+        alerts
+            .iter()
+            .map(|a| (a.object.to_string(), a.reason.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn ステータスが条件に一致したときに通知する() {
+        let mut manager = AlertManager::new(AlertRules::default());
+
+        manager.observe_pods(&[pod("app", "Running", 0), pod("web", "Running", 3)], now());
+
+        let actual = manager.observe_pods(
+            &[pod("app", "Running", 0), pod("web", "CrashLoopBackOff", 3)],
+            now(),
+        );
+
+        assert_eq!(
+            reasons(&actual),
+            vec![("pod/web".into(), "CrashLoopBackOff".into())]
+        );
+
+        // 同じステータスが続く間は通知しない
+        let actual = manager.observe_pods(&[pod("web", "CrashLoopBackOff", 3)], now());
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn 最初に受け取ったpodは通知せずあとから現れたpodは通知する() {
+        let mut manager = AlertManager::new(AlertRules::default());
+
+        assert!(manager
+            .observe_pods(&[pod("app", "CrashLoopBackOff", 3)], now())
+            .is_empty());
+
+        let actual = manager.observe_pods(
+            &[
+                pod("app", "CrashLoopBackOff", 3),
+                pod("web", "ImagePullBackOff", 0),
+            ],
+            now(),
+        );
+
+        assert_eq!(
+            reasons(&actual),
+            vec![("pod/web".into(), "ImagePullBackOff".into())]
+        );
+
+        // namespaceを切り替えたあとも最初に受け取ったpodは通知しない
+        manager.reset();
+
+        assert!(manager
+            .observe_pods(&[pod("db", "Error", 0)], now() + chrono::Duration::hours(1))
+            .is_empty());
+    }
+
+    #[test]
+    fn 再起動回数が増えたときに通知する() {
+        let mut manager = AlertManager::new(AlertRules::default());
+
+        assert!(manager
+            .observe_pods(&[pod("app", "Running", 1)], now())
+            .is_empty());
+
+        let actual = manager.observe_pods(&[pod("app", "Running", 2)], now());
+
+        assert_eq!(
+            reasons(&actual),
+            vec![("pod/app".into(), "Restarted".into())]
+        );
+        assert_eq!(actual[0].message, "restarts: 1 -> 2");
+    }
+
+    #[test]
+    fn 最初に受け取ったイベントは通知せず新しいwarningイベントを通知する() {
+        let mut manager = AlertManager::new(AlertRules::default());
+
+        assert!(manager
+            .observe_events(&[event("app", "Warning", "BackOff", 1)], now())
+            .is_empty());
+
+        let actual = manager.observe_events(
+            &[
+                event("app", "Warning", "BackOff", 2),
+                event("web", "Normal", "Pulled", 1),
+                event("db", "Warning", "Unhealthy", 1),
+            ],
+            now(),
+        );
+
+        assert_eq!(
+            reasons(&actual),
+            vec![
+                ("pod/app".into(), "BackOff".into()),
+                ("pod/db".into(), "Unhealthy".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn 同じオブジェクトは一定期間通知しない() {
+        let mut manager = AlertManager::new(AlertRules {
+            rate_limit: Duration::from_secs(60),
+            ..Default::default()
+        });
+
+        manager.observe_pods(&[pod("app", "Running", 0)], now());
+
+        let first = manager.observe_pods(&[pod("app", "Running", 1)], now());
+        let second = manager.observe_pods(
+            &[pod("app", "Running", 2)],
+            now() + chrono::Duration::seconds(30),
+        );
+        let third = manager.observe_pods(
+            &[pod("app", "Running", 3)],
+            now() + chrono::Duration::seconds(90),
+        );
+
+        assert_eq!((first.len(), second.len(), third.len()), (1, 0, 1));
+    }
+
+    #[test]
+    fn ミュートしたオブジェクトは通知しない() {
+        let mut manager = AlertManager::new(AlertRules::default());
+
+        manager.observe_pods(&[pod("app", "Running", 0)], now());
+
+        let alerts = manager.observe_pods(&[pod("app", "Error", 0)], now());
+
+        manager.toggle_mute(&alerts[0].object);
+
+        assert!(manager.alerts().is_empty());
+
+        manager.observe_pods(&[pod("app", "Running", 0)], now());
+
+        let actual = manager.observe_pods(
+            &[pod("app", "CrashLoopBackOff", 1)],
+            now() + chrono::Duration::hours(1),
+        );
+
+        assert!(actual.is_empty());
+
+        manager.toggle_mute(&alerts[0].object);
+
+        assert!(manager.muted().is_empty());
+    }
+
+    #[test]
+    fn 確認した通知を一覧から取り除く() {
+        let mut manager = AlertManager::new(AlertRules::default());
+
+        manager.observe_pods(&[], now());
+
+        let alerts =
+            manager.observe_pods(&[pod("app", "Error", 0), pod("web", "OOMKilled", 0)], now());
+
+        manager.acknowledge(alerts[0].id);
+
+        assert_eq!(
+            reasons(manager.alerts()),
+            vec![("pod/web".into(), "OOMKilled".into())]
+        );
+    }
+
+    #[test]
+    fn 無効のときは通知しない() {
+        let mut manager = AlertManager::new(AlertRules {
+            enabled: false,
+            ..Default::default()
+        });
+
+        manager.observe_pods(&[pod("app", "Running", 0)], now());
+
+        let actual = manager.observe_pods(&[pod("app", "CrashLoopBackOff", 0)], now());
+
+        assert!(actual.is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+use std::io::{self, Write as _};
+
+use serde::Deserialize;
+
+use crate::logger;
+
+use super::Alert;
+
+/// 画面のトースト以外の通知方法
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyMethod {
+    #[default]
+    None,
+    /// 端末のベル（BEL）
+    Bell,
+    /// デスクトップ通知（OSC 9、iTerm2やWindows Terminalなど）
+    Osc9,
+    /// デスクトップ通知（OSC 777、rxvtやGNOME系の端末など）
+    Osc777,
+}
+
+impl NotifyMethod {
+    /// 新しい通知をまとめて1回だけ端末に書き込む
+    pub fn notify(&self, alerts: &[Alert]) {
+        let Some(sequence) = self.sequence(alerts) else {
+            return;
+        };
+
+        let mut stdout = io::stdout();
+
+        if let Err(e) = stdout
+            .write_all(sequence.as_bytes())
+            .and_then(|_| stdout.flush())
+        {
+            logger!(error, "Failed to notify alerts: {}", e);
+        }
+    }
+
+    fn sequence(&self, alerts: &[Alert]) -> Option<String> {
+        let alert = alerts.first()?;
+
+        let body = match alerts.len() {
+            1 => alert.summary(),
+            n => format!("{} (+{} more)", alert.summary(), n - 1),
+        };
+
+        match self {
+            Self::None => None,
+            Self::Bell => Some("\x07".into()),
+            Self::Osc9 => Some(format!("\x1b]9;{}\x07", sanitize(&body))),
+            Self::Osc777 => Some(format!("\x1b]777;notify;kubetui;{}\x07", sanitize(&body))),
+        }
+    }
+}
+
+/// エスケープシーケンスを途中で終わらせないように制御文字を取り除く
+fn sanitize(s: &str) -> String {
+    s.chars().filter(|c| !c.is_control()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::features::alert::AlertObject;
+
+    fn alert(name: &str) -> Alert {
+        Alert {
+            id: 0,
+            object: AlertObject {
+                kind: "Pod".into(),
+                namespace: "default".into(),
+                name: name.into(),
+            },
+            reason: "CrashLoopBackOff".into(),
+            message: "back-off\x1b 10s".into(),
+            time: Default::default(),
+        }
+    }
+
+    #[rstest]
+    #[case(NotifyMethod::None, None)]
+    #[case(NotifyMethod::Bell, Some("\x07"))]
+    #[case(
+        NotifyMethod::Osc9,
+        Some("\x1b]9;pod/app (default): CrashLoopBackOff back-off 10s\x07")
+    )]
+    #[case(
+        NotifyMethod::Osc777,
+        Some("\x1b]777;notify;kubetui;pod/app (default): CrashLoopBackOff back-off 10s\x07")
+    )]
+    fn 通知方法に応じたシーケンスを返す(
+        #[case] method: NotifyMethod,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            method.sequence(&[alert("app")]),
+            expected.map(ToString::to_string)
+        );
+    }
+
+    #[test]
+    fn 複数の通知は件数をまとめる() {
+        let actual = NotifyMethod::Osc9.sequence(&[alert("app"), alert("web"), alert("db")]);
+
+        assert_eq!(
+            actual.as_deref(),
+            Some("\x1b]9;pod/app (default): CrashLoopBackOff back-off 10s (+2 more)\x07")
+        );
+    }
+
+    #[test]
+    fn 通知がないときは何も書き込まない() {
+        assert_eq!(NotifyMethod::Bell.sequence(&[]), None);
+    }
+}
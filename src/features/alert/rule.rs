@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use once_cell::sync::OnceCell;
+use regex::Regex;
+
+use super::NotifyMethod;
+
+static ALERT_RULES: OnceCell<AlertRules> = OnceCell::new();
+
+pub const DEFAULT_POD_STATUS: &[&str] = &[
+    "CrashLoopBackOff",
+    "ImagePullBackOff",
+    "ErrImagePull",
+    "OOMKilled",
+    "Error",
+];
+
+pub const DEFAULT_EVENT_REASONS: &[&str] = &[
+    "BackOff",
+    "Failed",
+    "FailedScheduling",
+    "FailedMount",
+    "Unhealthy",
+    "Evicted",
+    "OOMKilling",
+];
+
+pub const DEFAULT_RATE_LIMIT: Duration = Duration::from_secs(300);
+
+/// 通知する条件
+///
+/// 正規表現は部分一致で判定する
+#[derive(Debug, Clone)]
+pub struct AlertRules {
+    pub enabled: bool,
+    /// PodのSTATUSに一致したときに通知する
+    pub pod_status: Vec<Regex>,
+    /// Podの再起動回数が増えたときに通知する
+    pub restarts: bool,
+    /// Warningイベントのreasonに一致したときに通知する
+    pub event_reasons: Vec<Regex>,
+    pub notify: NotifyMethod,
+    /// 同じオブジェクトについて続けて通知しない期間
+    pub rate_limit: Duration,
+}
+
+impl Default for AlertRules {
+    fn default() -> Self {
+        let regexes = |patterns: &[&str]| {
+            patterns
+                .iter()
+                .map(|p| Regex::new(p).expect("invalid default pattern"))
+                .collect()
+        };
+
+        Self {
+            enabled: true,
+            pod_status: regexes(DEFAULT_POD_STATUS),
+            restarts: true,
+            event_reasons: regexes(DEFAULT_EVENT_REASONS),
+            notify: NotifyMethod::default(),
+            rate_limit: DEFAULT_RATE_LIMIT,
+        }
+    }
+}
+
+impl AlertRules {
+    /// 起動時に1度だけ設定する
+    pub fn init(self) {
+        let _ = ALERT_RULES.set(self);
+    }
+
+    pub fn global() -> &'static Self {
+        ALERT_RULES.get_or_init(Self::default)
+    }
+
+    pub fn matches_pod_status(&self, status: &str) -> bool {
+        self.pod_status.iter().any(|re| re.is_match(status))
+    }
+
+    pub fn matches_event_reason(&self, reason: &str) -> bool {
+        self.event_reasons.iter().any(|re| re.is_match(reason))
+    }
+}
@@ -0,0 +1,274 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use chrono::Local;
+use ratatui::{style::Style, text::Line};
+
+use crate::{
+    features::component_id::ALERT_POPUP_ID,
+    ui::{
+        event::EventResult,
+        theme::{paint, Theme},
+        widget::{config::WidgetConfig, SelectedItem, Table, TableItem, Widget, WidgetTrait as _},
+        Toast, Window,
+    },
+};
+
+use super::{AlertManager, AlertObject};
+
+const HEADER: [&str; 5] = ["TIME", "NAMESPACE", "OBJECT", "REASON", "MESSAGE"];
+
+/// トーストに表示する通知の数
+const TOAST_LINES: usize = 3;
+
+pub struct AlertPopup {
+    pub popup: Widget<'static>,
+}
+
+impl AlertPopup {
+    pub fn new(alerts: &Rc<RefCell<AlertManager>>) -> Self {
+        Self {
+            popup: popup(alerts),
+        }
+    }
+}
+
+fn popup(alerts: &Rc<RefCell<AlertManager>>) -> Widget<'static> {
+    Table::builder()
+        .id(ALERT_POPUP_ID)
+        .widget_config(
+            &WidgetConfig::builder()
+                .title("Alerts")
+                .append_title(" (Enter: acknowledge, a: acknowledge all, m: mute/unmute)")
+                .build(),
+        )
+        .header(HEADER.map(String::from))
+        .on_select(acknowledge(alerts.clone()))
+        .action('a', acknowledge_all(alerts.clone()))
+        .action('m', toggle_mute(alerts.clone()))
+        .build()
+        .into()
+}
+
+/// 未確認の通知の件数と最新の通知をトーストに表示する
+pub fn alert_toast(alerts: Rc<RefCell<AlertManager>>) -> Toast {
+    Toast::new(
+        " Alert ",
+        Style::default().fg(Theme::global().error),
+        move || toast_lines(&alerts.borrow()),
+    )
+}
+
+/// 通知の一覧を最新の状態にする
+pub fn update_alert_popup(w: &mut Window, alerts: &AlertManager) {
+    let header = HEADER.map(String::from);
+
+    w.find_widget_mut(ALERT_POPUP_ID)
+        .as_mut_table()
+        .update_header_and_rows(&header, &alert_items(alerts));
+}
+
+fn toast_lines(alerts: &AlertManager) -> Vec<Line<'static>> {
+    let alerts = alerts.alerts();
+
+    let mut lines: Vec<Line> = alerts
+        .iter()
+        .take(TOAST_LINES)
+        .map(|alert| {
+            Line::from(format!(
+                "{} {} ({}) {} {}",
+                alert.time.with_timezone(&Local).format("%H:%M:%S"),
+                alert.object,
+                alert.object.namespace,
+                alert.reason,
+                alert.message
+            ))
+        })
+        .collect();
+
+    if TOAST_LINES < alerts.len() {
+        lines.push(Line::from(format!("+{} more", alerts.len() - TOAST_LINES)));
+    }
+
+    lines
+}
+
+/// 未確認の通知のあとにミュート中のオブジェクトを並べる
+fn alert_items(alerts: &AlertManager) -> Vec<TableItem> {
+    let theme = Theme::global();
+
+    let metadata = |object: &AlertObject| {
+        BTreeMap::from([
+            ("kind".to_string(), object.kind.clone()),
+            ("namespace".to_string(), object.namespace.clone()),
+            ("name".to_string(), object.name.clone()),
+        ])
+    };
+
+    let active = alerts.alerts().iter().map(|alert| {
+        let mut metadata = metadata(&alert.object);
+        metadata.insert("id".to_string(), alert.id.to_string());
+
+        TableItem {
+            metadata: Some(metadata),
+            item: vec![
+                alert
+                    .time
+                    .with_timezone(&Local)
+                    .format("%H:%M:%S")
+                    .to_string(),
+                alert.object.namespace.clone(),
+                alert.object.to_string(),
+                paint(theme.error, &alert.reason),
+                alert.message.clone(),
+            ],
+        }
+    });
+
+    let muted = alerts.muted().iter().map(|object| TableItem {
+        metadata: Some(metadata(object)),
+        item: vec![
+            String::new(),
+            object.namespace.clone(),
+            object.to_string(),
+            paint(theme.muted, "Muted"),
+            String::new(),
+        ],
+    });
+
+    active.chain(muted).collect()
+}
+
+fn object_from_metadata(metadata: &BTreeMap<String, String>) -> Option<AlertObject> {
+    Some(AlertObject {
+        kind: metadata.get("kind")?.clone(),
+        namespace: metadata.get("namespace")?.clone(),
+        name: metadata.get("name")?.clone(),
+    })
+}
+
+/// 選択した通知を確認済みにする（ミュート中の行ではミュートを解除する）
+fn acknowledge(
+    alerts: Rc<RefCell<AlertManager>>,
+) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        let Some(metadata) = &v.metadata else {
+            return EventResult::Ignore;
+        };
+
+        let mut alerts = alerts.borrow_mut();
+
+        match metadata.get("id").and_then(|id| id.parse().ok()) {
+            Some(id) => alerts.acknowledge(id),
+            None => {
+                let Some(object) = object_from_metadata(metadata) else {
+                    return EventResult::Ignore;
+                };
+
+                alerts.toggle_mute(&object);
+            }
+        }
+
+        update_alert_popup(w, &alerts);
+
+        EventResult::Nop
+    }
+}
+
+fn acknowledge_all(alerts: Rc<RefCell<AlertManager>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut alerts = alerts.borrow_mut();
+
+        alerts.acknowledge_all();
+
+        update_alert_popup(w, &alerts);
+
+        EventResult::Nop
+    }
+}
+
+/// 選択した行のオブジェクトのミュートを切り替える
+fn toggle_mute(alerts: Rc<RefCell<AlertManager>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = w.find_widget(ALERT_POPUP_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let Some(object) = object_from_metadata(&metadata) else {
+            return EventResult::Ignore;
+        };
+
+        let mut alerts = alerts.borrow_mut();
+
+        alerts.toggle_mute(&object);
+
+        update_alert_popup(w, &alerts);
+
+        EventResult::Nop
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone as _, Utc};
+    use pretty_assertions::assert_eq;
+
+    use crate::{features::alert::AlertRules, kube::table::KubeTableRow};
+
+    fn manager(names: &[&str]) -> AlertManager {
+        let mut manager = AlertManager::new(AlertRules::default());
+
+        let rows: Vec<KubeTableRow> = names
+            .iter()
+            .map(|name| KubeTableRow {
+                namespace: "default".into(),
+                name: name.to_string(),
+                metadata: Some(BTreeMap::from([("status".into(), "Error".into())])),
+                ..Default::default()
+            })
+            .collect();
+
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        // 最初に受け取ったPodは通知しない
+        manager.observe_pods(&[], now);
+        manager.observe_pods(&rows, now);
+
+        manager
+    }
+
+    #[test]
+    fn トーストには最新の通知と残りの件数を表示する() {
+        let actual = toast_lines(&manager(&["a", "b", "c", "d", "e"]));
+
+        assert_eq!(actual.len(), TOAST_LINES + 1);
+        assert!(actual[0].to_string().contains("pod/e (default) Error"));
+        assert_eq!(actual[3].to_string(), "+2 more");
+    }
+
+    #[test]
+    fn ミュート中のオブジェクトは通知のあとに並べる() {
+        let mut manager = manager(&["a", "b"]);
+
+        let object = manager.alerts()[0].object.clone();
+
+        manager.toggle_mute(&object);
+
+        let actual: Vec<(Option<String>, String)> = alert_items(&manager)
+            .into_iter()
+            .map(|item| {
+                let metadata = item.metadata.unwrap();
+                (metadata.get("id").cloned(), metadata["name"].clone())
+            })
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![(Some("0".into()), "a".into()), (None, "b".into())]
+        );
+    }
+}
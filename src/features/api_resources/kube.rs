@@ -1,3 +1,7 @@
 mod api_resources;
+mod columns;
+mod table;
 
 pub use api_resources::*;
+pub use columns::*;
+pub use table::*;
@@ -1,4 +1,4 @@
-use std::{collections::BTreeSet, fmt::Display, hash::Hash, ops::Deref, sync::Arc, time};
+use std::{collections::BTreeSet, fmt::Display, hash::Hash, ops::Deref, sync::Arc};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -12,7 +12,13 @@ use serde_json::Value as JsonValue;
 use tokio::{sync::RwLock, time::Instant};
 
 use crate::{
-    features::api_resources::message::ApiResponse,
+    features::api_resources::{
+        kube::{
+            is_wide, needs_object, project, wide_changed, ApiTable, ListColumns,
+            INCLUDE_OBJECT_QUERY, NAMESPACE_COLUMN,
+        },
+        message::ApiResponse,
+    },
     kube::{
         apis::{
             metrics::{NodeMetricsList, PodMetricsList},
@@ -21,9 +27,12 @@ use crate::{
         table::insert_ns,
         KubeClient, KubeClientRequest as _,
     },
-    workers::kube::{
-        PollerBase, SharedTargetApiResources, TargetApiResources, TargetNamespaces, Worker,
-        WorkerResult,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::{
+            PollerBase, SharedTargetApiResources, TargetApiResources, TargetNamespaces, Worker,
+            WorkerResult,
+        },
     },
 };
 
@@ -38,6 +47,53 @@ impl ApiResources {
     pub fn to_vec(&self) -> Vec<ApiResource> {
         self.inner.clone().into_iter().collect()
     }
+
+    /// kind（またはリソース名）からAPIを探す
+    ///
+    /// 複数のグループやバージョンに一致するときはコアグループ、優先バージョンの順に選ぶ
+    pub fn find_by_kind(&self, kind: &str) -> Option<&ApiResource> {
+        self.inner
+            .iter()
+            .filter(|api| api.kind() == kind || api.name() == kind.to_lowercase())
+            .max_by_key(|api| (api.is_api(), api.is_preferred_version()))
+    }
+
+    /// `pods`、`deployments.apps`のようなキーからAPIを探す（優先バージョンを選ぶ）
+    pub fn find_by_key(&self, key: &str) -> Option<&ApiResource> {
+        self.inner
+            .iter()
+            .filter(|api| api.key() == key)
+            .max_by_key(|api| api.is_preferred_version())
+    }
+
+    /// マニフェストのapiVersionとkindからAPIを探す
+    pub fn find_by_api_version(&self, api_version: &str, kind: &str) -> Option<&ApiResource> {
+        self.inner
+            .iter()
+            .find(|api| api.api_version() == api_version && api.kind() == kind)
+    }
+
+    /// 行やイベントのapiVersionとkindからAPIを探す
+    ///
+    /// バージョンが提供されていないときは同じグループの優先バージョンを選ぶ。
+    /// apiVersionが分からないときだけkindから探す
+    pub fn resolve(&self, api_version: Option<&str>, kind: &str) -> Option<&ApiResource> {
+        let Some(api_version) = api_version.filter(|v| !v.is_empty()) else {
+            return self.find_by_kind(kind);
+        };
+
+        let group = api_version
+            .rsplit_once('/')
+            .map(|(group, _)| group)
+            .unwrap_or_default();
+
+        self.find_by_api_version(api_version, kind).or_else(|| {
+            self.inner
+                .iter()
+                .filter(|api| api.group() == group && api.kind() == kind)
+                .max_by_key(|api| api.is_preferred_version())
+        })
+    }
 }
 
 impl Deref for ApiResources {
@@ -58,6 +114,7 @@ impl From<BTreeSet<ApiResource>> for ApiResources {
 pub enum ApiResource {
     Apis {
         name: String,
+        kind: String,
         group: String,
         version: String,
         preferred_version: bool,
@@ -66,6 +123,7 @@ pub enum ApiResource {
     },
     Api {
         name: String,
+        kind: String,
         version: String,
         #[serde(with = "scope_format")]
         scope: Scope,
@@ -135,6 +193,37 @@ impl ApiResource {
         }
     }
 
+    pub fn kind(&self) -> &str {
+        match self {
+            Self::Api { kind, .. } => kind,
+            Self::Apis { kind, .. } => kind,
+        }
+    }
+
+    /// コアグループは空文字
+    pub fn group(&self) -> &str {
+        match self {
+            Self::Api { .. } => "",
+            Self::Apis { group, .. } => group,
+        }
+    }
+
+    /// バージョンを含まないリソース名（グループがあるときは`deployments.apps`のようにする）
+    pub fn key(&self) -> String {
+        match self {
+            Self::Api { name, .. } => name.to_string(),
+            Self::Apis { name, group, .. } => format!("{}.{}", name, group),
+        }
+    }
+
+    /// マニフェストのapiVersion
+    pub fn api_version(&self) -> String {
+        match self {
+            Self::Apis { group, version, .. } => format!("{}/{}", group, version),
+            Self::Api { version, .. } => version.to_string(),
+        }
+    }
+
     pub fn group_version_url(&self) -> String {
         match self {
             Self::Apis { group, version, .. } => format!("apis/{}/{}", group, version),
@@ -161,10 +250,6 @@ impl ApiResource {
             Self::Apis { scope, .. } => scope,
         }
     }
-
-    fn to_table_header(&self) -> String {
-        format!("\x1b[90m[ {} ]\x1b[0m\n", self)
-    }
 }
 
 impl Ord for ApiResource {
@@ -251,15 +336,19 @@ impl Worker for ApiPoller {
             }
         }
 
-        let mut interval = tokio::time::interval(time::Duration::from_millis(1000));
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().api);
 
         let mut last_tick = Instant::now();
-        let tick_rate = time::Duration::from_secs(10);
+        let tick_rate = IntervalConfig::global().api_discovery;
 
         let mut is_error = false;
 
         while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
-            interval.tick().await;
+            // 表示する列を切り替えたらすぐに取得し直す
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = wide_changed() => {}
+            }
 
             if tick_rate < last_tick.elapsed() {
                 last_tick = Instant::now();
@@ -277,7 +366,10 @@ impl Worker for ApiPoller {
                         }
                     }
                     Err(err) => {
-                        tx.send(ApiResponse::Poll(Err(err)).into())
+                        let result = Err(err);
+                        interval.record(&result);
+
+                        tx.send(ApiResponse::Poll(result).into())
                             .expect("Failed to send ApiResponse::Poll");
                         is_error = true;
                         continue;
@@ -300,6 +392,8 @@ impl Worker for ApiPoller {
             .fetch_table()
             .await;
 
+            interval.record(&result);
+
             tx.send(ApiResponse::Poll(result).into())
                 .expect("Failed to send ApiResponse::Poll");
         }
@@ -332,12 +426,14 @@ pub async fn fetch_api_resources(client: &KubeClient) -> Result<ApiResources> {
                             if group.name() == ApiGroup::CORE_GROUP {
                                 Some(ApiResource::Api {
                                     name: ar.plural.to_string(),
+                                    kind: ar.kind.to_string(),
                                     version: ar.version.to_string(),
                                     scope: caps.scope.clone(),
                                 })
                             } else {
                                 Some(ApiResource::Apis {
                                     name: ar.plural.to_string(),
+                                    kind: ar.kind.to_string(),
                                     group: ar.group.to_string(),
                                     version: ar.version.to_string(),
                                     preferred_version: is_preferred_version,
@@ -364,7 +460,7 @@ fn merge_tables(fetch_data: Vec<FetchData>, insert_ns: bool) -> Table {
 
     if insert_ns {
         let column_definitions = TableColumnDefinition {
-            name: "Namespace".to_string(),
+            name: NAMESPACE_COLUMN.to_string(),
             ..Default::default()
         };
 
@@ -432,12 +528,12 @@ async fn get_table_namespaced_resource(
     client: &KubeClient,
     api_resource: &ApiResource,
     namespaces: &[String],
+    query: &str,
 ) -> Result<Table> {
-    let jobs =
-        try_join_all(namespaces.iter().map(|ns| {
-            fetch_table_per_namespace(client, api_resource.api_url_with_namespace(ns), ns)
-        }))
-        .await?;
+    let jobs = try_join_all(namespaces.iter().map(|ns| {
+        fetch_table_per_namespace(client, api_resource.api_url_with_namespace(ns) + query, ns)
+    }))
+    .await?;
 
     let result: Vec<FetchData> = jobs.into_iter().collect();
 
@@ -468,24 +564,32 @@ impl<'a> FetchTargetApiResources<'a> {
         }
     }
 
-    async fn fetch_table(&self) -> Result<Vec<String>> {
+    async fn fetch_table(&self) -> Result<Vec<ApiTable>> {
         let mut ret = Vec::new();
         for api_resource in self.target_api_resources {
+            let columns = ListColumns::global().get(api_resource);
+
+            let query = if needs_object(columns) {
+                INCLUDE_OBJECT_QUERY
+            } else {
+                ""
+            };
+
             let table = if api_resource.is_namespaced() {
-                get_table_namespaced_resource(self.client, api_resource, self.target_namespace)
-                    .await
+                get_table_namespaced_resource(
+                    self.client,
+                    api_resource,
+                    self.target_namespace,
+                    query,
+                )
+                .await
             } else {
-                get_table_cluster_resource(self.client, &api_resource.api_url()).await
+                get_table_cluster_resource(self.client, &(api_resource.api_url() + query)).await
             }?;
 
-            let data = if table.rows.is_empty() {
-                api_resource.to_table_header()
-            } else {
-                api_resource.to_table_header() + &table.to_print()
-            };
+            let table = project(table, columns, is_wide());
 
-            ret.extend(data.lines().map(ToString::to_string).collect::<Vec<_>>());
-            ret.push("".to_string());
+            ret.push(ApiTable::new(api_resource, table, self.target_namespace));
         }
 
         Ok(ret)
@@ -502,12 +606,119 @@ mod tests {
         use rstest::rstest;
 
         #[rstest]
-        #[case(ApiResource::Api { name: "pods".into(), version: "v1".into(), scope: Scope::Namespaced }, "pods")]
-        #[case(ApiResource::Apis { name: "horizontalpodautoscalers".into(), group: "autoscaling".into(), version: "v2".into(), preferred_version: true, scope: Scope::Namespaced }, "horizontalpodautoscalers.autoscaling (*v2)")]
-        #[case(ApiResource::Apis { name: "horizontalpodautoscalers".into(), group: "autoscaling".into(), version: "v1".into(), preferred_version: false, scope: Scope::Namespaced }, "horizontalpodautoscalers.autoscaling (v1)")]
+        #[case(ApiResource::Api { name: "pods".into(), kind: "Pod".into(), version: "v1".into(), scope: Scope::Namespaced }, "pods")]
+        #[case(ApiResource::Apis { name: "horizontalpodautoscalers".into(), kind: "HorizontalPodAutoscaler".into(), group: "autoscaling".into(), version: "v2".into(), preferred_version: true, scope: Scope::Namespaced }, "horizontalpodautoscalers.autoscaling (*v2)")]
+        #[case(ApiResource::Apis { name: "horizontalpodautoscalers".into(), kind: "HorizontalPodAutoscaler".into(), group: "autoscaling".into(), version: "v1".into(), preferred_version: false, scope: Scope::Namespaced }, "horizontalpodautoscalers.autoscaling (v1)")]
         #[test]
         fn to_string(#[case] key: ApiResource, #[case] expected: &str) {
             assert_eq!(key.to_string(), expected)
         }
     }
+
+    mod api_resources {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        fn apis(group: &str, version: &str, preferred_version: bool) -> ApiResource {
+            ApiResource::Apis {
+                name: "events".into(),
+                kind: "Event".into(),
+                group: group.into(),
+                version: version.into(),
+                preferred_version,
+                scope: Scope::Namespaced,
+            }
+        }
+
+        fn api_resources(apis: impl IntoIterator<Item = ApiResource>) -> ApiResources {
+            apis.into_iter().collect::<BTreeSet<_>>().into()
+        }
+
+        #[test]
+        fn コアグループのapiを優先する() {
+            let core = ApiResource::Api {
+                name: "events".into(),
+                kind: "Event".into(),
+                version: "v1".into(),
+                scope: Scope::Namespaced,
+            };
+
+            let resources = api_resources([
+                apis("events.k8s.io", "v1", true),
+                core.clone(),
+                apis("events.k8s.io", "v1beta1", false),
+            ]);
+
+            assert_eq!(resources.find_by_kind("Event"), Some(&core));
+        }
+
+        #[rstest]
+        #[case("Event")]
+        #[case("events")]
+        fn 優先バージョンのapiを選ぶ(#[case] kind: &str) {
+            let resources = api_resources([
+                apis("events.k8s.io", "v1beta1", false),
+                apis("events.k8s.io", "v1", true),
+            ]);
+
+            assert_eq!(
+                resources.find_by_kind(kind),
+                Some(&apis("events.k8s.io", "v1", true))
+            );
+        }
+
+        #[rstest]
+        #[case("events", Some(ApiResource::Api { name: "events".into(), kind: "Event".into(), version: "v1".into(), scope: Scope::Namespaced }))]
+        #[case("events.events.k8s.io", Some(apis("events.k8s.io", "v1", true)))]
+        #[case("events.apps", None)]
+        fn キーからグループの一致するapiを選ぶ(
+            #[case] key: &str,
+            #[case] expected: Option<ApiResource>,
+        ) {
+            let resources = api_resources([
+                apis("events.k8s.io", "v1beta1", false),
+                apis("events.k8s.io", "v1", true),
+                ApiResource::Api {
+                    name: "events".into(),
+                    kind: "Event".into(),
+                    version: "v1".into(),
+                    scope: Scope::Namespaced,
+                },
+            ]);
+
+            assert_eq!(resources.find_by_key(key), expected.as_ref());
+        }
+
+        #[rstest]
+        #[case(Some("example.com/v1"), Some(crd("example.com", "v1")))]
+        #[case(Some("example.io/v1alpha1"), Some(crd("example.io", "v1")))]
+        #[case(Some("example.net/v1"), None)]
+        fn apiversionのグループが一致するapiを選ぶ(
+            #[case] api_version: Option<&str>,
+            #[case] expected: Option<ApiResource>,
+        ) {
+            let resources = api_resources([crd("example.com", "v1"), crd("example.io", "v1")]);
+
+            assert_eq!(resources.resolve(api_version, "Widget"), expected.as_ref());
+        }
+
+        fn crd(group: &str, version: &str) -> ApiResource {
+            ApiResource::Apis {
+                name: "widgets".into(),
+                kind: "Widget".into(),
+                group: group.into(),
+                version: version.into(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            }
+        }
+
+        #[test]
+        fn 一致するapiがなければnone() {
+            let resources = api_resources([apis("events.k8s.io", "v1", true)]);
+
+            assert_eq!(resources.find_by_kind("Pod"), None);
+        }
+    }
 }
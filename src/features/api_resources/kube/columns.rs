@@ -0,0 +1,311 @@
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use once_cell::sync::OnceCell;
+use serde_json::Value as JsonValue;
+use tokio::sync::Notify;
+
+use crate::{
+    features::yaml::output::json_path,
+    kube::apis::v1_table::{Table, TableColumnDefinition, TableRow, Value},
+};
+
+use super::ApiResource;
+
+static LIST_COLUMNS: OnceCell<ListColumns> = OnceCell::new();
+
+static WIDE: AtomicBool = AtomicBool::new(false);
+static WIDE_CHANGED: Notify = Notify::const_new();
+
+/// JSONPathの列を取得するときに付けるクエリ（行にオブジェクト全体を含める）
+pub const INCLUDE_OBJECT_QUERY: &str = "?includeObject=Object";
+
+/// 複数のnamespaceを表示するときに先頭に追加する列
+pub const NAMESPACE_COLUMN: &str = "Namespace";
+
+/// Listタブに表示する列
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    /// サーバーが返す列（大文字小文字を区別しない）
+    Printer(String),
+    /// オブジェクトから値を取り出す列
+    JsonPath { name: String, path: String },
+}
+
+impl Column {
+    fn name(&self) -> &str {
+        match self {
+            Self::Printer(name) | Self::JsonPath { name, .. } => name,
+        }
+    }
+}
+
+/// APIリソースごとのカスタム列
+///
+/// キーは`pods`のようなリソース名、または`deployments.apps`のようなグループ付きのリソース名
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListColumns(BTreeMap<String, Vec<Column>>);
+
+impl ListColumns {
+    pub fn new(columns: BTreeMap<String, Vec<Column>>) -> Self {
+        Self(columns)
+    }
+
+    /// 起動時に1度だけ設定する
+    pub fn init(self) {
+        let _ = LIST_COLUMNS.set(self);
+    }
+
+    pub fn global() -> &'static Self {
+        LIST_COLUMNS.get_or_init(Self::default)
+    }
+
+    /// グループ付きのリソース名を優先する
+    pub fn get(&self, api: &ApiResource) -> Option<&[Column]> {
+        self.0
+            .get(&api.key())
+            .or_else(|| self.0.get(api.name()))
+            .map(Vec::as_slice)
+    }
+}
+
+/// priorityが1以上の列も表示するかどうか
+pub fn is_wide() -> bool {
+    WIDE.load(Ordering::Relaxed)
+}
+
+pub fn toggle_wide() {
+    WIDE.fetch_xor(true, Ordering::Relaxed);
+
+    WIDE_CHANGED.notify_waiters();
+}
+
+/// 表示する列の設定が変わるまで待つ
+pub async fn wide_changed() {
+    WIDE_CHANGED.notified().await;
+}
+
+/// JSONPathの列があるときは行にオブジェクト全体が必要になる
+pub fn needs_object(columns: Option<&[Column]>) -> bool {
+    columns
+        .into_iter()
+        .flatten()
+        .any(|column| matches!(column, Column::JsonPath { .. }))
+}
+
+/// カスタム列を指定したときはその列だけにする（先頭のNamespaceの列と行のオブジェクトは残す）
+///
+/// カスタム列がないときはwideのときだけpriorityが1以上の列も表示する
+pub fn project(mut table: Table, columns: Option<&[Column]>, wide: bool) -> Table {
+    let Some(columns) = columns else {
+        if wide {
+            table
+                .column_definitions
+                .iter_mut()
+                .for_each(|column| column.priority = 0);
+        }
+
+        return table;
+    };
+
+    let has_namespace = table
+        .column_definitions
+        .first()
+        .is_some_and(|column| column.name == NAMESPACE_COLUMN);
+
+    let namespace = has_namespace.then(|| Column::Printer(NAMESPACE_COLUMN.into()));
+
+    // サーバーが返さない列は表示しない
+    let columns: Vec<(&Column, Option<usize>)> = namespace
+        .iter()
+        .chain(columns)
+        .filter_map(|column| match column {
+            Column::Printer(name) => table
+                .column_definitions
+                .iter()
+                .position(|c| c.name.eq_ignore_ascii_case(name))
+                .map(|i| (column, Some(i))),
+            Column::JsonPath { .. } => Some((column, None)),
+        })
+        .collect();
+
+    let column_definitions = columns
+        .iter()
+        .map(|(column, index)| TableColumnDefinition {
+            name: match index {
+                Some(i) => table.column_definitions[*i].name.clone(),
+                None => column.name().to_string(),
+            },
+            ..Default::default()
+        })
+        .collect();
+
+    let rows = table
+        .rows
+        .iter()
+        .map(|row| TableRow {
+            cells: columns
+                .iter()
+                .map(|(column, index)| match (column, index) {
+                    (_, Some(i)) => row.cells.get(*i).cloned().unwrap_or_default(),
+                    (Column::JsonPath { path, .. }, None) => json_path_cell(row, path),
+                    (Column::Printer(_), None) => Value::default(),
+                })
+                .collect(),
+            object: row.object.clone(),
+            ..Default::default()
+        })
+        .collect();
+
+    Table {
+        column_definitions,
+        rows,
+        ..table
+    }
+}
+
+/// 一致した値をカンマでつなげる（一致しないときは`<none>`）
+fn json_path_cell(row: &TableRow, path: &str) -> Value {
+    let values = row
+        .object
+        .as_ref()
+        .and_then(|object| json_path(path, &object.0).ok())
+        .unwrap_or_default();
+
+    let value = if values.is_empty() {
+        "<none>".to_string()
+    } else {
+        values.join(",")
+    };
+
+    Value(JsonValue::String(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::runtime::RawExtension;
+    use kube::discovery::Scope;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn table() -> Table {
+        let column = |name: &str, priority: i32| TableColumnDefinition {
+            name: name.into(),
+            priority,
+            ..Default::default()
+        };
+
+        let cell = |value: &str| Value(JsonValue::String(value.into()));
+
+        Table {
+            column_definitions: vec![
+                column("Namespace", 0),
+                column("Name", 0),
+                column("Ready", 0),
+                column("Node", 1),
+            ],
+            rows: vec![TableRow {
+                cells: vec![cell("default"), cell("app"), cell("1/1"), cell("node-1")],
+                object: Some(RawExtension(json!({
+                    "spec": {"containers": [{"image": "nginx"}, {"image": "envoy"}]}
+                }))),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn printed(table: &Table) -> Vec<(String, i32)> {
+        table
+            .column_definitions
+            .iter()
+            .map(|c| (c.name.clone(), c.priority))
+            .collect()
+    }
+
+    #[test]
+    fn wideのときはpriorityが1以上の列も表示する() {
+        let actual = project(table(), None, true);
+
+        assert_eq!(
+            printed(&actual),
+            vec![
+                ("Namespace".into(), 0),
+                ("Name".into(), 0),
+                ("Ready".into(), 0),
+                ("Node".into(), 0),
+            ]
+        );
+
+        let actual = project(table(), None, false);
+
+        assert_eq!(actual.column_definitions[3].priority, 1);
+    }
+
+    #[test]
+    fn カスタム列はnamespaceの列に続けて指定した順に並べる() {
+        let columns = [
+            Column::Printer("NODE".into()),
+            Column::Printer("NAME".into()),
+            Column::Printer("UNKNOWN".into()),
+            Column::JsonPath {
+                name: "IMAGES".into(),
+                path: ".spec.containers[*].image".into(),
+            },
+            Column::JsonPath {
+                name: "IP".into(),
+                path: ".status.podIP".into(),
+            },
+        ];
+
+        let actual = project(table(), Some(&columns), false);
+
+        assert_eq!(
+            printed(&actual),
+            vec![
+                ("Namespace".into(), 0),
+                ("Node".into(), 0),
+                ("Name".into(), 0),
+                ("IMAGES".into(), 0),
+                ("IP".into(), 0),
+            ]
+        );
+
+        assert_eq!(
+            actual.rows[0]
+                .cells
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["default", "node-1", "app", "nginx,envoy", "<none>"]
+        );
+    }
+
+    #[test]
+    fn グループ付きのリソース名を優先する() {
+        let deployments = ApiResource::Apis {
+            name: "deployments".into(),
+            kind: "Deployment".into(),
+            group: "apps".into(),
+            version: "v1".into(),
+            preferred_version: true,
+            scope: Scope::Namespaced,
+        };
+
+        let columns = ListColumns::new(BTreeMap::from([
+            ("deployments".into(), vec![Column::Printer("NAME".into())]),
+            (
+                "deployments.apps".into(),
+                vec![Column::Printer("READY".into())],
+            ),
+        ]));
+
+        assert_eq!(
+            columns.get(&deployments),
+            Some([Column::Printer("READY".into())].as_slice())
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value as JsonValue;
+
+use crate::kube::{
+    apis::v1_table::{Table, TableRow},
+    table::{KubeTable, KubeTableRow},
+};
+
+use super::{ApiResource, NAMESPACE_COLUMN};
+
+/// Listタブに表示するAPIリソースごとの表
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiTable {
+    pub resource: ApiResource,
+    pub table: KubeTable,
+}
+
+impl ApiTable {
+    /// サーバーが返した表を、行ごとにリソースを特定できるメタデータ付きの表にする
+    ///
+    /// 1つのnamespaceだけを表示するときはNamespaceの列がないため、行のオブジェクトか指定したnamespaceを使う
+    pub fn new(resource: &ApiResource, table: Table, namespaces: &[String]) -> Self {
+        let indexes: Vec<usize> = table
+            .column_definitions
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.priority == 0)
+            .map(|(i, _)| i)
+            .collect();
+
+        let header = indexes
+            .iter()
+            .map(|i| table.column_definitions[*i].name.to_uppercase())
+            .collect();
+
+        let name_index = table
+            .column_definitions
+            .iter()
+            .position(|column| column.name.eq_ignore_ascii_case("name"));
+
+        let namespace_index = table
+            .column_definitions
+            .first()
+            .is_some_and(|column| column.name == NAMESPACE_COLUMN)
+            .then_some(0);
+
+        let default_namespace = match namespaces {
+            [namespace] => namespace.as_str(),
+            _ => "",
+        };
+
+        let rows = table
+            .rows
+            .iter()
+            .map(|row| {
+                let name = object_metadata(row, "name")
+                    .or_else(|| cell(row, name_index))
+                    .unwrap_or_default();
+
+                let namespace = if resource.is_namespaced() {
+                    object_metadata(row, "namespace")
+                        .or_else(|| cell(row, namespace_index))
+                        .unwrap_or_else(|| default_namespace.to_string())
+                } else {
+                    String::default()
+                };
+
+                let mut metadata = BTreeMap::from([
+                    ("kind".into(), resource.kind().to_string()),
+                    ("api_version".into(), resource.api_version()),
+                ]);
+
+                if let Some(kind) = log_query_kind(resource) {
+                    metadata.insert("log_query".into(), format!("{}/{}", kind, name));
+                }
+
+                KubeTableRow {
+                    namespace,
+                    name,
+                    metadata: Some(metadata),
+                    row: indexes
+                        .iter()
+                        .map(|i| {
+                            row.cells
+                                .get(*i)
+                                .map(ToString::to_string)
+                                .unwrap_or_default()
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Self {
+            resource: resource.clone(),
+            table: KubeTable { header, rows },
+        }
+    }
+}
+
+fn object_metadata(row: &TableRow, key: &str) -> Option<String> {
+    row.object
+        .as_ref()
+        .and_then(|object| object.0["metadata"][key].as_str())
+        .map(ToString::to_string)
+}
+
+fn cell(row: &TableRow, index: Option<usize>) -> Option<String> {
+    index
+        .and_then(|i| row.cells.get(i))
+        .and_then(|value| match &value.0 {
+            JsonValue::String(s) => Some(s.to_string()),
+            _ => None,
+        })
+}
+
+/// ログのクエリで指定できるリソースのとき、クエリに使う種類を返す
+fn log_query_kind(resource: &ApiResource) -> Option<&'static str> {
+    let kind = match resource {
+        ApiResource::Api { name, .. } => match name.as_str() {
+            "pods" => "pod",
+            "services" => "service",
+            _ => return None,
+        },
+        ApiResource::Apis { name, group, .. } => match (group.as_str(), name.as_str()) {
+            ("apps", "deployments") => "deployment",
+            ("apps", "daemonsets") => "daemonset",
+            ("apps", "replicasets") => "replicaset",
+            ("apps", "statefulsets") => "statefulset",
+            ("batch", "jobs") => "job",
+            _ => return None,
+        },
+    };
+
+    Some(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kube::apis::v1_table::{TableColumnDefinition, Value};
+    use k8s_openapi::apimachinery::pkg::runtime::RawExtension;
+    use kube::discovery::Scope;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    fn apis(group: &str, name: &str, kind: &str) -> ApiResource {
+        ApiResource::Apis {
+            name: name.into(),
+            kind: kind.into(),
+            group: group.into(),
+            version: "v1".into(),
+            preferred_version: true,
+            scope: Scope::Namespaced,
+        }
+    }
+
+    fn table(object: Option<JsonValue>) -> Table {
+        let column = |name: &str, priority: i32| TableColumnDefinition {
+            name: name.into(),
+            priority,
+            ..Default::default()
+        };
+
+        let cell = |value: &str| Value(JsonValue::String(value.into()));
+
+        Table {
+            column_definitions: vec![
+                column("Namespace", 0),
+                column("Name", 0),
+                column("Ready", 0),
+                column("Node", 1),
+            ],
+            rows: vec![TableRow {
+                cells: vec![cell("default"), cell("app"), cell("1/1"), cell("node-1")],
+                object: object.map(RawExtension),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn 行のオブジェクトからリソースを特定する() {
+        let object = json!({"metadata": {"name": "web", "namespace": "prod"}});
+
+        let actual = ApiTable::new(
+            &apis("apps", "deployments", "Deployment"),
+            table(Some(object)),
+            &["default".into(), "prod".into()],
+        );
+
+        assert_eq!(
+            actual.table,
+            KubeTable {
+                header: vec!["NAMESPACE".into(), "NAME".into(), "READY".into()],
+                rows: vec![KubeTableRow {
+                    namespace: "prod".into(),
+                    name: "web".into(),
+                    metadata: Some(BTreeMap::from([
+                        ("kind".into(), "Deployment".into()),
+                        ("api_version".into(), "apps/v1".into()),
+                        ("log_query".into(), "deployment/web".into()),
+                    ])),
+                    row: vec!["default".into(), "app".into(), "1/1".into()],
+                }],
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["default".into(), "prod".into()], "default")]
+    #[case(vec!["prod".into()], "prod")]
+    fn オブジェクトがないときは列の値を使う(
+        #[case] namespaces: Vec<String>,
+        #[case] expected: &str,
+    ) {
+        let mut table = table(None);
+
+        // 1つのnamespaceのときはNamespaceの列がない
+        if namespaces.len() == 1 {
+            table.column_definitions.remove(0);
+            table.rows[0].cells.remove(0);
+        }
+
+        let actual = ApiTable::new(&apis("example.com", "foos", "Foo"), table, &namespaces);
+
+        let row = &actual.table.rows[0];
+
+        assert_eq!(
+            (row.namespace.as_str(), row.name.as_str()),
+            (expected, "app")
+        );
+        assert_eq!(
+            row.metadata,
+            Some(BTreeMap::from([
+                ("kind".into(), "Foo".into()),
+                ("api_version".into(), "example.com/v1".into()),
+            ]))
+        );
+    }
+
+    #[rstest]
+    #[case(apis("apps", "statefulsets", "StatefulSet"), Some("statefulset"))]
+    #[case(apis("batch", "jobs", "Job"), Some("job"))]
+    #[case(apis("batch", "cronjobs", "CronJob"), None)]
+    #[case(apis("example.com", "deployments", "Deployment"), None)]
+    #[case(ApiResource::Api { name: "services".into(), kind: "Service".into(), version: "v1".into(), scope: Scope::Namespaced }, Some("service"))]
+    fn ログのクエリで指定できるリソース(
+        #[case] resource: ApiResource,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(log_query_kind(&resource), expected);
+    }
+}
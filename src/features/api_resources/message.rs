@@ -2,18 +2,25 @@ use anyhow::Result;
 
 use crate::{message::Message, workers::kube::message::Kube};
 
-use super::kube::ApiResource;
+use super::kube::{ApiResource, ApiTable};
 
 #[derive(Debug)]
 pub enum ApiRequest {
     Get,
     Set(Vec<ApiResource>),
+    /// `pods`、`deployments.apps`のようなキーで指定する（プリセット）
+    SetByKeys(Vec<String>),
 }
 
 #[derive(Debug)]
 pub enum ApiResponse {
     Get(Result<Vec<ApiResource>>),
-    Poll(Result<Vec<String>>),
+    Poll(Result<Vec<ApiTable>>),
+    /// キーで指定したAPIリソース（見つからなかったキーも返す）
+    SetByKeys {
+        api_resources: Vec<ApiResource>,
+        missing: Vec<String>,
+    },
 }
 
 #[derive(Debug)]
@@ -1,6 +1,6 @@
+mod popup;
 mod tab;
 mod widget;
-mod popup;
 
 pub use tab::*;
-
+pub use widget::{clear_list, ListTables};
@@ -1,10 +1,7 @@
 use crossbeam::channel::Sender;
 
 use crate::{
-    features::{
-        api_resources::message::ApiRequest,
-        component_id::{LIST_POPUP_ID, LIST_WIDGET_ID},
-    },
+    features::{api_resources::message::ApiRequest, component_id::LIST_POPUP_ID},
     message::Message,
     ui::{
         event::EventResult,
@@ -16,8 +13,11 @@ use crate::{
     },
 };
 
-pub fn popup_widget(tx: &Sender<Message>) -> Widget<'static> {
+use super::widget::ListTables;
+
+pub fn popup_widget(tx: &Sender<Message>, tables: &ListTables) -> Widget<'static> {
     let tx = tx.clone();
+    let tables = tables.clone();
 
     MultipleSelect::builder()
         .id(LIST_POPUP_ID)
@@ -50,7 +50,7 @@ pub fn popup_widget(tx: &Sender<Message>) -> Widget<'static> {
             }
 
             if widget.selected_items().is_empty() {
-                w.widget_clear(LIST_WIDGET_ID)
+                tables.update(w, Vec::new());
             }
 
             EventResult::Nop
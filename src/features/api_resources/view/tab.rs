@@ -1,20 +1,15 @@
 use crossbeam::channel::Sender;
-use ratatui::prelude::Constraint;
-
-use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    clipboard::Clipboard,
     features::component_id::LIST_TAB_ID,
     message::Message,
-    ui::{
-        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
-        widget::Widget,
-        Tab,
-    },
+    ui::{widget::Widget, Tab},
 };
 
-use super::{popup::popup_widget, widget::list_widget};
+use super::{
+    popup::popup_widget,
+    widget::{layout, ListTables, MIN_TABLE_HEIGHT},
+};
 
 pub struct ListTab {
     pub tab: Tab<'static>,
@@ -22,24 +17,11 @@ pub struct ListTab {
 }
 
 impl ListTab {
-    pub fn new(
-        title: &'static str,
-        tx: &Sender<Message>,
-        clipboard: &Option<Rc<RefCell<Clipboard>>>,
-    ) -> Self {
-        let list_widget = list_widget(tx, clipboard);
-
+    pub fn new(title: &'static str, tx: &Sender<Message>, tables: &ListTables) -> Self {
         ListTab {
-            tab: Tab::new(
-                LIST_TAB_ID,
-                title,
-                [list_widget],
-                NestedWidgetLayout::default().nested_widget_layout([NestedLayoutElement(
-                    Constraint::Percentage(100),
-                    LayoutElement::WidgetIndex(0),
-                )]),
-            ),
-            popup: popup_widget(tx),
+            tab: Tab::new(LIST_TAB_ID, title, [tables.placeholder()], layout(1))
+                .min_widget_height(MIN_TABLE_HEIGHT),
+            popup: popup_widget(tx, tables),
         }
     }
 }
@@ -1,53 +1,240 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crossbeam::channel::Sender;
+use ratatui::layout::Constraint;
 
 use crate::{
     clipboard::Clipboard,
     features::{
-        api_resources::message::ApiRequest,
-        component_id::{LIST_POPUP_ID, LIST_WIDGET_ID},
+        api_resources::{
+            kube::{is_wide, toggle_wide, ApiTable},
+            message::ApiRequest,
+        },
+        component_id::{
+            LIST_POPUP_ID, LIST_TAB_ID, LIST_WIDGET_ID, POD_LOG_QUERY_WIDGET_ID, POD_TAB_ID,
+            YAML_POPUP_ID,
+        },
+        get::message::GetRequest,
     },
     message::Message,
     ui::{
         event::EventResult,
-        widget::{config::WidgetConfig, Text, Widget, WidgetTrait as _},
+        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
+        widget::{
+            config::WidgetConfig, Item, SelectedItem, Table, TableItem, Widget, WidgetTrait as _,
+        },
         Window,
     },
 };
 
-pub fn list_widget(
-    tx: &Sender<Message>,
-    clipboard: &Option<Rc<RefCell<Clipboard>>>,
-) -> Widget<'static> {
-    let tx = tx.clone();
+/// リソースを選んでいないときの表のタイトル
+const LIST_TITLE: &str = "List";
 
-    let open_subwin = move |w: &mut Window| {
-        tx.send(ApiRequest::Get.into())
-            .expect("Failed to send ApiRequest::Get");
-        w.open_popup(LIST_POPUP_ID);
-        EventResult::Nop
-    };
+/// 表の最小の高さ（枠とヘッダーを除いて6行）
+///
+/// すべての表を並べるとこの高さを下回るときは、フォーカスしている表だけを表示する
+pub const MIN_TABLE_HEIGHT: u16 = 10;
 
-    let builder = Text::builder()
-        .id(LIST_WIDGET_ID)
-        .widget_config(&WidgetConfig::builder().title("List").build())
-        .block_injection(|text: &Text, is_active: bool, is_mouse_over: bool| {
-            let (index, size) = text.state();
+/// 選択したAPIリソースごとの表をListタブに並べる
+#[derive(Debug, Clone)]
+pub struct ListTables {
+    tx: Sender<Message>,
+    clipboard: Option<Rc<RefCell<Clipboard>>>,
+}
 
-            let mut config = text.widget_config().clone();
+impl ListTables {
+    pub fn new(tx: &Sender<Message>, clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Self {
+        Self {
+            tx: tx.clone(),
+            clipboard: clipboard.clone(),
+        }
+    }
 
-            *config.append_title_mut() = Some(format!(" [{}/{}]", index, size).into());
+    /// リソースを選んでいないときに表示する空の表
+    pub fn placeholder(&self) -> Widget<'static> {
+        self.table(LIST_WIDGET_ID, LIST_TITLE, 0, 1)
+    }
 
-            config.render_block(text.can_activate() && is_active, is_mouse_over)
-        })
-        .action('f', open_subwin);
+    /// リソースの組み合わせが変わったときだけ表を作り直す（フィルターと選択中の行は残る）
+    pub fn update(&self, w: &mut Window, tables: Vec<ApiTable>) {
+        let Some(tab) = w.find_tab_mut(LIST_TAB_ID) else {
+            return;
+        };
+
+        let titles: Vec<String> = if tables.is_empty() {
+            vec![LIST_TITLE.into()]
+        } else {
+            tables.iter().map(|t| t.resource.to_string()).collect()
+        };
+
+        let current: Vec<String> = tab
+            .as_ref_widgets()
+            .iter()
+            .map(|w| w.widget_config().title().to_string())
+            .collect();
+
+        if titles != current {
+            let widgets: Vec<_> = titles
+                .iter()
+                .enumerate()
+                .map(|(i, title)| self.table(&table_id(i), title, i, titles.len()))
+                .collect();
+
+            tab.update_widgets(widgets, layout(titles.len()));
+        }
+
+        tables
+            .into_iter()
+            .enumerate()
+            .for_each(|(i, ApiTable { table, .. })| {
+                let Some(widget) = tab.find_widget_mut(&table_id(i)) else {
+                    return;
+                };
+
+                let w = widget.as_mut_table();
+
+                let rows: Vec<TableItem> = table.rows.into_iter().map(TableItem::from).collect();
 
-    if let Some(cb) = clipboard {
-        builder.clipboard(cb.clone())
+                if w.equal_header(&table.header) {
+                    w.update_widget_item(Item::Table(rows));
+                } else {
+                    w.update_header_and_rows(&table.header, &rows);
+                }
+            });
+    }
+
+    /// `position`は表の並びの中の位置で、表が複数のときはタイトルに表示する
+    fn table(&self, id: &str, title: &str, position: usize, len: usize) -> Widget<'static> {
+        let tx = self.tx.clone();
+
+        let open_subwin = move |w: &mut Window| {
+            tx.send(ApiRequest::Get.into())
+                .expect("Failed to send ApiRequest::Get");
+            w.open_popup(LIST_POPUP_ID);
+            EventResult::Nop
+        };
+
+        let mut builder = Table::builder()
+            .id(id)
+            .widget_config(&WidgetConfig::builder().title(title).build())
+            .block_injection(move |table: &Table| {
+                let index = if let Some(index) = table.state().selected() {
+                    index + 1
+                } else {
+                    0
+                };
+
+                let mut widget_config = table.widget_config().clone();
+
+                let wide = if is_wide() { " (wide)" } else { "" };
+
+                let position = if 1 < len {
+                    format!(" (resource {}/{})", position + 1, len)
+                } else {
+                    String::new()
+                };
+
+                *widget_config.append_title_mut() = Some(
+                    format!(" [{}/{}]{}{}", index, table.items().len(), wide, position).into(),
+                );
+
+                widget_config
+            })
+            .on_select(open_yaml(self.tx.clone()))
+            .action('f', open_subwin)
+            .action('w', |_: &mut Window| {
+                toggle_wide();
+                EventResult::Nop
+            })
+            .action('l', prefill_log_query);
+
+        if let Some(cb) = &self.clipboard {
+            builder = builder.clipboard(cb.clone());
+        }
+
+        builder.build().into()
+    }
+}
+
+/// 先頭の表はリソースを選んでいないときと同じIDにする
+fn table_id(index: usize) -> String {
+    if index == 0 {
+        LIST_WIDGET_ID.to_string()
     } else {
-        builder
+        format!("{}-{}", LIST_WIDGET_ID, index)
     }
-    .build()
-    .into()
+}
+
+pub fn layout(len: usize) -> NestedWidgetLayout {
+    let elements: Vec<_> = (0..len)
+        .map(|i| {
+            NestedLayoutElement(
+                Constraint::Ratio(1, len as u32),
+                LayoutElement::WidgetIndex(i),
+            )
+        })
+        .collect();
+
+    NestedWidgetLayout::default().nested_widget_layout(elements)
+}
+
+/// 表の内容を消す（表の並びは次に取得するまで残す）
+pub fn clear_list(w: &mut Window) {
+    if let Some(tab) = w.find_tab_mut(LIST_TAB_ID) {
+        tab.as_mut_widgets().into_iter().for_each(|w| w.clear());
+    }
+}
+
+/// 選択した行のリソースのyamlをポップアップで表示する
+fn open_yaml(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        let Some(ref metadata) = v.metadata else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(kind), Some(name)) = (metadata.get("kind"), metadata.get("name")) else {
+            return EventResult::Ignore;
+        };
+
+        let req = GetRequest {
+            kind: kind.to_string(),
+            api_version: metadata.get("api_version").cloned(),
+            name: name.to_string(),
+            namespace: metadata
+                .get("namespace")
+                .filter(|ns| !ns.is_empty())
+                .cloned(),
+        };
+
+        tx.send(req.into())
+            .expect("Failed to send YamlMessage::Request");
+
+        w.widget_clear(YAML_POPUP_ID);
+        w.open_popup(YAML_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// ワークロードの行のとき、Podタブのログのクエリに`<kind>/<name>`を入力する
+fn prefill_log_query(w: &mut Window) -> EventResult {
+    let Some(SelectedItem::TableRow {
+        metadata: Some(metadata),
+        ..
+    }) = w.active_tab().active_widget().widget_item()
+    else {
+        return EventResult::Ignore;
+    };
+
+    let Some(query) = metadata.get("log_query").cloned() else {
+        return EventResult::Ignore;
+    };
+
+    w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+        .update_widget_item(Item::Single(query.into()));
+
+    w.activate_tab_by_id(POD_TAB_ID);
+    w.activate_widget_by_id(POD_LOG_QUERY_WIDGET_ID);
+
+    EventResult::Nop
 }
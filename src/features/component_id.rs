@@ -18,6 +18,7 @@ component_id!(
     list_tab,
     network_tab,
     yaml_tab,
+    workload_tab,
     // widgets
     pod_widget,
     pod_log_widget,
@@ -29,8 +30,11 @@ component_id!(
     event_widget,
     list_widget,
     yaml_widget,
+    workload_widget,
+    workload_description_widget,
     // popups
     pod_log_query_help_popup,
+    pod_detail_popup,
     context_popup,
     single_namespace_popup,
     multiple_namespaces_popup,
@@ -38,6 +42,15 @@ component_id!(
     yaml_kind_popup,
     yaml_name_popup,
     yaml_not_found_popup,
+    yaml_json_path_popup,
+    yaml_save_popup,
     help_popup,
-    yaml_popup
+    yaml_popup,
+    owner_tree_popup,
+    describe_popup,
+    edit_popup,
+    preset_popup,
+    preset_save_popup,
+    alert_popup,
+    error_history_popup
 );
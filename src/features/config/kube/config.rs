@@ -1,20 +1,30 @@
-use std::{collections::BTreeMap, time};
+use std::{collections::BTreeMap, time::Duration};
 
 use crate::{
-    features::config::message::ConfigResponse,
+    features::{config::message::ConfigResponse, workload::kube::age},
     kube::{
         apis::v1_table::TableRow,
+        reflector::{NamespacedStore, ReflectorSignal},
         table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
         KubeClient,
     },
-    workers::kube::{
-        WorkerResult, {PollerBase, Worker},
+    workers::{
+        interval::IntervalConfig,
+        kube::{
+            ReflectorPoller, WorkerResult, {PollerBase, Worker},
+        },
     },
 };
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::Utc;
 use futures::future::try_join_all;
+use k8s_openapi::{
+    api::core::v1::{ConfigMap, Secret},
+    ByteString,
+};
+use kube::ResourceExt as _;
 
 #[derive(Clone)]
 pub struct ConfigPoller {
@@ -32,30 +42,102 @@ impl Worker for ConfigPoller {
     type Output = WorkerResult;
 
     async fn run(&self) -> Self::Output {
-        let mut interval = tokio::time::interval(time::Duration::from_secs(1));
+        self.run_reflector().await
+    }
+}
+
+pub struct ConfigStores {
+    configmaps: NamespacedStore<ConfigMap>,
+    secrets: NamespacedStore<Secret>,
+}
 
-        let Self {
-            base:
-                PollerBase {
-                    is_terminated,
-                    tx,
-                    shared_target_namespaces,
-                    kube_client,
-                },
-        } = self;
+#[async_trait]
+impl ReflectorPoller for ConfigPoller {
+    type Stores = ConfigStores;
+    type Output = KubeTable;
 
-        while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
-            interval.tick().await;
+    fn base(&self) -> &PollerBase {
+        &self.base
+    }
 
-            let target_namespaces = shared_target_namespaces.read().await;
+    fn interval(&self) -> Duration {
+        IntervalConfig::global().config
+    }
 
-            let table = fetch_configs(kube_client, &target_namespaces).await;
+    fn spawn_stores(&self, namespaces: &[String], signal: &ReflectorSignal) -> Self::Stores {
+        let client = &self.base.kube_client;
 
-            tx.send(ConfigResponse::Table(table).into())
-                .expect("Failed to send ConfigResponse::Table");
+        ConfigStores {
+            configmaps: NamespacedStore::spawn(client, namespaces, signal),
+            // 一覧にはキーの数だけを表示するため値は保持しない
+            secrets: NamespacedStore::spawn_with_modifier(client, namespaces, signal, |secret| {
+                if let Some(data) = secret.data.as_mut() {
+                    data.values_mut().for_each(|v| *v = ByteString(Vec::new()));
+                }
+
+                secret.string_data = None;
+            }),
         }
+    }
 
-        WorkerResult::Terminated
+    fn render(&self, stores: &Self::Stores, namespaces: &[String]) -> Result<Self::Output> {
+        let now = Utc::now();
+        let insert_ns = insert_ns(namespaces);
+
+        let configmaps = stores
+            .configmaps
+            .state()
+            .into_iter()
+            .flat_map(|(ns, items)| {
+                items.into_iter().map(move |cm| {
+                    let data = cm.data.as_ref().map_or(0, BTreeMap::len)
+                        + cm.binary_data.as_ref().map_or(0, BTreeMap::len);
+
+                    config_row(
+                        ns,
+                        Configs::ConfigMap,
+                        vec![
+                            cm.name_any(),
+                            data.to_string(),
+                            age(cm.creation_timestamp().as_ref(), now),
+                        ],
+                        insert_ns,
+                    )
+                })
+            });
+
+        let secrets = stores.secrets.state().into_iter().flat_map(|(ns, items)| {
+            items.into_iter().map(move |secret| {
+                let data = secret.data.as_ref().map_or(0, BTreeMap::len);
+
+                config_row(
+                    ns,
+                    Configs::Secret,
+                    vec![
+                        secret.name_any(),
+                        data.to_string(),
+                        age(secret.creation_timestamp().as_ref(), now),
+                    ],
+                    insert_ns,
+                )
+            })
+        });
+
+        Ok(config_table(
+            namespaces,
+            configmaps.chain(secrets).collect(),
+        ))
+    }
+
+    async fn poll(&self, namespaces: &[String]) -> Result<Self::Output> {
+        fetch_configs(&self.base.kube_client, namespaces).await
+    }
+
+    fn send(&self, output: Result<Self::Output>) {
+        self.base
+            .tx
+            .send(ConfigResponse::Table(output).into())
+            .expect("Failed to send ConfigResponse::Table");
     }
 }
 
@@ -93,26 +175,9 @@ async fn fetch_configs_per_namespace(
             format!("api/v1/namespaces/{}/{}", ns, ty.kind()),
             &["Name", r#"Data"#, "Age"],
             move |row: &TableRow, indexes: &[usize]| {
-                let mut row = vec![
-                    ty.resource().to_string(),
-                    row.cells[indexes[0]].to_string(),
-                    row.cells[indexes[1]].to_string(),
-                    row.cells[indexes[2]].to_string(),
-                ];
-
-                let kind = row[0].clone();
-                let name = row[1].clone();
-
-                if insert_ns {
-                    row.insert(0, ns.to_string())
-                }
+                let row = indexes.iter().map(|i| row.cells[*i].to_string()).collect();
 
-                KubeTableRow {
-                    namespace: ns.to_string(),
-                    name,
-                    row,
-                    metadata: Some(BTreeMap::from([("kind".to_string(), kind)])),
-                }
+                config_row(ns, ty, row, insert_ns)
             },
         )
     }))
@@ -122,6 +187,19 @@ async fn fetch_configs_per_namespace(
 }
 
 async fn fetch_configs(client: &KubeClient, namespaces: &[String]) -> Result<KubeTable> {
+    let jobs = try_join_all([
+        fetch_configs_per_namespace(client, namespaces, Configs::ConfigMap),
+        fetch_configs_per_namespace(client, namespaces, Configs::Secret),
+    ])
+    .await?;
+
+    Ok(config_table(
+        namespaces,
+        jobs.into_iter().flatten().collect(),
+    ))
+}
+
+fn config_table(namespaces: &[String], rows: Vec<KubeTableRow>) -> KubeTable {
     let mut table = KubeTable {
         header: if namespaces.len() == 1 {
             ["KIND", "NAME", "DATA", "AGE"]
@@ -137,13 +215,26 @@ async fn fetch_configs(client: &KubeClient, namespaces: &[String]) -> Result<Kub
         ..Default::default()
     };
 
-    let jobs = try_join_all([
-        fetch_configs_per_namespace(client, namespaces, Configs::ConfigMap),
-        fetch_configs_per_namespace(client, namespaces, Configs::Secret),
-    ])
-    .await?;
+    table.update_rows(rows);
+
+    table
+}
 
-    table.update_rows(jobs.into_iter().flatten().collect());
+/// [NAME, DATA, AGE] の先頭に種類を加えた行を作る
+fn config_row(ns: &str, ty: Configs, row: Vec<String>, insert_ns: bool) -> KubeTableRow {
+    let kind = ty.resource().to_string();
+    let name = row[0].clone();
 
-    Ok(table)
+    let mut row: Vec<String> = std::iter::once(kind.clone()).chain(row).collect();
+
+    if insert_ns {
+        row.insert(0, ns.to_string())
+    }
+
+    KubeTableRow {
+        namespace: ns.to_string(),
+        name,
+        row,
+        metadata: Some(BTreeMap::from([("kind".to_string(), kind)])),
+    }
 }
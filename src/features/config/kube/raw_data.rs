@@ -11,7 +11,10 @@ use crate::{
     features::config::message::{ConfigData, ConfigRequest, ConfigResponse, RequestData},
     kube::KubeClient,
     message::Message,
-    workers::kube::AbortWorker,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::AbortWorker,
+    },
 };
 
 use self::{configmap::ConfigMapDataWorker, secret::SecretDataWorker};
@@ -63,14 +66,12 @@ trait Fetch<'a> {
     async fn fetch(&self) -> Result<ConfigData>;
 }
 
-const INTERVAL: u64 = 3;
-
 impl ConfigsDataWorker {
     async fn fetch_description<'a, Worker>(&'a self) -> Result<()>
     where
         Worker: Fetch<'a>,
     {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(INTERVAL));
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().description);
 
         let RequestData { name, namespace } = self.req.data();
 
@@ -84,6 +85,8 @@ impl ConfigsDataWorker {
 
             let fetched_data = worker.fetch().await;
 
+            interval.record(&fetched_data);
+
             self.tx
                 .send(ConfigResponse::Data(fetched_data).into())
                 .expect("Failed to send ConfigResponse::Data");
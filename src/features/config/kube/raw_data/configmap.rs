@@ -5,7 +5,15 @@ use async_trait::async_trait;
 use k8s_openapi::api::core::v1::ConfigMap;
 use kube::Api;
 
-use crate::{features::config::message::ConfigData, kube::KubeClient, workers::kube::color::Color};
+use crate::{
+    features::{
+        config::message::ConfigData,
+        event::kube::{related_events, InvolvedObject},
+    },
+    kube::KubeClient,
+    ui::theme::paint,
+    workers::kube::color::Palette,
+};
 
 use super::Fetch;
 
@@ -31,12 +39,22 @@ impl<'a> Fetch<'a> for ConfigMapDataWorker<'a> {
 
         let target = list.get(&self.name).await?;
 
-        if let Some(data) = target.data {
-            let data = ConfigMapData(data);
-            Ok(data.to_vec_string_with_color())
+        let mut lines = if let Some(data) = &target.data {
+            ConfigMapData(data.clone()).to_vec_string_with_color()
         } else {
-            Ok(vec!["no data".into()])
-        }
+            vec!["no data".into()]
+        };
+
+        lines.extend(
+            related_events(
+                self.client,
+                Some(&self.namespace),
+                InvolvedObject::new(target.metadata.uid.as_deref(), "ConfigMap", &self.name),
+            )
+            .await,
+        );
+
+        Ok(lines)
     }
 }
 
@@ -47,15 +65,11 @@ impl ConfigMapData {
         let ret = self
             .0
             .iter()
-            .scan(Color::new(), |color, (key, value)| {
-                let color = color.next_color();
+            .scan(Palette::new(), |palette, (key, value)| {
+                let key = paint(palette.next_color(), format!("{}:", key));
 
                 if value.contains('\n') {
-                    let mut ret = vec![format!(
-                        "\x1b[{color}m{key}:\x1b[39m |",
-                        color = color,
-                        key = key
-                    )];
+                    let mut ret = vec![format!("{} |", key)];
 
                     let value: Vec<String> = value.lines().map(|l| format!("  {}\n", l)).collect();
 
@@ -63,12 +77,7 @@ impl ConfigMapData {
 
                     Some(ret)
                 } else {
-                    Some(vec![format!(
-                        "\x1b[{color}m{key}:\x1b[39m {value}",
-                        color = color,
-                        key = key,
-                        value = value,
-                    )])
+                    Some(vec![format!("{} {}", key, value)])
                 }
             })
             .flatten()
@@ -9,7 +9,13 @@ use async_trait::async_trait;
 use k8s_openapi::{api::core::v1::Secret, ByteString};
 use kube::Api;
 
-use crate::{features::config::message::ConfigData, kube::KubeClient};
+use crate::{
+    features::{
+        config::message::ConfigData,
+        event::kube::{related_events, InvolvedObject},
+    },
+    kube::KubeClient,
+};
 
 use self::{any::Any, helm::Helm};
 
@@ -35,12 +41,22 @@ impl<'a> Fetch<'a> for SecretDataWorker<'a> {
 
         let type_ = target.type_.as_deref().unwrap_or_default();
 
-        let Some(data) = target.data else {
-            return Ok(vec!["no data".into()]);
+        let mut lines = if let Some(data) = &target.data {
+            SecretData::new(type_, data.clone())?.to_string_key_values()
+        } else {
+            vec!["no data".into()]
         };
 
-        let data = SecretData::new(type_, data)?;
-        Ok(data.to_string_key_values())
+        lines.extend(
+            related_events(
+                self.client,
+                Some(&self.namespace),
+                InvolvedObject::new(target.metadata.uid.as_deref(), "Secret", &self.name),
+            )
+            .await,
+        );
+
+        Ok(lines)
     }
 }
 
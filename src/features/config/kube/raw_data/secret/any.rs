@@ -3,7 +3,7 @@ use std::collections::{btree_map, BTreeMap};
 use base64::{engine::general_purpose, Engine};
 use k8s_openapi::ByteString;
 
-use crate::workers::kube::color::Color;
+use crate::workers::kube::color::Palette;
 
 use super::format::{format_error, format_utf8};
 
@@ -32,14 +32,14 @@ impl Any {
     fn iter(&self) -> Iter {
         Iter {
             iter: self.data.iter(),
-            color: Color::new(),
+            palette: Palette::new(),
         }
     }
 }
 
 struct Iter<'a> {
     iter: btree_map::Iter<'a, String, ByteString>,
-    color: Color,
+    palette: Palette,
 }
 
 impl Iterator for Iter<'_> {
@@ -49,7 +49,7 @@ impl Iterator for Iter<'_> {
             return None;
         };
 
-        let color = self.color.next_color();
+        let color = self.palette.next_color();
 
         match String::from_utf8(value.to_vec()) {
             Ok(utf8_data) => Some(format_utf8(key, &utf8_data, color)),
@@ -1,8 +1,12 @@
-use crate::workers::kube::color::fg::Color;
+use ratatui::style::Color;
+
+use crate::ui::theme::{paint, Theme};
+
+pub(super) fn format_utf8(key: &str, value: &str, color: Color) -> String {
+    let key = paint(color, format!("{}:", key));
 
-pub(super) fn format_utf8(key: &str, value: &str, color: u8) -> String {
     if value.contains('\n') {
-        let mut ret = format!("\x1b[{color}m{key}:\x1b[39m |\n", color = color, key = key);
+        let mut ret = format!("{key} |\n", key = key);
 
         value.lines().for_each(|l| {
             ret += &format!("  {}\n", l);
@@ -10,22 +14,15 @@ pub(super) fn format_utf8(key: &str, value: &str, color: u8) -> String {
 
         ret.trim_end().to_string()
     } else {
-        format!(
-            "\x1b[{color}m{key}:\x1b[39m {value}",
-            color = color,
-            key = key,
-            value = value,
-        )
+        format!("{key} {value}", key = key, value = value)
     }
 }
 
-pub(super) fn format_error(key: &str, value: &str, err: &str, color: u8) -> String {
+pub(super) fn format_error(key: &str, value: &str, err: &str, color: Color) -> String {
     format!(
-            "\x1b[{color}m{key}:\x1b[39m | \x1b[{error_color}m# {error}\x1b[39m\n  [base64-encoded] {value}",
-        color = color,
-        key = key,
+        "{key} | {error}\n  [base64-encoded] {value}",
+        key = paint(color, format!("{}:", key)),
         value = value,
-        error_color = Color::DarkGray as u8,
-        error = err
+        error = paint(Theme::global().muted, format!("# {}", err)),
     )
 }
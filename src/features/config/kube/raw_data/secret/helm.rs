@@ -4,7 +4,13 @@ use anyhow::Result;
 use base64::{engine::general_purpose, Engine};
 use k8s_openapi::ByteString;
 
-use crate::workers::kube::color::{self, Color};
+use crate::{
+    ui::{
+        highlight::highlight_yaml,
+        theme::{paint, Theme},
+    },
+    workers::kube::color::Palette,
+};
 
 use super::format::{format_error, format_utf8};
 
@@ -23,23 +29,25 @@ impl Helm {
             return vec!["no release data".into()];
         };
 
-        let mut color = Color::new();
+        let mut palette = Palette::new();
 
         let decoded_release = match decode_release(value) {
             Ok(decoded) => {
-                let color = color.next_color();
-                format_utf8("release (decoded)", &decoded, color)
-            }
-            Err(err) => {
-                format!(
-                    "\x1b[{red}m# Failed to decode the 'release' value: {err}\x1b[39m",
-                    red = color::fg::Color::Red as u8,
-                    err = err
+                let color = palette.next_color();
+                let decoded: Vec<&str> = decoded.lines().collect();
+                format_utf8(
+                    "release (decoded)",
+                    &highlight_yaml(&decoded).join("\n"),
+                    color,
                 )
             }
+            Err(err) => paint(
+                Theme::global().error,
+                format!("# Failed to decode the 'release' value: {}", err),
+            ),
         };
 
-        let color = color.next_color();
+        let color = palette.next_color();
 
         let release = match String::from_utf8(value.to_vec()) {
             Ok(utf8_data) => format_utf8("release", &utf8_data, color),
@@ -2,8 +2,12 @@ use crossbeam::channel::Sender;
 
 use crate::{
     features::{
+        api_resources::view::clear_list,
         component_id::{
-            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, CONTEXT_POPUP_ID, EVENT_WIDGET_ID, LIST_POPUP_ID, LIST_WIDGET_ID, MULTIPLE_NAMESPACES_POPUP_ID, NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID, POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID, YAML_WIDGET_ID
+            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, CONTEXT_POPUP_ID, EVENT_WIDGET_ID,
+            LIST_POPUP_ID, MULTIPLE_NAMESPACES_POPUP_ID, NETWORK_DESCRIPTION_WIDGET_ID,
+            NETWORK_WIDGET_ID, POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID,
+            YAML_WIDGET_ID,
         },
         context::message::ContextRequest,
     },
@@ -53,7 +57,7 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> Event
         w.widget_clear(NETWORK_WIDGET_ID);
         w.widget_clear(NETWORK_DESCRIPTION_WIDGET_ID);
         w.widget_clear(EVENT_WIDGET_ID);
-        w.widget_clear(LIST_WIDGET_ID);
+        clear_list(w);
         w.widget_clear(YAML_WIDGET_ID);
 
         let widget = w
@@ -0,0 +1,4 @@
+mod describe;
+mod worker;
+
+pub use worker::*;
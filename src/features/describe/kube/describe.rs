@@ -0,0 +1,803 @@
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use k8s_openapi::{
+    api::core::v1::Event,
+    apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition,
+    apimachinery::pkg::apis::meta::v1::Time,
+};
+use serde_json::Value;
+
+use crate::features::{event::kube::events_value, workload::kube::age, yaml::output::json_path};
+
+/// 値がないときの表示
+const NONE: &str = "<none>";
+
+/// 大きすぎて説明には向かないアノテーション
+const OMITTED_ANNOTATIONS: [&str; 1] = ["kubectl.kubernetes.io/last-applied-configuration"];
+
+/// `kubectl describe`のような説明を作る
+///
+/// columnsはカスタムリソースの表示列で、カスタムリソースのときはspecとstatusの項目も並べる（組み込みのリソースはNone）
+pub fn describe(
+    object: &Value,
+    columns: Option<&[CustomResourceColumnDefinition]>,
+    events: &[Event],
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    let mut lines = metadata(object, now);
+
+    if let Some(columns) = columns {
+        lines.extend(printer_columns(object, columns));
+    }
+
+    lines.extend(summary(object));
+    lines.extend(conditions(object, now));
+    lines.extend(containers(object));
+
+    if columns.is_some() {
+        let mut status = object["status"].clone();
+
+        if let Some(status) = status.as_object_mut() {
+            status.remove("conditions");
+        }
+
+        lines.extend(outline_section("Spec", &object["spec"]));
+        lines.extend(outline_section("Status", &status));
+    }
+
+    lines.extend(events_section(events, now));
+
+    lines
+}
+
+fn metadata(object: &Value, now: DateTime<Utc>) -> Vec<String> {
+    let metadata = &object["metadata"];
+
+    let mut items = vec![("Name", vec![text(&metadata["name"])])];
+
+    if let Some(namespace) = metadata["namespace"].as_str() {
+        items.push(("Namespace", vec![namespace.to_string()]));
+    }
+
+    items.extend([
+        (
+            "Kind",
+            vec![format!(
+                "{} ({})",
+                text(&object["kind"]),
+                text(&object["apiVersion"])
+            )],
+        ),
+        ("Labels", key_values(&metadata["labels"], &[])),
+        (
+            "Annotations",
+            key_values(&metadata["annotations"], &OMITTED_ANNOTATIONS),
+        ),
+        (
+            "Created",
+            vec![format!(
+                "{} ({} ago)",
+                text(&metadata["creationTimestamp"]),
+                timestamp_age(&metadata["creationTimestamp"], now)
+            )],
+        ),
+        ("Owner References", owner_references(metadata)),
+    ]);
+
+    fields(0, items)
+}
+
+fn owner_references(metadata: &Value) -> Vec<String> {
+    list(&metadata["ownerReferences"])
+        .map(|owner| {
+            let controller = if owner["controller"].as_bool().unwrap_or_default() {
+                " (controller)"
+            } else {
+                ""
+            };
+
+            format!(
+                "{}/{}{}",
+                text(&owner["kind"]),
+                text(&owner["name"]),
+                controller
+            )
+        })
+        .collect()
+}
+
+/// カスタムリソースの表示列（作成日時はCreatedと重なるため除く）
+fn printer_columns(object: &Value, columns: &[CustomResourceColumnDefinition]) -> Vec<String> {
+    let items: Vec<_> = columns
+        .iter()
+        .filter(|column| column.json_path != ".metadata.creationTimestamp")
+        .map(|column| {
+            // CRDでよく使う`[?(@.type=="Ready")]`の文字列は単一引用符でしか解釈できない
+            let value = json_path(&column.json_path.replace('"', "'"), object)
+                .map(|values| values.join(","))
+                .unwrap_or_default();
+
+            (column.name.as_str(), non_empty(value))
+        })
+        .collect();
+
+    section("Printer Columns", fields(2, items))
+}
+
+/// よく使う組み込みのリソースの概要
+fn summary(object: &Value) -> Vec<String> {
+    let spec = &object["spec"];
+    let status = &object["status"];
+
+    match object["kind"].as_str().unwrap_or_default() {
+        "Pod" => fields(
+            0,
+            vec![
+                ("Node", non_empty(text(&spec["nodeName"]))),
+                ("Status", non_empty(text(&status["phase"]))),
+                ("IP", non_empty(text(&status["podIP"]))),
+                ("QoS Class", non_empty(text(&status["qosClass"]))),
+            ],
+        ),
+        "Deployment" | "StatefulSet" | "ReplicaSet" => fields(
+            0,
+            vec![
+                (
+                    "Selector",
+                    key_values(&spec["selector"]["matchLabels"], &[]),
+                ),
+                (
+                    "Replicas",
+                    vec![format!(
+                        "{} desired | {} updated | {} ready | {} available",
+                        number(&spec["replicas"]),
+                        number(&status["updatedReplicas"]),
+                        number(&status["readyReplicas"]),
+                        number(&status["availableReplicas"])
+                    )],
+                ),
+            ],
+        ),
+        "DaemonSet" => fields(
+            0,
+            vec![
+                (
+                    "Selector",
+                    key_values(&spec["selector"]["matchLabels"], &[]),
+                ),
+                (
+                    "Pods",
+                    vec![format!(
+                        "{} desired | {} current | {} ready | {} available",
+                        number(&status["desiredNumberScheduled"]),
+                        number(&status["currentNumberScheduled"]),
+                        number(&status["numberReady"]),
+                        number(&status["numberAvailable"])
+                    )],
+                ),
+            ],
+        ),
+        "Job" => fields(
+            0,
+            vec![
+                (
+                    "Completions",
+                    vec![format!(
+                        "{}/{}",
+                        number(&status["succeeded"]),
+                        number(&spec["completions"])
+                    )],
+                ),
+                ("Failed", vec![number(&status["failed"]).to_string()]),
+            ],
+        ),
+        "CronJob" => fields(
+            0,
+            vec![
+                ("Schedule", non_empty(text(&spec["schedule"]))),
+                (
+                    "Suspend",
+                    vec![spec["suspend"].as_bool().unwrap_or_default().to_string()],
+                ),
+                (
+                    "Last Schedule",
+                    non_empty(text(&status["lastScheduleTime"])),
+                ),
+            ],
+        ),
+        "Service" => {
+            let mut lines = fields(
+                0,
+                vec![
+                    ("Type", non_empty(text(&spec["type"]))),
+                    ("Cluster IP", non_empty(text(&spec["clusterIP"]))),
+                    ("Selector", key_values(&spec["selector"], &[])),
+                ],
+            );
+
+            let rows = list(&spec["ports"])
+                .map(|port| {
+                    vec![
+                        text(&port["name"]),
+                        format!("{}/{}", text(&port["port"]), protocol(port)),
+                        text(&port["targetPort"]),
+                        text(&port["nodePort"]),
+                    ]
+                })
+                .collect();
+
+            lines.extend(section(
+                "Ports",
+                table(&["NAME", "PORT", "TARGET PORT", "NODE PORT"], rows),
+            ));
+
+            lines
+        }
+        "ConfigMap" => section(
+            "Data",
+            data_keys(&object["data"], |value| text(value).len()),
+        ),
+        "Secret" => {
+            let mut lines = fields(0, vec![("Type", non_empty(text(&object["type"])))]);
+
+            lines.extend(section(
+                "Data",
+                data_keys(&object["data"], |value| {
+                    general_purpose::STANDARD
+                        .decode(text(value))
+                        .map(|bytes| bytes.len())
+                        .unwrap_or_default()
+                }),
+            ));
+
+            lines
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// ConfigMapやSecretのキーとサイズ（値は表示しない）
+fn data_keys(data: &Value, size: impl Fn(&Value) -> usize) -> Vec<String> {
+    let Some(data) = data.as_object() else {
+        return Vec::new();
+    };
+
+    let items = data
+        .iter()
+        .map(|(key, value)| (key.as_str(), vec![format!("{} bytes", size(value))]))
+        .collect();
+
+    fields(2, items)
+}
+
+fn conditions(object: &Value, now: DateTime<Utc>) -> Vec<String> {
+    let rows = list(&object["status"]["conditions"])
+        .map(|condition| {
+            let transition = match &condition["lastTransitionTime"] {
+                Value::Null => &condition["lastUpdateTime"],
+                time => time,
+            };
+
+            vec![
+                text(&condition["type"]),
+                text(&condition["status"]),
+                text(&condition["reason"]),
+                timestamp_age(transition, now),
+                text(&condition["message"]).replace('\n', " "),
+            ]
+        })
+        .collect();
+
+    section(
+        "Conditions",
+        table(&["TYPE", "STATUS", "REASON", "AGE", "MESSAGE"], rows),
+    )
+}
+
+/// Podのspec（ワークロードはPodテンプレートのspec）
+fn pod_spec(object: &Value) -> Option<&Value> {
+    let spec = &object["spec"];
+
+    [
+        spec,
+        &spec["template"]["spec"],
+        &spec["jobTemplate"]["spec"]["template"]["spec"],
+    ]
+    .into_iter()
+    .find(|spec| spec["containers"].is_array())
+}
+
+fn containers(object: &Value) -> Vec<String> {
+    let Some(spec) = pod_spec(object) else {
+        return Vec::new();
+    };
+
+    [
+        ("Init Containers", "initContainers", "initContainerStatuses"),
+        ("Containers", "containers", "containerStatuses"),
+    ]
+    .into_iter()
+    .flat_map(|(title, key, status_key)| {
+        let lines = list(&spec[key])
+            .flat_map(|container| {
+                let status =
+                    list(&object["status"][status_key]).find(|s| s["name"] == container["name"]);
+
+                container_lines(container, status)
+            })
+            .collect();
+
+        section(title, lines)
+    })
+    .collect()
+}
+
+fn container_lines(container: &Value, status: Option<&Value>) -> Vec<String> {
+    let resources = &container["resources"];
+
+    let ports = list(&container["ports"])
+        .map(|port| match port["name"].as_str() {
+            Some(name) => format!(
+                "{}/{} ({})",
+                text(&port["containerPort"]),
+                protocol(port),
+                name
+            ),
+            None => format!("{}/{}", text(&port["containerPort"]), protocol(port)),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut items = vec![
+        ("Image", vec![text(&container["image"])]),
+        ("Ports", non_empty(ports)),
+        (
+            "Requests",
+            non_empty(key_values(&resources["requests"], &[]).join(", ")),
+        ),
+        (
+            "Limits",
+            non_empty(key_values(&resources["limits"], &[]).join(", ")),
+        ),
+    ];
+
+    if let Some(status) = status {
+        items.extend([
+            ("State", vec![container_state(&status["state"])]),
+            ("Ready", vec![text(&status["ready"])]),
+            (
+                "Restart Count",
+                vec![number(&status["restartCount"]).to_string()],
+            ),
+        ]);
+    }
+
+    let mut lines = vec![format!("  {}:", text(&container["name"]))];
+
+    lines.extend(fields(4, items));
+
+    lines
+}
+
+/// `Running`、`Waiting (CrashLoopBackOff)`、`Terminated (Completed, exit code 0)`
+fn container_state(state: &Value) -> String {
+    if state["running"].is_object() {
+        "Running".into()
+    } else if let Some(reason) = state["waiting"]["reason"].as_str() {
+        format!("Waiting ({})", reason)
+    } else if state["terminated"].is_object() {
+        format!(
+            "Terminated ({}, exit code {})",
+            text(&state["terminated"]["reason"]),
+            number(&state["terminated"]["exitCode"])
+        )
+    } else {
+        NONE.into()
+    }
+}
+
+fn events_section(events: &[Event], now: DateTime<Utc>) -> Vec<String> {
+    let events = events_value(events, now);
+
+    let mut lines: Vec<String> = events
+        .as_sequence()
+        .into_iter()
+        .flatten()
+        .filter_map(|event| event.as_str())
+        .map(|event| format!("  {}", event))
+        .collect();
+
+    if lines.is_empty() {
+        lines.push(format!("  {}", NONE));
+    }
+
+    lines.insert(0, "Events:".into());
+
+    lines
+}
+
+/// カスタムリソースの項目を`kubectl describe`と同じく単語に分けた名前で並べる
+fn outline_section(title: &str, value: &Value) -> Vec<String> {
+    match value {
+        Value::Object(map) if !map.is_empty() => section(title, outline(value, 2)),
+        _ => Vec::new(),
+    }
+}
+
+fn outline(value: &Value, indent: usize) -> Vec<String> {
+    let Some(map) = value.as_object() else {
+        return vec![format!("{:indent$}{}", "", text(value), indent = indent)];
+    };
+
+    map.iter()
+        .flat_map(|(key, value)| {
+            let key = title_case(key);
+
+            match value {
+                Value::Object(map) if !map.is_empty() => {
+                    let mut lines = vec![format!("{:indent$}{}:", "", key, indent = indent)];
+                    lines.extend(outline(value, indent + 2));
+                    lines
+                }
+                Value::Array(items) if !items.is_empty() => {
+                    let mut lines = vec![format!("{:indent$}{}:", "", key, indent = indent)];
+
+                    for item in items {
+                        let mut item_lines = outline(item, indent + 2);
+
+                        // オブジェクトの要素は先頭の行に印を付けて区切る
+                        if item.is_object() {
+                            if let Some(first) = item_lines.first_mut() {
+                                first.replace_range(indent..indent + 2, "- ");
+                            }
+                        }
+
+                        lines.extend(item_lines);
+                    }
+
+                    lines
+                }
+                Value::Object(_) | Value::Array(_) | Value::Null => {
+                    vec![format!("{:indent$}{}:  {}", "", key, NONE, indent = indent)]
+                }
+                value => vec![format!(
+                    "{:indent$}{}:  {}",
+                    "",
+                    key,
+                    text(value),
+                    indent = indent
+                )],
+            }
+        })
+        .collect()
+}
+
+/// `podSelector` → `Pod Selector`、`hostIPC` → `Host IPC`
+fn title_case(key: &str) -> String {
+    let mut title = String::new();
+
+    let mut prev: Option<char> = None;
+
+    for c in key.chars() {
+        match prev {
+            None => title.extend(c.to_uppercase()),
+            Some(p) if c.is_uppercase() && p.is_lowercase() => {
+                title.push(' ');
+                title.push(c);
+            }
+            _ => title.push(c),
+        }
+
+        prev = Some(c);
+    }
+
+    title
+}
+
+/// 項目名の幅をそろえて`Name:  value`の形式で並べる（値が複数行のときは値の位置をそろえる）
+fn fields(indent: usize, items: Vec<(&str, Vec<String>)>) -> Vec<String> {
+    let width = items
+        .iter()
+        .map(|(key, _)| key.len() + 1)
+        .max()
+        .unwrap_or_default();
+
+    items
+        .into_iter()
+        .flat_map(|(key, values)| {
+            let values = if values.is_empty() {
+                vec![NONE.to_string()]
+            } else {
+                values
+            };
+
+            values.into_iter().enumerate().map(move |(i, value)| {
+                let key = if i == 0 {
+                    format!("{}:", key)
+                } else {
+                    String::new()
+                };
+
+                format!(
+                    "{:indent$}{:width$}  {}",
+                    "",
+                    key,
+                    value,
+                    indent = indent,
+                    width = width
+                )
+            })
+        })
+        .collect()
+}
+
+/// 列の幅をそろえた表（行がないときは空）
+fn table(header: &[&str], rows: Vec<Vec<String>>) -> Vec<String> {
+    if rows.is_empty() {
+        return Vec::new();
+    }
+
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain([h.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let header = header.iter().map(ToString::to_string).collect();
+
+    [header]
+        .into_iter()
+        .chain(rows)
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            format!("  {}", line.trim_end())
+        })
+        .collect()
+}
+
+/// 見出しに続けて並べる（中身がないときは見出しも出さない）
+fn section(title: &str, lines: Vec<String>) -> Vec<String> {
+    if lines.is_empty() {
+        return Vec::new();
+    }
+
+    [format!("{}:", title)].into_iter().chain(lines).collect()
+}
+
+/// `key=value`の形式で並べる
+fn key_values(value: &Value, omitted: &[&str]) -> Vec<String> {
+    value
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(key, _)| !omitted.contains(&key.as_str()))
+        .map(|(key, value)| format!("{}={}", key, text(value)))
+        .collect()
+}
+
+fn list(value: &Value) -> impl Iterator<Item = &Value> {
+    value.as_array().into_iter().flatten()
+}
+
+fn non_empty(value: String) -> Vec<String> {
+    if value.is_empty() {
+        Vec::new()
+    } else {
+        vec![value]
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn number(value: &Value) -> i64 {
+    value.as_i64().unwrap_or_default()
+}
+
+fn protocol(port: &Value) -> String {
+    port["protocol"].as_str().unwrap_or("TCP").to_string()
+}
+
+fn timestamp_age(value: &Value, now: DateTime<Utc>) -> String {
+    let time = value
+        .as_str()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|time| Time(time.with_timezone(&Utc)));
+
+    age(time.as_ref(), now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn now() -> DateTime<Utc> {
+        "2024-01-01T01:00:00Z".parse().unwrap()
+    }
+
+    fn yaml(s: &str) -> Value {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn 組み込みのリソースは概要とコンディションとコンテナを表示する() {
+        let deployment = yaml(indoc! {"
+            apiVersion: apps/v1
+            kind: Deployment
+            metadata:
+              name: app
+              namespace: default
+              creationTimestamp: '2024-01-01T00:00:00Z'
+              labels:
+                app: web
+              annotations:
+                kubectl.kubernetes.io/last-applied-configuration: '{}'
+            spec:
+              replicas: 2
+              selector:
+                matchLabels:
+                  app: web
+              template:
+                spec:
+                  containers:
+                  - name: nginx
+                    image: nginx:1.25
+                    ports:
+                    - containerPort: 80
+                      name: http
+                    resources:
+                      limits:
+                        memory: 128Mi
+            status:
+              readyReplicas: 1
+              updatedReplicas: 2
+              availableReplicas: 1
+              conditions:
+              - type: Available
+                status: 'False'
+                reason: MinimumReplicasUnavailable
+                lastTransitionTime: '2024-01-01T00:30:00Z'
+                message: Deployment does not have minimum availability.
+        "});
+
+        let actual = describe(&deployment, None, &[], now());
+
+        assert_eq!(
+            actual,
+            vec![
+                "Name:              app",
+                "Namespace:         default",
+                "Kind:              Deployment (apps/v1)",
+                "Labels:            app=web",
+                "Annotations:       <none>",
+                "Created:           2024-01-01T00:00:00Z (60m ago)",
+                "Owner References:  <none>",
+                "Selector:  app=web",
+                "Replicas:  2 desired | 2 updated | 1 ready | 1 available",
+                "Conditions:",
+                "  TYPE       STATUS  REASON                      AGE  MESSAGE",
+                "  Available  False   MinimumReplicasUnavailable  30m  Deployment does not have minimum availability.",
+                "Containers:",
+                "  nginx:",
+                "    Image:     nginx:1.25",
+                "    Ports:     80/TCP (http)",
+                "    Requests:  <none>",
+                "    Limits:    memory=128Mi",
+                "Events:",
+                "  <none>",
+            ]
+        );
+    }
+
+    #[test]
+    fn カスタムリソースは表示列とspecとstatusの項目を表示する() {
+        let certificate = yaml(indoc! {"
+            apiVersion: cert-manager.io/v1
+            kind: Certificate
+            metadata:
+              name: web
+              namespace: default
+              creationTimestamp: '2024-01-01T00:00:00Z'
+            spec:
+              secretName: web-tls
+              dnsNames:
+              - example.com
+              issuerRef:
+                kind: ClusterIssuer
+                name: letsencrypt
+            status:
+              notAfter: '2024-04-01T00:00:00Z'
+              conditions:
+              - type: Ready
+                status: 'True'
+                reason: Ready
+        "});
+
+        let columns = vec![
+            CustomResourceColumnDefinition {
+                name: "Ready".into(),
+                json_path: r#".status.conditions[?(@.type=="Ready")].status"#.into(),
+                type_: "string".into(),
+                ..Default::default()
+            },
+            CustomResourceColumnDefinition {
+                name: "Secret".into(),
+                json_path: ".spec.secretName".into(),
+                type_: "string".into(),
+                ..Default::default()
+            },
+            CustomResourceColumnDefinition {
+                name: "Age".into(),
+                json_path: ".metadata.creationTimestamp".into(),
+                type_: "date".into(),
+                ..Default::default()
+            },
+        ];
+
+        let actual = describe(&certificate, Some(&columns), &[], now());
+
+        assert_eq!(
+            actual[7..],
+            vec![
+                "Printer Columns:",
+                "  Ready:   True",
+                "  Secret:  web-tls",
+                "Conditions:",
+                "  TYPE   STATUS  REASON  AGE        MESSAGE",
+                "  Ready  True    Ready   <unknown>",
+                "Spec:",
+                "  Dns Names:",
+                "    example.com",
+                "  Issuer Ref:",
+                "    Kind:  ClusterIssuer",
+                "    Name:  letsencrypt",
+                "  Secret Name:  web-tls",
+                "Status:",
+                "  Not After:  2024-04-01T00:00:00Z",
+                "Events:",
+                "  <none>",
+            ]
+        );
+    }
+
+    #[test]
+    fn 配列のオブジェクトは先頭の行に印を付ける() {
+        let value = yaml(indoc! {"
+            rules:
+            - host: a.example.com
+              port: 80
+            - host: b.example.com
+              port: 443
+        "});
+
+        assert_eq!(
+            outline(&value, 2),
+            vec![
+                "  Rules:",
+                "  - Host:  a.example.com",
+                "    Port:  80",
+                "  - Host:  b.example.com",
+                "    Port:  443",
+            ]
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use crossbeam::channel::Sender;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceColumnDefinition, CustomResourceDefinition,
+};
+use serde_json::Value;
+
+use crate::{
+    features::{
+        api_resources::kube::{ApiResource, SharedApiResources},
+        describe::message::DescribeResponse,
+        event::kube::{fetch_related_events, InvolvedObject},
+        get::message::GetRequest,
+    },
+    kube::KubeClientRequest,
+    logger,
+    message::Message,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::AbortWorker,
+    },
+};
+
+use super::describe::describe;
+
+#[derive(Clone)]
+pub struct DescribeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    client: C,
+    shared_api_resources: SharedApiResources,
+    req: GetRequest,
+}
+
+impl<C: KubeClientRequest> DescribeWorker<C> {
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        client: C,
+        shared_api_resources: SharedApiResources,
+        req: GetRequest,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            client,
+            shared_api_resources,
+            req,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: KubeClientRequest> AbortWorker for DescribeWorker<C> {
+    async fn run(&self) {
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().description);
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            let (kind, lines) = self.fetch().await;
+
+            interval.record(&lines);
+
+            self.tx
+                .send(
+                    DescribeResponse {
+                        kind,
+                        name: self.req.name.clone(),
+                        lines,
+                    }
+                    .into(),
+                )
+                .expect("Failed to send DescribeResponse");
+        }
+    }
+}
+
+impl<C: KubeClientRequest> DescribeWorker<C> {
+    /// kind（分かるときはapiVersionも）をAPIリソースから解決して説明を作る
+    ///
+    /// タイトルに表示するため、解決できたときはリソース名も返す
+    async fn fetch(&self) -> (String, Result<Vec<String>>) {
+        let GetRequest {
+            kind,
+            name,
+            namespace,
+            api_version,
+        } = &self.req;
+
+        let api_resources = self.shared_api_resources.read().await;
+
+        let Some(api) = api_resources.resolve(api_version.as_deref(), kind) else {
+            return (
+                kind.to_string(),
+                Err(anyhow!("Can't find {} in API resources", kind)),
+            );
+        };
+
+        let namespace = match (api.is_namespaced(), namespace.as_deref()) {
+            (true, None) => {
+                return (
+                    api.name().to_string(),
+                    Err(anyhow!("Namespace is required for {}/{}", api.name(), name)),
+                );
+            }
+            (true, namespace) => namespace,
+            (false, _) => None,
+        };
+
+        (
+            api.name().to_string(),
+            self.describe(api, name, namespace).await,
+        )
+    }
+
+    async fn describe(
+        &self,
+        api: &ApiResource,
+        name: &str,
+        namespace: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let path = match namespace {
+            Some(namespace) => format!("{}/{}", api.api_url_with_namespace(namespace), name),
+            None => format!("{}/{}", api.api_url(), name),
+        };
+
+        let object: Value = self.client.request(&path).await?;
+
+        let columns = self.printer_columns(api).await;
+
+        let uid = object["metadata"]["uid"].as_str();
+
+        // イベントを取得できなくても説明は表示する
+        let events = fetch_related_events(
+            &self.client,
+            namespace,
+            InvolvedObject::new(uid, api.kind(), name),
+        )
+        .await
+        .unwrap_or_else(|e| {
+            logger!(warn, "Failed to fetch events for {}/{}: {}", api, name, e);
+            Vec::new()
+        });
+
+        Ok(describe(&object, columns.as_deref(), &events, Utc::now()))
+    }
+
+    /// カスタムリソースのときはCRDの表示列を返す（組み込みのリソースはNone）
+    ///
+    /// CRDを参照する権限がないときも、カスタムリソースとして項目を並べるために空の列を返す
+    async fn printer_columns(
+        &self,
+        api: &ApiResource,
+    ) -> Option<Vec<CustomResourceColumnDefinition>> {
+        let ApiResource::Apis { group, version, .. } = api else {
+            return None;
+        };
+
+        // apps、batchなどの組み込みのグループにCRDはない
+        if !group.contains('.') {
+            return None;
+        }
+
+        let path = format!(
+            "apis/apiextensions.k8s.io/v1/customresourcedefinitions/{}.{}",
+            api.name(),
+            group
+        );
+
+        match self.client.request::<CustomResourceDefinition>(&path).await {
+            Ok(crd) => Some(
+                crd.spec
+                    .versions
+                    .into_iter()
+                    .find(|v| &v.name == version)
+                    .and_then(|v| v.additional_printer_columns)
+                    .unwrap_or_default(),
+            ),
+            Err(e) if is_custom_group(group) => {
+                logger!(
+                    warn,
+                    "Failed to fetch CustomResourceDefinition {}: {}",
+                    path,
+                    e
+                );
+                Some(Vec::new())
+            }
+            Err(_) => None,
+        }
+    }
+}
+
+/// CRDのグループは`.`を含み、Kubernetesのグループ（`*.k8s.io`）とは別の名前になる
+fn is_custom_group(group: &str) -> bool {
+    group.contains('.') && !group.ends_with(".k8s.io")
+}
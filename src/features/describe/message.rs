@@ -0,0 +1,29 @@
+use anyhow::Result;
+
+use crate::{features::get::message::GetRequest, message::Message, workers::kube::message::Kube};
+
+#[derive(Debug)]
+pub enum DescribeMessage {
+    /// 対象は`y`で開くyamlと同じく選択した行のリソース
+    Request(GetRequest),
+    Response(DescribeResponse),
+}
+
+#[derive(Debug)]
+pub struct DescribeResponse {
+    pub kind: String,
+    pub name: String,
+    pub lines: Result<Vec<String>>,
+}
+
+impl From<DescribeMessage> for Message {
+    fn from(m: DescribeMessage) -> Self {
+        Self::Kube(Kube::Describe(m))
+    }
+}
+
+impl From<DescribeResponse> for Message {
+    fn from(res: DescribeResponse) -> Self {
+        Self::Kube(Kube::Describe(DescribeMessage::Response(res)))
+    }
+}
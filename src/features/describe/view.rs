@@ -0,0 +1,32 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::DESCRIBE_POPUP_ID,
+    ui::widget::{config::WidgetConfig, Text, Widget},
+};
+
+pub struct DescribePopup {
+    pub popup: Widget<'static>,
+}
+
+impl DescribePopup {
+    pub fn new(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Self {
+        Self {
+            popup: popup(clipboard),
+        }
+    }
+}
+
+fn popup(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    let mut builder = Text::builder()
+        .id(DESCRIBE_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Describe").build())
+        .wrap();
+
+    if let Some(clipboard) = clipboard {
+        builder = builder.clipboard(clipboard.clone());
+    }
+
+    builder.build().into()
+}
@@ -0,0 +1,7 @@
+mod editor;
+pub mod kube;
+pub mod message;
+mod session;
+pub mod view;
+
+pub use session::EditSession;
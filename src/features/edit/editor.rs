@@ -0,0 +1,81 @@
+use std::{io::Write as _, path::Path, process::Command};
+
+use anyhow::{bail, Context as _, Result};
+
+use crate::workers::suspend_terminal;
+
+/// $VISUAL、$EDITORのどちらも設定されていないときに使うエディタ
+const DEFAULT_EDITOR: &str = "vi";
+
+/// 一時ファイルに書き出した内容をエディタで編集して、保存した内容を返す
+///
+/// エディタを閉じるまでTUIを止めて端末を明け渡す。
+/// Secretのデータを書き出すため、一時ファイルは本人だけが読み書きできる権限で新しく作る
+pub fn edit_in_editor(text: &str, target: &str) -> Result<String> {
+    let mut file = tempfile::Builder::new()
+        .prefix(&format!(
+            "kubetui-edit-{}-",
+            target.replace(['/', ' '], "-")
+        ))
+        .suffix(".yaml")
+        .tempfile()
+        .context("Failed to create a temporary file")?;
+
+    file.write_all(text.as_bytes())
+        .and_then(|_| file.flush())
+        .with_context(|| format!("Failed to write {}", file.path().display()))?;
+
+    // 一時ファイルはNamedTempFileをドロップしたときに消える
+    suspend_terminal(|| run_editor(file.path()))?;
+
+    std::fs::read_to_string(file.path())
+        .with_context(|| format!("Failed to read {}", file.path().display()))
+}
+
+fn run_editor(path: &Path) -> Result<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .find_map(|key| std::env::var(key).ok().filter(|v| !v.trim().is_empty()))
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+
+    let (program, args) = parse_editor(&editor);
+
+    let status = Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+        .with_context(|| format!("Failed to start editor '{}'", editor))?;
+
+    if !status.success() {
+        bail!("Editor '{}' exited with {}", editor, status);
+    }
+
+    Ok(())
+}
+
+/// `code --wait`のように引数を含むエディタのコマンドを分ける
+fn parse_editor(editor: &str) -> (&str, Vec<&str>) {
+    let mut words = editor.split_whitespace();
+
+    let program = words.next().unwrap_or(DEFAULT_EDITOR);
+
+    (program, words.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("vim", ("vim", vec![]))]
+    #[case("code --wait", ("code", vec!["--wait"]))]
+    #[case("  ", ("vi", vec![]))]
+    fn エディタのコマンドと引数を分ける(
+        #[case] editor: &str,
+        #[case] expected: (&str, Vec<&str>),
+    ) {
+        assert_eq!(parse_editor(editor), expected);
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::{anyhow, Result};
+use kube::{
+    api::{DynamicObject, PostParams},
+    discovery, Api,
+};
+use serde_yaml::Value;
+
+use crate::{
+    features::{
+        api_resources::kube::{ApiResource, ApiResources},
+        edit::message::EditError,
+    },
+    kube::KubeClient,
+    logger,
+};
+
+const FIELD_MANAGER: &str = "kubetui";
+
+/// 編集したリソースで置き換える（dry_runのときはサーバーで検証するだけで保存しない）
+///
+/// resourceVersionが古いときは最新のリソースを取得してConflictを返す
+pub async fn replace(
+    api: &Api<DynamicObject>,
+    name: &str,
+    object: &Value,
+    dry_run: bool,
+) -> Result<Value, EditError> {
+    let data: DynamicObject =
+        serde_yaml::from_value(object.clone()).map_err(anyhow::Error::from)?;
+
+    let params = PostParams {
+        dry_run,
+        field_manager: Some(FIELD_MANAGER.into()),
+    };
+
+    logger!(info, "Replacing {} [dry_run={}]", name, dry_run);
+
+    match api.replace(name, &params, &data).await {
+        Ok(replaced) => Ok(to_value(&replaced)?),
+        Err(kube::Error::Api(err)) if err.code == 409 => {
+            let latest = api.get(name).await.map_err(anyhow::Error::from)?;
+
+            Err(EditError::Conflict {
+                message: err.message,
+                latest: to_value(&latest)?,
+            })
+        }
+        Err(err) => Err(anyhow!(err).into()),
+    }
+}
+
+/// 編集したリソースのapiVersionとkindからAPIを解決する
+pub fn target_api(
+    client: &KubeClient,
+    api_resources: &ApiResources,
+    object: &Value,
+) -> Result<(Api<DynamicObject>, String)> {
+    let field = |value: &Value, key: &str| {
+        value[key]
+            .as_str()
+            .map(ToString::to_string)
+            .ok_or_else(|| anyhow!("{} is required", key))
+    };
+
+    let api_version = field(object, "apiVersion")?;
+    let kind = field(object, "kind")?;
+    let name = field(&object["metadata"], "name")?;
+
+    let api = api_resources
+        .find_by_api_version(&api_version, &kind)
+        .ok_or_else(|| anyhow!("Can't find {} {} in API resources", api_version, kind))?;
+
+    let resource = discovery::ApiResource {
+        group: match api {
+            ApiResource::Apis { group, .. } => group.to_string(),
+            ApiResource::Api { .. } => String::new(),
+        },
+        version: match api {
+            ApiResource::Apis { version, .. } | ApiResource::Api { version, .. } => {
+                version.to_string()
+            }
+        },
+        api_version,
+        kind,
+        plural: api.name().to_string(),
+    };
+
+    let api = if api.is_namespaced() {
+        let namespace = field(&object["metadata"], "namespace")?;
+
+        Api::namespaced_with(client.to_client(), &namespace, &resource)
+    } else {
+        Api::all_with(client.to_client(), &resource)
+    };
+
+    Ok((api, name))
+}
+
+/// managedFieldsを除いてyamlの値にする
+fn to_value(object: &DynamicObject) -> Result<Value> {
+    let mut value = serde_yaml::to_value(object)?;
+
+    if let Some(Value::Mapping(metadata)) = value.get_mut("metadata") {
+        metadata.remove("managedFields");
+    }
+
+    Ok(value)
+}
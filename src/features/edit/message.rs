@@ -0,0 +1,51 @@
+use serde_yaml::Value;
+
+use crate::{message::Message, workers::kube::message::Kube};
+
+#[derive(Debug)]
+pub enum EditRequest {
+    /// サーバーサイドのdry-runで検証する
+    DryRun(Value),
+    Apply(Value),
+}
+
+impl From<EditRequest> for Message {
+    fn from(req: EditRequest) -> Self {
+        Self::Kube(Kube::Edit(EditMessage::Request(req)))
+    }
+}
+
+#[derive(Debug)]
+pub enum EditResponse {
+    /// dry-runでサーバーが返したリソース
+    DryRun(Result<Value, EditError>),
+    Applied(Result<Value, EditError>),
+}
+
+impl From<EditResponse> for Message {
+    fn from(res: EditResponse) -> Self {
+        Self::Kube(Kube::Edit(EditMessage::Response(res)))
+    }
+}
+
+#[derive(Debug)]
+pub enum EditError {
+    /// resourceVersionが古い（編集している間にほかで更新された）
+    Conflict {
+        message: String,
+        latest: Value,
+    },
+    Other(anyhow::Error),
+}
+
+impl From<anyhow::Error> for EditError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::Other(err)
+    }
+}
+
+#[derive(Debug)]
+pub enum EditMessage {
+    Request(EditRequest),
+    Response(EditResponse),
+}
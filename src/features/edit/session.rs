@@ -0,0 +1,359 @@
+use anyhow::Result;
+use serde_yaml::Value;
+
+use crate::features::yaml::diff::{unified_diff, CONTEXT_LINES};
+
+use super::message::EditError;
+
+/// エディタの先頭に表示する説明
+const HEADER: [&str; 3] = [
+    "# Edit the object below. Saving validates it with a server-side dry-run",
+    "# and shows the diff before it is applied. Lines beginning with '#' at the",
+    "# top are ignored, and an empty file aborts the edit.",
+];
+
+/// エディタで保存した内容の読み込み結果
+#[derive(Debug, PartialEq)]
+pub enum Edited {
+    /// dry-runで検証するリソース
+    Changed(Value),
+    /// 空のファイル、または変更がないため編集をやめた
+    Cancelled,
+    /// YAMLとして読めないためエラーを表示して開き直す
+    Invalid,
+}
+
+/// エディタでの編集からdry-run、適用までの状態
+#[derive(Debug, Default)]
+pub struct EditSession {
+    /// 編集の元にしたリソース（競合したときは最新のリソース）
+    original: Option<Value>,
+    /// エディタで保存した内容（開き直すときに引き継ぐ）
+    edited: Option<String>,
+    /// 検証しているリソース（dry-runが通ったら適用する）
+    pending: Option<Value>,
+    /// dry-runが通ったかどうか
+    validated: bool,
+    /// エディタの先頭にコメントで表示するエラー
+    errors: Vec<String>,
+    /// 描画のループでエディタを開く
+    open_requested: bool,
+}
+
+impl EditSession {
+    /// 表示中のリソースの編集を始める
+    pub fn start(&mut self, object: Value) {
+        *self = Self {
+            original: Some(object),
+            open_requested: true,
+            ..Default::default()
+        };
+    }
+
+    pub fn cancel(&mut self) {
+        *self = Self::default();
+    }
+
+    /// エディタを開き直す
+    pub fn request_editor(&mut self) {
+        if self.original.is_some() {
+            self.validated = false;
+            self.open_requested = true;
+        }
+    }
+
+    pub fn take_editor_request(&mut self) -> bool {
+        std::mem::take(&mut self.open_requested)
+    }
+
+    /// `<kind>/<name>`
+    pub fn target(&self) -> String {
+        let Some(original) = &self.original else {
+            return String::new();
+        };
+
+        format!(
+            "{}/{}",
+            original["kind"].as_str().unwrap_or_default(),
+            original["metadata"]["name"].as_str().unwrap_or_default()
+        )
+    }
+
+    /// エディタに渡す内容（エラーがあれば説明の下にコメントで表示する）
+    pub fn editor_text(&self) -> Result<String> {
+        let body = match (&self.edited, &self.original) {
+            (Some(edited), _) => edited.clone(),
+            (None, Some(original)) => serde_yaml::to_string(original)?,
+            (None, None) => String::new(),
+        };
+
+        let mut lines: Vec<String> = HEADER.iter().map(ToString::to_string).collect();
+
+        if !self.errors.is_empty() {
+            lines.push("#".into());
+            lines.extend(self.errors.iter().map(|line| format!("# {}", line)));
+        }
+
+        Ok(lines.join("\n") + "\n" + &body)
+    }
+
+    /// エディタで保存した内容を読み込む
+    pub fn finish_editing(&mut self, text: &str) -> Edited {
+        let body = strip_header(text);
+
+        match serde_yaml::from_str::<Value>(&body) {
+            Ok(Value::Null) => {
+                self.cancel();
+                Edited::Cancelled
+            }
+            Ok(value) if self.original.as_ref() == Some(&value) => {
+                self.cancel();
+                Edited::Cancelled
+            }
+            Ok(value) => {
+                self.edited = Some(body);
+                self.errors.clear();
+                self.pending = Some(value.clone());
+                Edited::Changed(value)
+            }
+            Err(err) => {
+                self.edited = Some(body);
+                self.errors = vec![format!("error: {}", err)];
+                self.open_requested = true;
+                Edited::Invalid
+            }
+        }
+    }
+
+    /// dry-runの結果と編集前のリソースの差分（適用を待つ）
+    pub fn validated(&mut self, result: &Value) -> Vec<String> {
+        self.validated = true;
+
+        diff(self.original.as_ref(), result)
+    }
+
+    /// dry-runが通ったリソースを取り出す
+    pub fn take_validated(&mut self) -> Option<Value> {
+        if !std::mem::take(&mut self.validated) {
+            return None;
+        }
+
+        self.pending.take()
+    }
+
+    /// 検証や適用に失敗したときはエラーを表示してエディタを開き直す（競合したとき以外は編集した内容を残す）
+    pub fn fail(&mut self, err: EditError) {
+        self.validated = false;
+
+        self.errors = match err {
+            EditError::Conflict { message, latest } => self.rebase(message, latest),
+            EditError::Other(err) => format!("error: {:#}", err)
+                .lines()
+                .map(ToString::to_string)
+                .collect(),
+        };
+
+        self.open_requested = true;
+    }
+
+    /// 最新のリソースで開き直し、編集した内容は元のリソースとの差分としてコメントで表示する
+    ///
+    /// 古い編集にresourceVersionを付け替えて適用するとサーバーでの変更を上書きするため、
+    /// 編集はやり直してもらう
+    fn rebase(&mut self, message: String, latest: Value) -> Vec<String> {
+        let mut errors = vec![
+            format!("error: {}", message),
+            format!(
+                "The object has been modified (resourceVersion: {}). The latest version is shown below.",
+                latest["metadata"]["resourceVersion"]
+                    .as_str()
+                    .unwrap_or_default()
+            ),
+            "Your changes were not applied. Make them again; they were:".into(),
+        ];
+
+        if let Some(edited) = self
+            .pending
+            .take()
+            .or_else(|| serde_yaml::from_str(self.edited.as_deref()?).ok())
+        {
+            errors.extend(diff(self.original.as_ref(), &edited));
+        }
+
+        self.edited = None;
+        self.original = Some(latest);
+
+        errors
+    }
+}
+
+/// エディタで追加した先頭のコメントを除く
+fn strip_header(text: &str) -> String {
+    text.lines()
+        .skip_while(|line| line.starts_with('#'))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+fn diff(old: Option<&Value>, new: &Value) -> Vec<String> {
+    let lines = |value: &Value| -> Vec<String> {
+        serde_yaml::to_string(value)
+            .unwrap_or_default()
+            .lines()
+            .map(ToString::to_string)
+            .collect()
+    };
+
+    let old = old.map(lines).unwrap_or_default();
+
+    unified_diff(&old, &lines(new), CONTEXT_LINES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn deployment(replicas: u32, resource_version: &str) -> Value {
+        serde_yaml::from_str(&format!(
+            indoc! {"
+                apiVersion: apps/v1
+                kind: Deployment
+                metadata:
+                  name: app
+                  namespace: default
+                  resourceVersion: '{}'
+                spec:
+                  replicas: {}
+            "},
+            resource_version, replicas
+        ))
+        .unwrap()
+    }
+
+    fn session() -> EditSession {
+        let mut session = EditSession::default();
+
+        session.start(deployment(1, "10"));
+        session.take_editor_request();
+
+        session
+    }
+
+    #[test]
+    fn 変更がないときと空のファイルは編集をやめる() {
+        let mut session = session();
+
+        let text = session.editor_text().unwrap();
+
+        assert_eq!(session.finish_editing(&text), Edited::Cancelled);
+        assert_eq!(session.target(), "");
+
+        let mut session = self::session();
+
+        assert_eq!(
+            session.finish_editing(&HEADER.join("\n")),
+            Edited::Cancelled
+        );
+    }
+
+    #[test]
+    fn 変更したリソースはdry_runが通ってから適用する() {
+        let mut session = session();
+
+        let text = session
+            .editor_text()
+            .unwrap()
+            .replace("replicas: 1", "replicas: 3");
+
+        assert_eq!(
+            session.finish_editing(&text),
+            Edited::Changed(deployment(3, "10"))
+        );
+
+        assert_eq!(session.take_validated(), None);
+
+        assert_eq!(
+            session.validated(&deployment(3, "10")),
+            vec![
+                "@@ -5,4 +5,4 @@",
+                "   namespace: default",
+                "   resourceVersion: '10'",
+                " spec:",
+                "-  replicas: 1",
+                "+  replicas: 3",
+            ]
+        );
+
+        assert_eq!(session.take_validated(), Some(deployment(3, "10")));
+        assert_eq!(session.take_validated(), None);
+    }
+
+    #[test]
+    fn yamlとして読めないときはエラーを表示して開き直す() {
+        let mut session = session();
+
+        assert_eq!(session.finish_editing("spec: [replicas"), Edited::Invalid);
+        assert!(session.take_editor_request());
+
+        let text = session.editor_text().unwrap();
+
+        assert!(text.contains("\n#\n# error: "));
+        assert!(text.ends_with("spec: [replicas\n"));
+    }
+
+    #[test]
+    fn 競合したときは最新のリソースで開き直し編集した内容は差分で表示する() {
+        let mut session = session();
+
+        let text = session
+            .editor_text()
+            .unwrap()
+            .replace("replicas: 1", "replicas: 3");
+
+        session.finish_editing(&text);
+
+        session.fail(EditError::Conflict {
+            message: "the object has been modified".into(),
+            latest: deployment(2, "11"),
+        });
+
+        assert!(session.take_editor_request());
+
+        let text = session.editor_text().unwrap();
+
+        assert!(text.contains("# error: the object has been modified\n"));
+        assert!(text.contains("# -  replicas: 1\n# +  replicas: 3\n"));
+        assert_eq!(
+            serde_yaml::from_str::<Value>(&strip_header(&text)).unwrap(),
+            deployment(2, "11")
+        );
+
+        // 最新のリソースのまま保存したときは編集をやめる
+        assert_eq!(session.finish_editing(&text), Edited::Cancelled);
+    }
+
+    #[test]
+    fn 検証に失敗したときはエラーを表示して編集した内容で開き直す() {
+        let mut session = session();
+
+        let text = session
+            .editor_text()
+            .unwrap()
+            .replace("replicas: 1", "replicas: -1");
+
+        session.finish_editing(&text);
+        session.fail(EditError::Other(anyhow!(
+            "spec.replicas: Invalid value: -1"
+        )));
+
+        assert!(session.take_editor_request());
+
+        let text = session.editor_text().unwrap();
+
+        assert!(text.contains("# error: spec.replicas: Invalid value: -1\n"));
+        assert!(text.ends_with("  replicas: -1\n"));
+    }
+}
@@ -0,0 +1,202 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::EDIT_POPUP_ID,
+        yaml::diff::{paint_diff_line, YamlDiff},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, Item, LiteralItem, Text, Widget, WidgetTrait as _},
+        Window,
+    },
+};
+
+use super::{
+    editor::edit_in_editor,
+    message::{EditRequest, EditResponse},
+    session::{EditSession, Edited},
+};
+
+pub struct EditPopup {
+    pub popup: Widget<'static>,
+    pub opener: EditOpener,
+}
+
+impl EditPopup {
+    pub fn new(tx: &Sender<Message>, session: &Rc<RefCell<EditSession>>) -> Self {
+        Self {
+            popup: popup(tx.clone(), session.clone()),
+            opener: EditOpener {
+                session: session.clone(),
+            },
+        }
+    }
+}
+
+/// 表示中のリソースの編集を始める（エディタは描画のループで開く）
+#[derive(Debug, Clone)]
+pub struct EditOpener {
+    session: Rc<RefCell<EditSession>>,
+}
+
+impl EditOpener {
+    pub fn open(&self, diff: Rc<RefCell<YamlDiff>>) -> impl Fn(&mut Window) -> EventResult {
+        let session = self.session.clone();
+
+        move |_: &mut Window| {
+            let Some(object) = diff.borrow().object().cloned() else {
+                return EventResult::Ignore;
+            };
+
+            session.borrow_mut().start(object);
+
+            EventResult::Nop
+        }
+    }
+}
+
+fn popup(tx: Sender<Message>, session: Rc<RefCell<EditSession>>) -> Widget<'static> {
+    let apply_session = session.clone();
+
+    Text::builder()
+        .id(EDIT_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Edit").build())
+        .action('y', move |w: &mut Window| {
+            let Some(object) = apply_session.borrow_mut().take_validated() else {
+                return EventResult::Ignore;
+            };
+
+            tx.send(EditRequest::Apply(object).into())
+                .expect("Failed to send EditRequest::Apply");
+
+            set_title(w, " : applying...".into());
+
+            EventResult::Nop
+        })
+        .action('e', move |_: &mut Window| {
+            session.borrow_mut().request_editor();
+
+            EventResult::Nop
+        })
+        .wrap()
+        .build()
+        .into()
+}
+
+fn set_title(w: &mut Window, title: String) {
+    *w.find_widget_mut(EDIT_POPUP_ID)
+        .widget_config_mut()
+        .append_title_mut() = Some(title.into());
+}
+
+fn show(w: &mut Window, title: String, lines: Vec<String>) {
+    set_title(w, title);
+
+    w.find_widget_mut(EDIT_POPUP_ID)
+        .update_widget_item(Item::Array(
+            lines.into_iter().map(LiteralItem::from).collect(),
+        ));
+
+    w.open_popup(EDIT_POPUP_ID);
+}
+
+/// 編集を始めたリソースをエディタで開き、保存した内容をdry-runで検証する
+///
+/// エディタを開いたときはtrueを返す（端末を描画し直す）
+pub fn open_editor(w: &mut Window, session: &RefCell<EditSession>, tx: &Sender<Message>) -> bool {
+    let mut opened = false;
+
+    let mut session = session.borrow_mut();
+
+    while session.take_editor_request() {
+        opened = true;
+
+        let target = session.target();
+
+        let edited = session
+            .editor_text()
+            .and_then(|text| edit_in_editor(&text, &target));
+
+        match edited.map(|text| session.finish_editing(&text)) {
+            Ok(Edited::Changed(object)) => {
+                tx.send(EditRequest::DryRun(object).into())
+                    .expect("Failed to send EditRequest::DryRun");
+
+                show(
+                    w,
+                    format!(" : {} validating...", target),
+                    vec![format!("Validating {} with a server-side dry-run", target)],
+                );
+            }
+            Ok(Edited::Cancelled) => {
+                show(
+                    w,
+                    format!(" : {}", target),
+                    vec!["Edit cancelled: no changes".into()],
+                );
+            }
+            Ok(Edited::Invalid) => {}
+            Err(err) => {
+                session.cancel();
+
+                show(
+                    w,
+                    format!(" : {}", target),
+                    format!("error: {:#}", err)
+                        .lines()
+                        .map(ToString::to_string)
+                        .collect(),
+                );
+            }
+        }
+    }
+
+    opened
+}
+
+/// dry-runや適用の結果を表示する（失敗したときはエディタを開き直す）
+pub fn update_edit_popup(w: &mut Window, session: &RefCell<EditSession>, res: EditResponse) {
+    let mut session = session.borrow_mut();
+
+    let target = session.target();
+
+    match res {
+        EditResponse::DryRun(Ok(object)) => {
+            let lines = session.validated(&object);
+
+            let lines = if lines.is_empty() {
+                vec!["No changes after server-side defaulting".into()]
+            } else {
+                lines.into_iter().map(paint_diff_line).collect()
+            };
+
+            show(
+                w,
+                format!(" : {} (y: apply, e: edit again, Esc: cancel)", target),
+                lines,
+            );
+        }
+        EditResponse::Applied(Ok(object)) => {
+            session.cancel();
+
+            show(
+                w,
+                format!(" : {} applied", target),
+                vec![format!(
+                    "Applied {} (resourceVersion: {})",
+                    target,
+                    object["metadata"]["resourceVersion"]
+                        .as_str()
+                        .unwrap_or_default()
+                )],
+            );
+        }
+        EditResponse::DryRun(Err(err)) | EditResponse::Applied(Err(err)) => {
+            session.fail(err);
+        }
+    }
+}
@@ -1,3 +1,5 @@
 mod event;
+mod related_events;
 
 pub use event::*;
+pub use related_events::*;
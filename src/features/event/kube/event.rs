@@ -1,17 +1,25 @@
-use std::{sync::atomic::Ordering, time};
+use std::{cmp::Reverse, collections::BTreeMap, time::Duration};
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use futures::future::try_join_all;
+use k8s_openapi::{api::core::v1::Event, apimachinery::pkg::apis::meta::v1::Time as MetaTime};
+use kube::{api::ListParams, Api};
 
 use crate::{
+    features::workload::kube::age,
     kube::{
-        apis::v1_table::{TableRow, ToTime as _},
-        table::{get_resource_per_namespace, insert_ns, KubeTableRow},
+        reflector::{NamespacedStore, ReflectorSignal},
+        table::{insert_ns, KubeTable, KubeTableRow},
         KubeClient,
     },
     message::Message,
-    workers::kube::{message::Kube, PollerBase, Worker, WorkerResult},
+    ui::theme::{paint, Theme},
+    workers::{
+        interval::IntervalConfig,
+        kube::{message::Kube, PollerBase, ReflectorPoller, Worker, WorkerResult},
+    },
 };
 
 #[derive(Clone)]
@@ -29,88 +37,322 @@ impl EventPoller {
 impl Worker for EventPoller {
     type Output = WorkerResult;
     async fn run(&self) -> Self::Output {
-        let Self {
-            base:
-                PollerBase {
-                    is_terminated,
-                    tx,
-                    shared_target_namespaces,
-                    kube_client,
-                },
-        } = self;
+        self.run_reflector().await
+    }
+}
+
+#[async_trait]
+impl ReflectorPoller for EventPoller {
+    type Stores = NamespacedStore<Event>;
+    type Output = KubeTable;
+
+    fn base(&self) -> &PollerBase {
+        &self.base
+    }
+
+    fn interval(&self) -> Duration {
+        IntervalConfig::global().event
+    }
+
+    fn spawn_stores(&self, namespaces: &[String], signal: &ReflectorSignal) -> Self::Stores {
+        NamespacedStore::spawn(&self.base.kube_client, namespaces, signal)
+    }
+
+    fn render(&self, stores: &Self::Stores, namespaces: &[String]) -> Result<Self::Output> {
+        let state = stores.state();
 
-        let mut interval = tokio::time::interval(time::Duration::from_millis(1000));
-        while !is_terminated.load(Ordering::Relaxed) {
-            interval.tick().await;
-            let target_namespaces = shared_target_namespaces.read().await;
+        let events = state
+            .iter()
+            .flat_map(|(ns, events)| events.iter().map(move |ev| (*ns, ev.as_ref())));
 
-            let event_list = get_event_table(kube_client, &target_namespaces).await;
+        Ok(event_table(namespaces, events, Utc::now()))
+    }
+
+    async fn poll(&self, namespaces: &[String]) -> Result<Self::Output> {
+        let client = &self.base.kube_client;
 
-            tx.send(Message::Kube(Kube::Event(event_list)))
-                .expect("Failed to send Kube::Event");
-        }
+        let lists = try_join_all(namespaces.iter().map(|ns| fetch_events(client, ns))).await?;
 
-        WorkerResult::Terminated
+        let events = namespaces
+            .iter()
+            .zip(lists.iter())
+            .flat_map(|(ns, events)| events.iter().map(move |ev| (ns.as_str(), ev)));
+
+        Ok(event_table(namespaces, events, Utc::now()))
     }
+
+    fn send(&self, output: Result<Self::Output>) {
+        self.base
+            .tx
+            .send(Message::Kube(Kube::Event(output)))
+            .expect("Failed to send Kube::Event");
+    }
+}
+
+async fn fetch_events(client: &KubeClient, ns: &str) -> Result<Vec<Event>> {
+    let api: Api<Event> = Api::namespaced(client.to_client(), ns);
+
+    Ok(api.list(&ListParams::default()).await?.items)
 }
 
-const TARGET_LEN: usize = 4;
-const TARGET: [&str; TARGET_LEN] = ["Last Seen", "Object", "Reason", "Message"];
+/// kubectl get eventsのLAST SEENと同じ優先順位で最終発生日時を求める
+pub(super) fn last_seen(ev: &Event) -> Option<DateTime<Utc>> {
+    ev.series
+        .as_ref()
+        .and_then(|series| series.last_observed_time.as_ref().map(|t| t.0))
+        .or_else(|| ev.last_timestamp.as_ref().map(|t| t.0))
+        .or_else(|| ev.event_time.as_ref().map(|t| t.0))
+        .or_else(|| ev.first_timestamp.as_ref().map(|t| t.0))
+}
+
+fn first_seen(ev: &Event) -> Option<DateTime<Utc>> {
+    ev.first_timestamp
+        .as_ref()
+        .map(|t| t.0)
+        .or_else(|| ev.event_time.as_ref().map(|t| t.0))
+}
 
-async fn get_event_table(client: &KubeClient, namespaces: &[String]) -> Result<Vec<String>> {
+/// seriesがあるときはseriesの回数を優先する
+pub(super) fn count(ev: &Event) -> i32 {
+    ev.series
+        .as_ref()
+        .and_then(|series| series.count)
+        .or(ev.count)
+        .unwrap_or(1)
+}
+
+const HEADER: [&str; 7] = [
+    "TYPE",
+    "REASON",
+    "OBJECT",
+    "COUNT",
+    "FIRST SEEN",
+    "LAST SEEN",
+    "MESSAGE",
+];
+
+/// 新しいイベントが先頭になるように並べたテーブルを作る
+fn event_table<'a>(
+    namespaces: &[String],
+    events: impl Iterator<Item = (&'a str, &'a Event)>,
+    now: DateTime<Utc>,
+) -> KubeTable {
     let insert_ns = insert_ns(namespaces);
 
-    let jobs = try_join_all(namespaces.iter().map(|ns| {
-        get_resource_per_namespace(
-            client,
-            format!("api/v1/namespaces/{}/{}", ns, "events"),
-            &TARGET,
-            move |row: &TableRow, indexes: &[usize]| {
-                let mut row: Vec<String> =
-                    indexes.iter().map(|i| row.cells[*i].to_string()).collect();
-
-                let name = row[0].clone();
-
-                if insert_ns {
-                    row.insert(1, ns.to_string())
-                }
-
-                KubeTableRow {
-                    namespace: ns.to_string(),
-                    name,
-                    row,
-                    ..Default::default()
-                }
-            },
-        )
-    }))
-    .await?;
-
-    let mut ok_only: Vec<KubeTableRow> = jobs.into_iter().flatten().collect();
-
-    ok_only.sort_by_key(|row| row.row[0].to_time());
-
-    Ok(ok_only
-        .iter()
-        .flat_map(|v| {
-            v.row
-                .iter()
-                .enumerate()
-                .fold(String::new(), |mut s: String, (i, item)| -> String {
-                    if i == v.row.len() - 1 {
-                        item.lines()
-                            .for_each(|i| s += &format!("\n\x1b[90m> {}", i));
-
-                        s += "\x1b[0m\n ";
-                        // s += &format!("\n\x1b[90m> {}\x1b[0m\n ", item);
-                    } else {
-                        s += &format!("{:<4}  ", item);
-                    }
-                    s
-                })
-                .lines()
-                .map(ToString::to_string)
-                .collect::<Vec<_>>()
-        })
-        .collect())
+    let mut events: Vec<_> = events.collect();
+
+    events.sort_by_key(|(_, ev)| Reverse(last_seen(ev)));
+
+    let mut header: Vec<String> = HEADER.iter().map(ToString::to_string).collect();
+
+    if insert_ns {
+        header.insert(0, "NAMESPACE".to_string());
+    }
+
+    let mut table = KubeTable {
+        header,
+        ..Default::default()
+    };
+
+    table.update_rows(
+        events
+            .into_iter()
+            .map(|(ns, ev)| event_row(ns, ev, insert_ns, now))
+            .collect(),
+    );
+
+    table
+}
+
+/// namespaceとnameには関連するオブジェクトの値を入れる
+///
+/// メタデータのtypeとkindは種類ごとの絞り込みに、reason、count、messageは通知の判定に使う
+fn event_row(ns: &str, ev: &Event, insert_ns: bool, now: DateTime<Utc>) -> KubeTableRow {
+    let object = &ev.involved_object;
+
+    let kind = object.kind.clone().unwrap_or_default();
+    let name = object.name.clone().unwrap_or_default();
+    let type_ = ev.type_.clone().unwrap_or_default();
+
+    let seen = |t: Option<DateTime<Utc>>| age(t.map(MetaTime).as_ref(), now);
+
+    let mut row = vec![
+        type_.clone(),
+        ev.reason.clone().unwrap_or_default(),
+        format!("{}/{}", kind.to_lowercase(), name),
+        count(ev).to_string(),
+        seen(first_seen(ev)),
+        seen(last_seen(ev)),
+        ev.message.as_deref().unwrap_or_default().trim().to_string(),
+    ];
+
+    if insert_ns {
+        row.insert(0, ns.to_string());
+    }
+
+    if type_ == "Warning" {
+        let warning = Theme::global().warning;
+
+        row.iter_mut().for_each(|r| *r = paint(warning, &r));
+    }
+
+    let mut metadata = BTreeMap::from([
+        ("type".to_string(), type_),
+        ("kind".to_string(), kind),
+        ("reason".to_string(), ev.reason.clone().unwrap_or_default()),
+        ("count".to_string(), count(ev).to_string()),
+        (
+            "message".to_string(),
+            ev.message.as_deref().unwrap_or_default().trim().to_string(),
+        ),
+    ]);
+
+    if let Some(api_version) = &object.api_version {
+        metadata.insert("api_version".to_string(), api_version.to_string());
+    }
+
+    KubeTableRow {
+        namespace: object.namespace.clone().unwrap_or_else(|| ns.to_string()),
+        name,
+        metadata: Some(metadata),
+        row,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn events() -> Vec<Event> {
+        serde_yaml::from_str(indoc! {r#"
+            - metadata:
+                name: app.1
+                namespace: default
+              involvedObject:
+                kind: Pod
+                name: app
+                namespace: default
+                apiVersion: v1
+              type: Warning
+              reason: BackOff
+              message: "Back-off restarting failed container\n"
+              count: 5
+              firstTimestamp: "2024-01-01T00:00:00Z"
+              lastTimestamp: "2024-01-01T00:09:00Z"
+            - metadata:
+                name: app.2
+                namespace: default
+              involvedObject:
+                kind: Deployment
+                name: app
+                namespace: default
+                apiVersion: apps/v1
+              type: Normal
+              reason: ScalingReplicaSet
+              message: Scaled up replica set app-abc to 1
+              eventTime: "2024-01-01T00:09:30.000000Z"
+              series:
+                count: 3
+                lastObservedTime: "2024-01-01T00:09:50.000000Z"
+        "#})
+        .unwrap()
+    }
+
+    #[test]
+    fn 新しいイベントから順に行を作る() {
+        let events = events();
+        let now = "2024-01-01T00:10:00Z".parse().unwrap();
+
+        let actual = event_table(
+            &["default".to_string()],
+            events.iter().map(|ev| ("default", ev)),
+            now,
+        );
+
+        let warning = Theme::global().warning;
+
+        assert_eq!(
+            actual,
+            KubeTable {
+                header: HEADER.iter().map(ToString::to_string).collect(),
+                rows: vec![
+                    KubeTableRow {
+                        namespace: "default".into(),
+                        name: "app".into(),
+                        metadata: Some(BTreeMap::from([
+                            ("type".into(), "Normal".into()),
+                            ("kind".into(), "Deployment".into()),
+                            ("api_version".into(), "apps/v1".into()),
+                            ("reason".into(), "ScalingReplicaSet".into()),
+                            ("count".into(), "3".into()),
+                            (
+                                "message".into(),
+                                "Scaled up replica set app-abc to 1".into()
+                            ),
+                        ])),
+                        row: [
+                            "Normal",
+                            "ScalingReplicaSet",
+                            "deployment/app",
+                            "3",
+                            "30s",
+                            "10s",
+                            "Scaled up replica set app-abc to 1",
+                        ]
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                    },
+                    KubeTableRow {
+                        namespace: "default".into(),
+                        name: "app".into(),
+                        metadata: Some(BTreeMap::from([
+                            ("type".into(), "Warning".into()),
+                            ("kind".into(), "Pod".into()),
+                            ("api_version".into(), "v1".into()),
+                            ("reason".into(), "BackOff".into()),
+                            ("count".into(), "5".into()),
+                            (
+                                "message".into(),
+                                "Back-off restarting failed container".into()
+                            ),
+                        ])),
+                        row: [
+                            "Warning",
+                            "BackOff",
+                            "pod/app",
+                            "5",
+                            "10m",
+                            "60s",
+                            "Back-off restarting failed container",
+                        ]
+                        .iter()
+                        .map(|s| paint(warning, s))
+                        .collect(),
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn 複数のnamespaceのときnamespaceの列を追加する() {
+        let events = events();
+        let now = "2024-01-01T00:10:00Z".parse().unwrap();
+
+        let actual = event_table(
+            &["default".to_string(), "kube-system".to_string()],
+            events.iter().take(1).map(|ev| ("default", ev)),
+            now,
+        );
+
+        assert_eq!(actual.header[0], "NAMESPACE");
+        assert_eq!(
+            actual.rows[0].row[0],
+            paint(Theme::global().warning, "default")
+        );
+    }
 }
@@ -0,0 +1,259 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use k8s_openapi::{api::core::v1::Event, apimachinery::pkg::apis::meta::v1::Time, List};
+use kube::Resource;
+use serde_yaml::{Mapping, Value};
+
+use crate::{features::workload::kube::age, kube::KubeClientRequest, logger};
+
+use super::event::{count, last_seen};
+
+/// イベントを絞り込む対象のオブジェクト
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvolvedObject<'a> {
+    Uid(&'a str),
+    /// uidが分からないときは種類と名前で絞り込む
+    Name {
+        kind: &'a str,
+        name: &'a str,
+    },
+}
+
+impl<'a> InvolvedObject<'a> {
+    /// uidがあればuidで、なければ種類と名前で絞り込む
+    pub fn new(uid: Option<&'a str>, kind: &'a str, name: &'a str) -> Self {
+        match uid {
+            Some(uid) => Self::Uid(uid),
+            None => Self::Name { kind, name },
+        }
+    }
+
+    fn field_selector(&self) -> String {
+        match self {
+            Self::Uid(uid) => format!("involvedObject.uid%3D{}", uid),
+            Self::Name { kind, name } => format!(
+                "involvedObject.kind%3D{}%2CinvolvedObject.name%3D{}",
+                kind, name
+            ),
+        }
+    }
+}
+
+/// 対象のオブジェクトに関連するイベントを取得する
+///
+/// namespaceがNoneのときは全namespaceから探す
+pub async fn fetch_related_events<C: KubeClientRequest>(
+    client: &C,
+    namespace: Option<&str>,
+    object: InvolvedObject<'_>,
+) -> Result<Vec<Event>> {
+    let url = format!(
+        "{}?fieldSelector={}",
+        Event::url_path(&(), namespace),
+        object.field_selector()
+    );
+
+    let events: List<Event> = client.request(&url).await?;
+
+    Ok(events.items)
+}
+
+/// 関連するイベントを取得して説明の末尾に追加するyamlを返す
+///
+/// イベントを取得できなくても説明は表示したいので、失敗したときはログに残して何も追加しない
+pub async fn related_events<C: KubeClientRequest>(
+    client: &C,
+    namespace: Option<&str>,
+    object: InvolvedObject<'_>,
+) -> Vec<String> {
+    let lines = match fetch_related_events(client, namespace, object).await {
+        Ok(events) => related_events_lines(&events, Utc::now()),
+        Err(e) => Err(e),
+    };
+
+    lines.unwrap_or_else(|e| {
+        logger!(warn, "Failed to fetch related events {:?}: {}", object, e);
+        Vec::new()
+    })
+}
+
+/// 空行に続けてrelatedEventsを出力する（イベントがないときは空）
+pub fn related_events_lines(events: &[Event], now: DateTime<Utc>) -> Result<Vec<String>> {
+    if events.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut root = Mapping::new();
+
+    root.insert("relatedEvents".into(), events_value(events, now));
+
+    let mut lines = vec![String::default()];
+
+    lines.extend(
+        serde_yaml::to_string(&root)?
+            .lines()
+            .map(ToString::to_string),
+    );
+
+    Ok(lines)
+}
+
+/// 最終発生日時の新しい順にイベントを並べる
+pub fn events_value(events: &[Event], now: DateTime<Utc>) -> Value {
+    let mut events: Vec<&Event> = events.iter().collect();
+
+    events.sort_by_key(|ev| std::cmp::Reverse(last_seen(ev)));
+
+    events
+        .into_iter()
+        .map(|ev| {
+            let count = match count(ev) {
+                count if 1 < count => format!(" (x{})", count),
+                _ => String::new(),
+            };
+
+            Value::from(format!(
+                "{}  {}  {}  {}{}",
+                age(last_seen(ev).map(Time).as_ref(), now),
+                ev.type_.as_deref().unwrap_or_default(),
+                ev.reason.as_deref().unwrap_or_default(),
+                ev.message.as_deref().unwrap_or_default().trim(),
+                count
+            ))
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::bail;
+    use chrono::TimeZone as _;
+    use indoc::indoc;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    fn events() -> List<Event> {
+        serde_yaml::from_str(indoc! {r#"
+            items:
+              - involvedObject:
+                  kind: Service
+                  name: test
+                metadata:
+                  name: test.1
+                lastTimestamp: "2024-01-01T11:50:00Z"
+                type: Normal
+                reason: Created
+                message: created
+              - involvedObject:
+                  kind: Service
+                  name: test
+                metadata:
+                  name: test.2
+                lastTimestamp: "2024-01-01T11:58:00Z"
+                count: 2
+                type: Warning
+                reason: SyncLoadBalancerFailed
+                message: "Error syncing load balancer "
+        "#})
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn uidがあるときはuidで絞り込む() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                List<Event>,
+                eq("/api/v1/namespaces/default/events?fieldSelector=involvedObject.uid%3Dsvc-uid"),
+                Ok(events())
+            )]
+        );
+
+        let actual = fetch_related_events(&client, Some("default"), InvolvedObject::Uid("svc-uid"))
+            .await
+            .unwrap();
+
+        assert_eq!(actual.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn uidがないときは種類と名前で絞り込む() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                List<Event>,
+                eq("/api/v1/events?fieldSelector=involvedObject.kind%3DNode%2CinvolvedObject.name%3Dnode-1"),
+                Ok(List::default())
+            )]
+        );
+
+        let actual = fetch_related_events(
+            &client,
+            None,
+            InvolvedObject::Name {
+                kind: "Node",
+                name: "node-1",
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(actual.is_empty());
+    }
+
+    #[tokio::test]
+    async fn イベントの取得に失敗したときは何も追加しない() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                List<Event>,
+                eq("/api/v1/namespaces/default/events?fieldSelector=involvedObject.uid%3Dsvc-uid"),
+                bail!("forbidden")
+            )]
+        );
+
+        let actual = related_events(&client, Some("default"), InvolvedObject::Uid("svc-uid")).await;
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn 空行に続けて新しい順にイベントを出力する() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let actual = related_events_lines(&events().items, now).unwrap();
+
+        let expected: Vec<String> = indoc! {"
+
+            relatedEvents:
+            - 2m0s  Warning  SyncLoadBalancerFailed  Error syncing load balancer (x2)
+            - 10m  Normal  Created  created
+        "}
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn イベントがないときは空を返す() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        assert!(related_events_lines(&[], now).unwrap().is_empty());
+    }
+}
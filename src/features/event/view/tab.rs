@@ -1,10 +1,9 @@
-use std::{cell::RefCell, rc::Rc};
-
+use crossbeam::channel::Sender;
 use ratatui::prelude::Constraint;
 
 use crate::{
-    clipboard::Clipboard,
     features::component_id::EVENT_TAB_ID,
+    message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
         Tab,
@@ -18,8 +17,8 @@ pub struct EventTab {
 }
 
 impl EventTab {
-    pub fn new(title: &str, clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Self {
-        let event_widget = event_widget(clipboard);
+    pub fn new(title: &str, tx: &Sender<Message>) -> Self {
+        let event_widget = event_widget(tx);
 
         EventTab {
             tab: Tab::new(
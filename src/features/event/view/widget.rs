@@ -1,38 +1,180 @@
-use std::{cell::RefCell, rc::Rc};
+use std::collections::{BTreeMap, BTreeSet};
 
-use ratatui::widgets::Block;
+use crossbeam::channel::Sender;
 
 use crate::{
-    clipboard::Clipboard,
-    features::component_id::EVENT_WIDGET_ID,
-    ui::widget::{config::WidgetConfig, Text, Widget, WidgetTrait as _},
+    features::{
+        component_id::{EVENT_WIDGET_ID, YAML_POPUP_ID},
+        get::message::GetRequest,
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, Table, TableItem, Widget, WidgetTrait as _},
+        Window,
+    },
 };
 
-pub fn event_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
-    let builder = Text::builder()
+const TYPE_KEY: &str = "type";
+const KIND_KEY: &str = "kind";
+const WARNING: &str = "Warning";
+
+pub fn event_widget(tx: &Sender<Message>) -> Widget<'static> {
+    Table::builder()
         .id(EVENT_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Event").build())
-        .wrap()
-        .follow()
-        .block_injection(block_injection());
-
-    if let Some(cb) = clipboard {
-        builder.clipboard(cb.clone())
-    } else {
-        builder
+        .filtered_key("OBJECT")
+        .block_injection(block_injection())
+        .on_select(on_select(tx.clone()))
+        .action('w', toggle_warning_only)
+        .action('K', cycle_kind)
+        .build()
+        .into()
+}
+
+fn block_injection() -> impl Fn(&Table) -> WidgetConfig {
+    |table: &Table| {
+        let index = if let Some(index) = table.state().selected() {
+            index + 1
+        } else {
+            0
+        };
+
+        let mut widget_config = table.widget_config().clone();
+
+        let filter = filter_title(table.metadata_filter());
+
+        *widget_config.append_title_mut() =
+            Some(format!(" [{}/{}]{}", index, table.items().len(), filter).into());
+
+        widget_config
+    }
+}
+
+/// 絞り込み中の条件をタイトルに表示する
+fn filter_title(filter: &BTreeMap<String, String>) -> String {
+    if filter.is_empty() {
+        return String::new();
+    }
+
+    let conditions: Vec<String> = [TYPE_KEY, KIND_KEY]
+        .iter()
+        .filter_map(|key| filter.get(*key).map(|value| format!("{}={}", key, value)))
+        .collect();
+
+    format!(" ({})", conditions.join(", "))
+}
+
+/// Warningのイベントだけを表示するかを切り替える
+fn toggle_warning_only(w: &mut Window) -> EventResult {
+    let table = w.find_widget_mut(EVENT_WIDGET_ID).as_mut_table();
+
+    let mut filter = table.metadata_filter().clone();
+
+    if filter.remove(TYPE_KEY).is_none() {
+        filter.insert(TYPE_KEY.to_string(), WARNING.to_string());
+    }
+
+    table.update_metadata_filter(filter);
+
+    EventResult::Nop
+}
+
+/// 関連するオブジェクトのkindで絞り込む
+/// 表示中のkindを順番に切り替え、最後のkindの次は絞り込みを解除する
+fn cycle_kind(w: &mut Window) -> EventResult {
+    let table = w.find_widget_mut(EVENT_WIDGET_ID).as_mut_table();
+
+    let kinds = kinds(table.original_items());
+
+    let mut filter = table.metadata_filter().clone();
+
+    match next_kind(&kinds, filter.get(KIND_KEY).map(String::as_str)) {
+        Some(kind) => filter.insert(KIND_KEY.to_string(), kind),
+        None => filter.remove(KIND_KEY),
+    };
+
+    table.update_metadata_filter(filter);
+
+    EventResult::Nop
+}
+
+fn kinds(items: &[TableItem]) -> BTreeSet<String> {
+    items
+        .iter()
+        .filter_map(|item| item.metadata.as_ref()?.get(KIND_KEY))
+        .filter(|kind| !kind.is_empty())
+        .cloned()
+        .collect()
+}
+
+fn next_kind(kinds: &BTreeSet<String>, current: Option<&str>) -> Option<String> {
+    match current {
+        Some(current) => kinds.iter().find(|kind| current < kind.as_str()).cloned(),
+        None => kinds.first().cloned(),
+    }
+}
+
+/// 関連するオブジェクトのYAMLを表示する
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        let Some(metadata) = v.metadata.as_ref() else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(kind), Some(name)) = (metadata.get(KIND_KEY), metadata.get("name")) else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(
+            GetRequest {
+                name: name.to_string(),
+                namespace: metadata.get("namespace").cloned(),
+                kind: kind.to_string(),
+                api_version: metadata.get("api_version").cloned(),
+            }
+            .into(),
+        )
+        .expect("Failed to send GetRequest");
+
+        w.widget_clear(YAML_POPUP_ID);
+        w.open_popup(YAML_POPUP_ID);
+
+        EventResult::Nop
     }
-    .build()
-    .into()
 }
 
-fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
-    |text: &Text, is_active: bool, is_mouse_over: bool| {
-        let (index, size) = text.state();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
 
-        let mut config = text.widget_config().clone();
+    #[rstest]
+    #[case::first(None, Some("Deployment"))]
+    #[case::next(Some("Deployment"), Some("Pod"))]
+    #[case::last(Some("Pod"), None)]
+    #[case::disappeared(Some("Job"), Some("Pod"))]
+    fn kindを順番に切り替える(
+        #[case] current: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        let kinds = BTreeSet::from(["Deployment".to_string(), "Pod".to_string()]);
+
+        assert_eq!(
+            next_kind(&kinds, current),
+            expected.map(ToString::to_string)
+        );
+    }
 
-        *config.append_title_mut() = Some(format!(" [{}/{}]", index, size).into());
+    #[test]
+    fn 絞り込み条件をタイトルに表示する() {
+        let filter = BTreeMap::from([
+            (KIND_KEY.to_string(), "Pod".to_string()),
+            (TYPE_KEY.to_string(), WARNING.to_string()),
+        ]);
 
-        config.render_block(text.can_activate() && is_active, is_mouse_over)
+        assert_eq!(filter_title(&filter), " (type=Warning, kind=Pod)");
+        assert_eq!(filter_title(&BTreeMap::new()), "");
     }
 }
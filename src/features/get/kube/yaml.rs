@@ -1,113 +1,92 @@
 use std::sync::{atomic::AtomicBool, Arc};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use crossbeam::channel::Sender;
-use k8s_openapi::{
-    api::{
-        core::v1::{ConfigMap, Pod, Secret, Service},
-        networking::v1::{Ingress, NetworkPolicy},
-    },
-    NamespaceResourceScope,
-};
-use kube::{Api, Resource};
-use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    features::get::message::{GetRequest, GetResponse},
-    kube::KubeClient,
-    logger,
+    features::{
+        api_resources::kube::SharedApiResources,
+        get::message::{GetRequest, GetResponse},
+        yaml::{
+            kube::fetch_resource_yaml,
+            revision::{YamlContent, YamlRevisions},
+        },
+    },
+    kube::KubeClientRequest,
     message::Message,
-    workers::kube::AbortWorker,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::AbortWorker,
+    },
 };
 
-#[derive(Debug, Clone)]
-pub enum GetYamlKind {
-    Pod,
-    ConfigMap,
-    Secret,
-    Ingress,
-    Service,
-    NetworkPolicy,
-}
-
-impl std::fmt::Display for GetYamlKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            GetYamlKind::Pod => write!(f, "pods"),
-            GetYamlKind::ConfigMap => write!(f, "configmaps"),
-            GetYamlKind::Secret => write!(f, "secrets"),
-            GetYamlKind::Ingress => write!(f, "ingresses"),
-            GetYamlKind::Service => write!(f, "services"),
-            GetYamlKind::NetworkPolicy => write!(f, "networkpolicies"),
-        }
-    }
-}
-
 #[derive(Clone)]
-pub struct GetYamlWorker {
+pub struct GetYamlWorker<C>
+where
+    C: KubeClientRequest,
+{
     is_terminated: Arc<AtomicBool>,
     tx: Sender<Message>,
-    client: KubeClient,
+    client: C,
+    shared_api_resources: SharedApiResources,
     req: GetRequest,
 }
 
-impl GetYamlWorker {
+impl<C: KubeClientRequest> GetYamlWorker<C> {
     pub fn new(
         is_terminated: Arc<AtomicBool>,
         tx: Sender<Message>,
-        client: KubeClient,
+        client: C,
+        shared_api_resources: SharedApiResources,
         req: GetRequest,
     ) -> Self {
         Self {
             is_terminated,
             tx,
             client,
+            shared_api_resources,
             req,
         }
     }
 }
 
 #[async_trait::async_trait]
-impl AbortWorker for GetYamlWorker {
+impl<C: KubeClientRequest> AbortWorker for GetYamlWorker<C> {
     async fn run(&self) {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3));
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().description);
 
         let GetRequest {
             kind,
             name,
             namespace,
+            api_version,
         } = &self.req;
 
+        let mut revisions = YamlRevisions::default();
+
         while !self
             .is_terminated
             .load(std::sync::atomic::Ordering::Relaxed)
         {
             interval.tick().await;
 
-            let yaml = match kind {
-                GetYamlKind::Pod => fetch_resource_yaml::<Pod>(&self.client, name, namespace).await,
-                GetYamlKind::ConfigMap => {
-                    fetch_resource_yaml::<ConfigMap>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Secret => {
-                    fetch_resource_yaml::<Secret>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Ingress => {
-                    fetch_resource_yaml::<Ingress>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Service => {
-                    fetch_resource_yaml::<Service>(&self.client, name, namespace).await
-                }
-                GetYamlKind::NetworkPolicy => {
-                    fetch_resource_yaml::<NetworkPolicy>(&self.client, name, namespace).await
-                }
-            };
+            let (resource, yaml) = self
+                .fetch(
+                    kind,
+                    api_version.as_deref(),
+                    name,
+                    namespace.as_deref(),
+                    &mut revisions,
+                )
+                .await;
+
+            interval.record(&yaml);
 
             self.tx
                 .send(
                     GetResponse {
                         yaml,
-                        kind: kind.to_string(),
+                        kind: resource,
                         name: name.to_string(),
                     }
                     .into(),
@@ -117,33 +96,44 @@ impl AbortWorker for GetYamlWorker {
     }
 }
 
-/// 選択されているリソースのyamlを取得する
-async fn fetch_resource_yaml<K>(client: &KubeClient, name: &str, ns: &str) -> Result<Vec<String>>
-where
-    K: Resource<Scope = NamespaceResourceScope> + k8s_openapi::Resource,
-    <K as kube::Resource>::DynamicType: Default,
-    K: DeserializeOwned + Clone + std::fmt::Debug,
-    K: Serialize,
-{
-    logger!(
-        info,
-        "Fetching resource target [kind={} ns={} name={}]",
-        K::KIND,
-        ns,
-        name
-    );
-
-    let api: Api<K> = Api::namespaced(client.to_client(), ns);
-
-    let mut data = api.get(name).await?;
-
-    let metadata = data.meta_mut();
-    metadata.managed_fields = None;
-
-    let yaml_string = serde_yaml::to_string(&data)?
-        .lines()
-        .map(ToString::to_string)
-        .collect();
+impl<C: KubeClientRequest> GetYamlWorker<C> {
+    /// kind（分かるときはapiVersionも）をAPIリソースから解決してyamlを取得する（カスタムリソースやクラスタスコープのリソースも対象）
+    ///
+    /// タイトルに表示するため、解決できたときはリソース名も返す
+    async fn fetch(
+        &self,
+        kind: &str,
+        api_version: Option<&str>,
+        name: &str,
+        namespace: Option<&str>,
+        revisions: &mut YamlRevisions,
+    ) -> (String, Result<YamlContent>) {
+        let api_resources = self.shared_api_resources.read().await;
+
+        let Some(api) = api_resources.resolve(api_version, kind) else {
+            return (
+                kind.to_string(),
+                Err(anyhow!("Can't find {} in API resources", kind)),
+            );
+        };
+
+        if api.is_namespaced() && namespace.is_none() {
+            return (
+                api.name().to_string(),
+                Err(anyhow!("Namespace is required for {}/{}", api.name(), name)),
+            );
+        }
 
-    Ok(yaml_string)
+        let yaml = fetch_resource_yaml(
+            &self.client,
+            &api_resources,
+            api,
+            name.to_string(),
+            namespace.unwrap_or_default().to_string(),
+            revisions,
+        )
+        .await;
+
+        (api.name().to_string(), yaml)
+    }
 }
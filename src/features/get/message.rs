@@ -1,8 +1,8 @@
 use anyhow::Result;
 
-use crate::{message::Message, workers::kube::message::Kube};
-
-pub use super::kube::yaml::GetYamlKind;
+use crate::{
+    features::yaml::revision::YamlContent, message::Message, workers::kube::message::Kube,
+};
 
 #[derive(Debug)]
 pub enum GetMessage {
@@ -13,15 +13,19 @@ pub enum GetMessage {
 #[derive(Debug, Clone)]
 pub struct GetRequest {
     pub name: String,
-    pub namespace: String,
-    pub kind: GetYamlKind,
+    /// クラスタスコープのリソースはNone
+    pub namespace: Option<String>,
+    /// Kubernetesのkind（またはリソース名）
+    pub kind: String,
+    /// 分かるときはapiVersion（グループの違う同じkindのリソースを区別する）
+    pub api_version: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct GetResponse {
     pub kind: String,
     pub name: String,
-    pub yaml: Result<Vec<String>>,
+    pub yaml: Result<YamlContent>,
 }
 
 impl From<GetRequest> for Message {
@@ -2,7 +2,14 @@ use std::{cell::RefCell, rc::Rc};
 
 use crate::{
     clipboard::Clipboard,
-    features::component_id::YAML_POPUP_ID,
+    features::{
+        component_id::YAML_POPUP_ID,
+        edit::view::EditOpener,
+        yaml::{
+            diff::{newer_revision, older_revision, toggle_json_mode, toggle_neat_mode, YamlDiff},
+            view::{JsonPathOpener, SaveOpener},
+        },
+    },
     ui::widget::{config::WidgetConfig, Text, Widget, WidgetTrait},
 };
 
@@ -11,26 +18,59 @@ pub struct YamlPopup {
 }
 
 impl YamlPopup {
-    pub fn new(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Self {
+    pub fn new(
+        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        diff: Rc<RefCell<YamlDiff>>,
+        json_path: &JsonPathOpener,
+        save: &SaveOpener,
+        edit: &EditOpener,
+    ) -> Self {
         Self {
-            popup: popup(clipboard),
+            popup: popup(clipboard, diff, json_path, save, edit),
         }
     }
 }
 
-pub fn popup(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+pub fn popup(
+    clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    diff: Rc<RefCell<YamlDiff>>,
+    json_path: &JsonPathOpener,
+    save: &SaveOpener,
+    edit: &EditOpener,
+) -> Widget<'static> {
+    let title_diff = diff.clone();
+
     let mut builder = Text::builder()
         .id(YAML_POPUP_ID)
         .widget_config(&WidgetConfig::builder().title("Yaml").build())
-        .block_injection(|text: &Text, is_active: bool, is_mouse_over: bool| {
+        .block_injection(move |text: &Text, is_active: bool, is_mouse_over: bool| {
             let (index, size) = text.state();
 
             let mut config = text.widget_config().clone();
 
-            *config.title_mut() = format!("Yaml [{}/{}]", index, size).into();
+            let diff = title_diff.borrow();
+
+            *config.title_mut() = format!(
+                "Yaml [{}/{}]{}{}",
+                index,
+                size,
+                diff.output().title(),
+                diff.title().unwrap_or_default()
+            )
+            .into();
 
             config.render_block(text.can_activate() && is_active, is_mouse_over)
         })
+        .action('[', older_revision(diff.clone(), YAML_POPUP_ID))
+        .action(']', newer_revision(diff.clone(), YAML_POPUP_ID))
+        .action('C', toggle_neat_mode(diff.clone(), YAML_POPUP_ID))
+        .action('J', toggle_json_mode(diff.clone(), YAML_POPUP_ID))
+        .action(
+            'P',
+            json_path.open(diff.clone(), YAML_POPUP_ID, Some(YAML_POPUP_ID)),
+        )
+        .action('S', save.open(diff.clone()))
+        .action('e', edit.open(diff))
         .wrap();
 
     if let Some(clipboard) = clipboard {
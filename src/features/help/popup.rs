@@ -3,7 +3,10 @@ use unicode_width::UnicodeWidthStr;
 use crate::{
     ansi::{AnsiEscapeSequence, TextParser},
     features::component_id::HELP_POPUP_ID,
-    ui::widget::{config::WidgetConfig, Text, Widget},
+    ui::{
+        theme::{paint, Theme},
+        widget::{config::WidgetConfig, Text, Widget},
+    },
 };
 
 const LEFT_HELP_TEXT: &[HelpBlock] = &[
@@ -11,7 +14,7 @@ const LEFT_HELP_TEXT: &[HelpBlock] = &[
         title: "General",
         bindings: &[
             KeyBindings {
-                keys: &["0~6"],
+                keys: &["1~7"],
                 desc: "switch tab",
             },
             KeyBindings {
@@ -38,6 +41,26 @@ const LEFT_HELP_TEXT: &[HelpBlock] = &[
                 keys: &["y"],
                 desc: "open yaml popup",
             },
+            KeyBindings {
+                keys: &["D"],
+                desc: "open describe popup",
+            },
+            KeyBindings {
+                keys: &["o"],
+                desc: "open owner tree popup",
+            },
+            KeyBindings {
+                keys: &["A"],
+                desc: "open alerts popup",
+            },
+            KeyBindings {
+                keys: &["E"],
+                desc: "open error history popup",
+            },
+            KeyBindings {
+                keys: &["p"],
+                desc: "open api resource presets popup",
+            },
             KeyBindings {
                 keys: &["q", "Esc"],
                 desc: "quit",
@@ -156,10 +179,61 @@ const RIGHT_HELP_TEXT: &[HelpBlock] = &[
     },
     HelpBlock {
         title: "List / Yaml Tab",
-        bindings: &[KeyBindings {
-            keys: &["f"],
-            desc: "open select popup",
-        }],
+        bindings: &[
+            KeyBindings {
+                keys: &["f"],
+                desc: "open select popup",
+            },
+            KeyBindings {
+                keys: &["w"],
+                desc: "toggle wide columns (List)",
+            },
+            KeyBindings {
+                keys: &["Enter"],
+                desc: "open the row in the yaml popup (List)",
+            },
+            KeyBindings {
+                keys: &["l"],
+                desc: "pre-fill the log query with the workload (List)",
+            },
+            KeyBindings {
+                keys: &["/"],
+                desc: "filter the rows of the resource (List)",
+            },
+            KeyBindings {
+                keys: &["Y"],
+                desc: "copy the selected row (List)",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "Yaml",
+        bindings: &[
+            KeyBindings {
+                keys: &["[", "]"],
+                desc: "diff against older/newer revision",
+            },
+            KeyBindings {
+                keys: &["C"],
+                desc: "toggle clean (neat) output",
+            },
+            KeyBindings {
+                keys: &["J"],
+                desc: "toggle yaml/json",
+            },
+            KeyBindings {
+                keys: &["P"],
+                desc: "filter with jsonpath (empty: back to yaml)",
+            },
+            KeyBindings {
+                keys: &["S"],
+                desc: "save to a file (raw / clean / list tab bundle)",
+            },
+            KeyBindings {
+                keys: &["e"],
+                desc: "edit with $EDITOR (dry-run, diff, then apply)",
+            },
+        ],
     },
     HelpBlock {
         title: "Search (Only text view)",
@@ -199,6 +273,19 @@ const RIGHT_HELP_TEXT: &[HelpBlock] = &[
             },
         ],
     },
+    HelpBlock {
+        title: "Pod",
+        bindings: &[
+            KeyBindings {
+                keys: &["d"],
+                desc: "open pod detail popup",
+            },
+            KeyBindings {
+                keys: &["[", "]"],
+                desc: "rewind/forward pod history",
+            },
+        ],
+    },
     HelpBlock {
         title: "Log",
         bindings: &[KeyBindings {
@@ -206,6 +293,23 @@ const RIGHT_HELP_TEXT: &[HelpBlock] = &[
             desc: "insert blank line",
         }],
     },
+    HelpBlock {
+        title: "Event",
+        bindings: &[
+            KeyBindings {
+                keys: &["Enter"],
+                desc: "open yaml popup of the involved object",
+            },
+            KeyBindings {
+                keys: &["w"],
+                desc: "toggle warning only",
+            },
+            KeyBindings {
+                keys: &["K"],
+                desc: "filter by involved object kind",
+            },
+        ],
+    },
 ];
 
 struct KeyBindings {
@@ -246,12 +350,9 @@ impl HelpBlock {
             .bindings
             .iter()
             .map(|b| {
-                format!(
-                    "\x1b[96m{:>pad$}:\x1b[0m {}",
-                    b.keys(),
-                    b.desc(),
-                    pad = max_key_len
-                )
+                let keys = format!("{:>pad$}:", b.keys(), pad = max_key_len);
+
+                format!("{} {}", paint(Theme::global().info, keys), b.desc())
             })
             .collect();
 
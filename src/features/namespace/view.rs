@@ -3,3 +3,29 @@ mod single_namespace_popup;
 
 pub use multiple_namespaces_popup::*;
 pub use single_namespace_popup::*;
+
+use crate::{
+    features::{
+        api_resources::view::clear_list,
+        component_id::{
+            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, EVENT_WIDGET_ID,
+            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID, POD_LOG_QUERY_WIDGET_ID,
+            POD_LOG_WIDGET_ID, POD_WIDGET_ID, YAML_WIDGET_ID,
+        },
+    },
+    ui::Window,
+};
+
+/// namespaceを切り替えたときに前のnamespaceの内容を消す
+pub fn clear_namespaced_widgets(w: &mut Window) {
+    w.widget_clear(POD_WIDGET_ID);
+    w.widget_clear(POD_LOG_WIDGET_ID);
+    w.widget_clear(POD_LOG_QUERY_WIDGET_ID);
+    w.widget_clear(CONFIG_WIDGET_ID);
+    w.widget_clear(CONFIG_RAW_DATA_WIDGET_ID);
+    w.widget_clear(NETWORK_WIDGET_ID);
+    w.widget_clear(NETWORK_DESCRIPTION_WIDGET_ID);
+    w.widget_clear(EVENT_WIDGET_ID);
+    clear_list(w);
+    w.widget_clear(YAML_WIDGET_ID);
+}
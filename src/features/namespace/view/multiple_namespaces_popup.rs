@@ -1,14 +1,7 @@
 use crossbeam::channel::Sender;
 
 use crate::{
-    features::{
-        component_id::{
-            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, EVENT_WIDGET_ID, LIST_WIDGET_ID,
-            MULTIPLE_NAMESPACES_POPUP_ID, NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID,
-            POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID, YAML_WIDGET_ID,
-        },
-        namespace::message::NamespaceRequest,
-    },
+    features::{component_id::MULTIPLE_NAMESPACES_POPUP_ID, namespace::message::NamespaceRequest},
     message::Message,
     ui::{
         event::EventResult,
@@ -17,6 +10,8 @@ use crate::{
     },
 };
 
+use super::clear_namespaced_widgets;
+
 pub struct MultipleNamespacesPopup {
     pub popup: Widget<'static>,
 }
@@ -58,16 +53,7 @@ fn on_select(
         tx.send(NamespaceRequest::Set(items).into())
             .expect("Failed to send NamespaceRequest::Set");
 
-        w.widget_clear(POD_WIDGET_ID);
-        w.widget_clear(POD_LOG_WIDGET_ID);
-        w.widget_clear(POD_LOG_QUERY_WIDGET_ID);
-        w.widget_clear(CONFIG_WIDGET_ID);
-        w.widget_clear(CONFIG_RAW_DATA_WIDGET_ID);
-        w.widget_clear(NETWORK_WIDGET_ID);
-        w.widget_clear(NETWORK_DESCRIPTION_WIDGET_ID);
-        w.widget_clear(EVENT_WIDGET_ID);
-        w.widget_clear(LIST_WIDGET_ID);
-        w.widget_clear(YAML_WIDGET_ID);
+        clear_namespaced_widgets(w);
 
         EventResult::Nop
     }
@@ -12,7 +12,10 @@ use crate::{
     features::network::message::{NetworkRequest, NetworkResponse, RequestData},
     kube::KubeClientRequest,
     message::Message,
-    workers::kube::AbortWorker,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::AbortWorker,
+    },
 };
 
 use self::{
@@ -24,8 +27,6 @@ use anyhow::Result;
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
 
-const INTERVAL: u64 = 3;
-
 type FetchedData = Vec<String>;
 
 #[async_trait]
@@ -103,7 +104,7 @@ where
     where
         Worker: Fetch<'a, C>,
     {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(INTERVAL));
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().description);
 
         let RequestData { name, namespace } = self.req.data();
 
@@ -117,6 +118,8 @@ where
 
             let fetched_data = worker.fetch().await;
 
+            interval.record(&fetched_data);
+
             self.tx
                 .send(NetworkResponse::Yaml(fetched_data).into())
                 .expect("Failed to send NetworkResponse::Yaml");
@@ -280,7 +283,7 @@ mod tests {
         use indoc::indoc;
         use k8s_openapi::{
             api::{
-                core::v1::Service,
+                core::v1::{Event, Service},
                 networking::v1::{Ingress, NetworkPolicy},
             },
             List,
@@ -322,6 +325,11 @@ mod tests {
                         List<NetworkPolicy>,
                         eq("/apis/networking.k8s.io/v1/namespaces/default/networkpolicies"),
                         Ok(Default::default())
+                    ),
+                    (
+                        List<Event>,
+                        eq("/api/v1/namespaces/default/events?fieldSelector=involvedObject.kind%3DPod%2CinvolvedObject.name%3Dtest"),
+                        Ok(Default::default())
                     )
                 ]
             );
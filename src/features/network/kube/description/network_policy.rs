@@ -159,7 +159,6 @@ mod extract {
                     ..Default::default()
                 },
                 spec: self.spec.clone(),
-                status: self.status.clone(),
             }
         }
     }
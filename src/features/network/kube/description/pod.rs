@@ -15,7 +15,10 @@ use super::{
     Fetch, FetchedData, Result,
 };
 
-use crate::kube::KubeClientRequest;
+use crate::{
+    features::event::kube::{related_events, InvolvedObject},
+    kube::KubeClientRequest,
+};
 
 pub(super) struct PodDescriptionWorker<'a, C>
 where
@@ -44,6 +47,7 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
         );
 
         let pod: Pod = self.client.request(&url).await?;
+        let uid = pod.uid();
         let pod = pod.extract();
 
         let related_services = RelatedClient::new(self.client, &self.namespace)
@@ -108,6 +112,15 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
             value.extend(vec);
         }
 
+        value.extend(
+            related_events(
+                self.client,
+                Some(&self.namespace),
+                InvolvedObject::new(uid.as_deref(), "Pod", &self.name),
+            )
+            .await,
+        );
+
         Ok(value)
     }
 }
@@ -120,7 +133,7 @@ mod tests {
     use indoc::indoc;
     use k8s_openapi::{
         api::{
-            core::v1::{Pod, Service},
+            core::v1::{Event, Pod, Service},
             networking::v1::{Ingress, NetworkPolicy},
         },
         List,
@@ -477,6 +490,11 @@ mod tests {
                     List<NetworkPolicy>,
                     eq("/apis/networking.k8s.io/v1/namespaces/default/networkpolicies"),
                     Ok(networkpolicies())
+                ),
+                (
+                    List<Event>,
+                    eq("/api/v1/namespaces/default/events?fieldSelector=involvedObject.uid%3D7a1ffede-c201-4438-893b-f81dc5ded89e"),
+                    Ok(Default::default())
                 )
             ]
         );
@@ -9,7 +9,10 @@ use k8s_openapi::{
 use kube::{Resource, ResourceExt};
 use serde_yaml::Mapping;
 
-use crate::kube::KubeClientRequest;
+use crate::{
+    features::event::kube::{related_events, InvolvedObject},
+    kube::KubeClientRequest,
+};
 
 use self::to_value::ToValue;
 
@@ -50,6 +53,7 @@ where
         );
 
         let service: Service = self.client.request(&url).await?;
+        let uid = service.uid();
         let service = service.extract();
 
         let related_ingresses: Option<List<Ingress>> =
@@ -105,6 +109,15 @@ where
             value.extend(related_resources);
         }
 
+        value.extend(
+            related_events(
+                self.client,
+                Some(&self.namespace),
+                InvolvedObject::new(uid.as_deref(), "Service", &self.name),
+            )
+            .await,
+        );
+
         Ok(value)
     }
 }
@@ -114,7 +127,10 @@ mod tests {
     use anyhow::bail;
     use indoc::indoc;
     use k8s_openapi::{
-        api::{core::v1::Pod, networking::v1::Ingress},
+        api::{
+            core::v1::{Event, Pod},
+            networking::v1::Ingress,
+        },
         List,
     };
     use mockall::predicate::eq;
@@ -224,6 +240,11 @@ mod tests {
                     List<Pod>,
                     eq("/api/v1/namespaces/default/pods"),
                     Ok(pods())
+                ),
+                (
+                    List<Event>,
+                    eq("/api/v1/namespaces/default/events?fieldSelector=involvedObject.kind%3DService%2CinvolvedObject.name%3Dservice"),
+                    Ok(Default::default())
                 )
             ]
         );
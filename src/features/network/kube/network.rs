@@ -1,16 +1,30 @@
-use std::{collections::BTreeMap, time};
+use std::{collections::BTreeMap, time::Duration};
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use futures::future::try_join_all;
+use k8s_openapi::{
+    api::{
+        core::v1::{Pod, Service},
+        networking::v1::{Ingress, NetworkPolicy},
+    },
+    NamespaceResourceScope,
+};
+use kube::{Resource, ResourceExt as _};
+use serde::de::DeserializeOwned;
 
 use crate::{
-    features::network::message::NetworkResponse,
+    features::{network::message::NetworkResponse, workload::kube::age},
     kube::{
         apis::v1_table::TableRow,
+        reflector::{NamespacedStore, ReflectorSignal},
         table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
     },
-    workers::kube::{PollerBase, Worker, WorkerResult},
+    workers::{
+        interval::IntervalConfig,
+        kube::{PollerBase, ReflectorPoller, Worker, WorkerResult},
+    },
 };
 
 #[derive(Clone)]
@@ -29,21 +43,67 @@ impl Worker for NetworkPoller {
     type Output = WorkerResult;
 
     async fn run(&self) -> Self::Output {
-        let mut interval = tokio::time::interval(time::Duration::from_secs(1));
+        self.run_reflector().await
+    }
+}
+
+pub struct NetworkStores {
+    ingresses: NamespacedStore<Ingress>,
+    services: NamespacedStore<Service>,
+    pods: NamespacedStore<Pod>,
+    network_policies: NamespacedStore<NetworkPolicy>,
+}
+
+#[async_trait]
+impl ReflectorPoller for NetworkPoller {
+    type Stores = NetworkStores;
+    type Output = KubeTable;
 
-        let is_terminated = &self.base.is_terminated;
-        let tx = &self.base.tx;
+    fn base(&self) -> &PollerBase {
+        &self.base
+    }
 
-        while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
-            interval.tick().await;
+    fn interval(&self) -> Duration {
+        IntervalConfig::global().network
+    }
 
-            let table = self.polling().await;
+    fn spawn_stores(&self, namespaces: &[String], signal: &ReflectorSignal) -> Self::Stores {
+        let client = &self.base.kube_client;
 
-            tx.send(NetworkResponse::List(table).into())
-                .expect("Failed to send NetworkResponse::List");
+        NetworkStores {
+            ingresses: NamespacedStore::spawn(client, namespaces, signal),
+            services: NamespacedStore::spawn(client, namespaces, signal),
+            pods: NamespacedStore::spawn(client, namespaces, signal),
+            network_policies: NamespacedStore::spawn(client, namespaces, signal),
         }
+    }
+
+    fn render(&self, stores: &Self::Stores, namespaces: &[String]) -> Result<Self::Output> {
+        let now = Utc::now();
+        let insert_ns = insert_ns(namespaces);
+
+        let rows = [
+            store_rows(&stores.ingresses, insert_ns, now),
+            store_rows(&stores.services, insert_ns, now),
+            store_rows(&stores.pods, insert_ns, now),
+            store_rows(&stores.network_policies, insert_ns, now),
+        ];
+
+        Ok(network_table(
+            namespaces,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
 
-        WorkerResult::Terminated
+    async fn poll(&self, namespaces: &[String]) -> Result<Self::Output> {
+        self.polling(namespaces).await
+    }
+
+    fn send(&self, output: Result<Self::Output>) {
+        self.base
+            .tx
+            .send(NetworkResponse::List(output).into())
+            .expect("Failed to send NetworkResponse::List");
     }
 }
 
@@ -59,31 +119,16 @@ const POLLING_RESOURCES: [[&str; 3]; 4] = [
 ];
 
 impl NetworkPoller {
-    async fn polling(&self) -> Result<KubeTable> {
-        let target_namespaces = self.base.shared_target_namespaces.read().await;
-        let mut table = KubeTable {
-            header: if target_namespaces.len() == 1 {
-                ["KIND", "NAME", "AGE"]
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect()
-            } else {
-                ["NAMESPACE", "KIND", "NAME", "AGE"]
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect()
-            },
-            ..Default::default()
-        };
-
+    async fn polling(&self, target_namespaces: &[String]) -> Result<KubeTable> {
         let jobs = try_join_all(POLLING_RESOURCES.iter().map(|&[kind, plural, api]| {
-            self.fetch_per_namespace(&target_namespaces, kind, api, plural)
+            self.fetch_per_namespace(target_namespaces, kind, api, plural)
         }))
         .await?;
 
-        table.update_rows(jobs.into_iter().flatten().collect());
-
-        Ok(table)
+        Ok(network_table(
+            target_namespaces,
+            jobs.into_iter().flatten().collect(),
+        ))
     }
 
     async fn fetch_per_namespace(
@@ -101,25 +146,9 @@ impl NetworkPoller {
                 format!("{}/namespaces/{}/{}", url, ns, plural),
                 &["Name", "Age"],
                 move |row: &TableRow, indexes: &[usize]| {
-                    let mut row = vec![
-                        kind.to_string(),
-                        row.cells[indexes[0]].to_string(),
-                        row.cells[indexes[1]].to_string(),
-                    ];
-
-                    let kind = row[0].clone();
-                    let name = row[1].clone();
-
-                    if insert_ns {
-                        row.insert(0, ns.to_string())
-                    }
-
-                    KubeTableRow {
-                        namespace: ns.to_string(),
-                        name,
-                        row,
-                        metadata: Some(BTreeMap::from([("kind".to_string(), kind)])),
-                    }
+                    let row = indexes.iter().map(|i| row.cells[*i].to_string()).collect();
+
+                    network_row(ns, kind, row, insert_ns)
                 },
             )
         }))
@@ -128,3 +157,75 @@ impl NetworkPoller {
         Ok(jobs.into_iter().flatten().collect())
     }
 }
+
+fn network_table(namespaces: &[String], rows: Vec<KubeTableRow>) -> KubeTable {
+    let mut table = KubeTable {
+        header: if namespaces.len() == 1 {
+            ["KIND", "NAME", "AGE"]
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        } else {
+            ["NAMESPACE", "KIND", "NAME", "AGE"]
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        },
+        ..Default::default()
+    };
+
+    table.update_rows(rows);
+
+    table
+}
+
+fn store_rows<K>(
+    store: &NamespacedStore<K>,
+    insert_ns: bool,
+    now: DateTime<Utc>,
+) -> Vec<KubeTableRow>
+where
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope>
+        + Clone
+        + DeserializeOwned
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'static,
+{
+    store
+        .state()
+        .into_iter()
+        .flat_map(|(ns, items)| {
+            items.into_iter().map(move |item| {
+                network_row(
+                    ns,
+                    &K::kind(&()),
+                    vec![
+                        item.name_any(),
+                        age(item.creation_timestamp().as_ref(), now),
+                    ],
+                    insert_ns,
+                )
+            })
+        })
+        .collect()
+}
+
+/// [NAME, AGE] の先頭に種類を加えた行を作る
+fn network_row(ns: &str, kind: &str, row: Vec<String>, insert_ns: bool) -> KubeTableRow {
+    let name = row[0].clone();
+
+    let mut row: Vec<String> = std::iter::once(kind.to_string()).chain(row).collect();
+
+    if insert_ns {
+        row.insert(0, ns.to_string())
+    }
+
+    KubeTableRow {
+        namespace: ns.to_string(),
+        name,
+        row,
+        metadata: Some(BTreeMap::from([("kind".to_string(), kind.to_string())])),
+    }
+}
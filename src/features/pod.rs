@@ -1,3 +1,4 @@
 pub mod kube;
-pub mod view;
 pub mod message;
+pub mod timeline;
+pub mod view;
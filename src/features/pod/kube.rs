@@ -1,6 +1,10 @@
+mod container;
+mod detail;
 mod filter;
 mod log;
 mod pod;
 
+pub use container::*;
+pub use detail::*;
 pub use log::*;
 pub use pod::*;
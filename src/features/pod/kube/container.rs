@@ -0,0 +1,133 @@
+use k8s_openapi::api::core::v1::{Container, ContainerStatus, Pod};
+
+/// コンテナの種類
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerType {
+    Init,
+    /// restartPolicy: Always が指定されたinitContainer
+    Sidecar,
+    #[default]
+    Regular,
+    Ephemeral,
+}
+
+impl ContainerType {
+    /// ログのプレフィックスなどに付与するラベル
+    /// 通常のコンテナはラベルを付与しない
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            Self::Init => Some("init"),
+            Self::Sidecar => Some("sidecar"),
+            Self::Regular => None,
+            Self::Ephemeral => Some("ephemeral"),
+        }
+    }
+
+    pub fn from_init_container(container: &Container) -> Self {
+        if container.restart_policy.as_deref() == Some("Always") {
+            Self::Sidecar
+        } else {
+            Self::Init
+        }
+    }
+}
+
+/// コンテナステータスを種類とあわせて実行順（init, regular, ephemeral）に並べる
+pub fn container_statuses_in_execution_order(pod: &Pod) -> Vec<(ContainerType, ContainerStatus)> {
+    let Some(status) = &pod.status else {
+        return Vec::default();
+    };
+
+    let init_containers = pod
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.init_containers.as_ref());
+
+    let init_container_statuses = status.init_container_statuses.iter().flatten().map(|s| {
+        let ty = init_containers
+            .and_then(|containers| containers.iter().find(|c| c.name == s.name))
+            .map(ContainerType::from_init_container)
+            .unwrap_or(ContainerType::Init);
+
+        (ty, s.clone())
+    });
+
+    let container_statuses = status
+        .container_statuses
+        .iter()
+        .flatten()
+        .map(|s| (ContainerType::Regular, s.clone()));
+
+    let ephemeral_container_statuses = status
+        .ephemeral_container_statuses
+        .iter()
+        .flatten()
+        .map(|s| (ContainerType::Ephemeral, s.clone()));
+
+    init_container_statuses
+        .chain(container_statuses)
+        .chain(ephemeral_container_statuses)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn initコンテナとサイドカーとエフェメラルコンテナを区別して実行順に並べる() {
+        let pod: Pod = serde_yaml::from_str(indoc! {
+            r#"
+            spec:
+              initContainers:
+              - name: setup
+              - name: proxy
+                restartPolicy: Always
+              containers:
+              - name: app
+            status:
+              containerStatuses:
+              - name: app
+                image: app
+                imageID: ""
+                ready: true
+                restartCount: 0
+              initContainerStatuses:
+              - name: setup
+                image: setup
+                imageID: ""
+                ready: false
+                restartCount: 0
+              - name: proxy
+                image: proxy
+                imageID: ""
+                ready: true
+                restartCount: 0
+              ephemeralContainerStatuses:
+              - name: debugger
+                image: busybox
+                imageID: ""
+                ready: false
+                restartCount: 0
+            "#
+        })
+        .unwrap();
+
+        let actual: Vec<_> = container_statuses_in_execution_order(&pod)
+            .into_iter()
+            .map(|(ty, status)| (ty, status.name))
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                (ContainerType::Init, "setup".to_string()),
+                (ContainerType::Sidecar, "proxy".to_string()),
+                (ContainerType::Regular, "app".to_string()),
+                (ContainerType::Ephemeral, "debugger".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,644 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crossbeam::channel::Sender;
+use k8s_openapi::{
+    api::core::v1::{Container, ContainerStatus, Event, Pod, Probe, Volume},
+    apimachinery::pkg::util::intstr::IntOrString,
+};
+use kube::{Resource, ResourceExt as _};
+use serde_yaml::{Mapping, Value};
+
+use crate::{
+    features::{
+        event::kube::{events_value, fetch_related_events, InvolvedObject},
+        pod::{
+            kube::{container_statuses_in_execution_order, ContainerType},
+            message::{PodDetailMessage, PodDetailRequest},
+        },
+    },
+    kube::KubeClientRequest,
+    message::Message,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::AbortWorker,
+    },
+};
+
+#[derive(Clone)]
+pub struct PodDetailWorker<C>
+where
+    C: KubeClientRequest,
+{
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    client: C,
+    req: PodDetailRequest,
+}
+
+impl<C> PodDetailWorker<C>
+where
+    C: KubeClientRequest,
+{
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        client: C,
+        req: PodDetailRequest,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            client,
+            req,
+        }
+    }
+}
+
+#[async_trait]
+impl<C> AbortWorker for PodDetailWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn run(&self) {
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().description);
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            let fetched_data = self.fetch().await;
+
+            interval.record(&fetched_data);
+
+            self.tx
+                .send(PodDetailMessage::Response(fetched_data).into())
+                .expect("Failed to send PodDetailMessage::Response");
+        }
+    }
+}
+
+impl<C> PodDetailWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn fetch(&self) -> Result<Vec<String>> {
+        let url = format!(
+            "{}/{}",
+            Pod::url_path(&(), Some(&self.req.namespace)),
+            self.req.name
+        );
+
+        let pod: Pod = self.client.request(&url).await?;
+
+        let events = self.fetch_events(&pod).await?;
+
+        detail_lines(&pod, &events, Utc::now())
+    }
+
+    async fn fetch_events(&self, pod: &Pod) -> Result<Vec<Event>> {
+        let Some(uid) = pod.uid() else {
+            return Ok(Vec::new());
+        };
+
+        fetch_related_events(
+            &self.client,
+            Some(&self.req.namespace),
+            InvolvedObject::Uid(&uid),
+        )
+        .await
+    }
+}
+
+fn detail_lines(pod: &Pod, events: &[Event], now: DateTime<Utc>) -> Result<Vec<String>> {
+    let mut root = Mapping::new();
+
+    root.insert("pod".into(), pod_value(pod));
+
+    let spec = pod.spec.clone().unwrap_or_default();
+    let container_statuses = container_statuses_in_execution_order(pod);
+
+    let find_status = |ty: ContainerType, name: &str| {
+        container_statuses
+            .iter()
+            .find(|(t, s)| *t == ty && s.name == name)
+            .map(|(_, s)| s)
+    };
+
+    // 実行順（initContainers, containers, ephemeralContainers）に並べる
+    let init_containers = spec.init_containers.iter().flatten().map(|c| {
+        let ty = ContainerType::from_init_container(c);
+        container_value(c, ty, find_status(ty, &c.name))
+    });
+
+    let regular_containers = spec.containers.iter().map(|c| {
+        container_value(
+            c,
+            ContainerType::Regular,
+            find_status(ContainerType::Regular, &c.name),
+        )
+    });
+
+    let ephemeral_containers = spec.ephemeral_containers.iter().flatten().map(|c| {
+        let container = Container {
+            name: c.name.clone(),
+            image: c.image.clone(),
+            resources: c.resources.clone(),
+            volume_mounts: c.volume_mounts.clone(),
+            ..Default::default()
+        };
+
+        container_value(
+            &container,
+            ContainerType::Ephemeral,
+            find_status(ContainerType::Ephemeral, &c.name),
+        )
+    });
+
+    let containers: Vec<Value> = init_containers
+        .chain(regular_containers)
+        .chain(ephemeral_containers)
+        .collect();
+
+    root.insert("containers".into(), containers.into());
+
+    if let Some(volumes) = spec.volumes.filter(|v| !v.is_empty()) {
+        root.insert(
+            "volumes".into(),
+            volumes.iter().map(volume_value).collect::<Vec<_>>().into(),
+        );
+    }
+
+    if !events.is_empty() {
+        root.insert("events".into(), events_value(events, now));
+    }
+
+    Ok(serde_yaml::to_string(&root)?
+        .lines()
+        .map(ToString::to_string)
+        .collect())
+}
+
+fn pod_value(pod: &Pod) -> Value {
+    let mut value = Mapping::new();
+
+    value.insert("name".into(), pod.name_any().into());
+
+    if let Some(namespace) = pod.namespace() {
+        value.insert("namespace".into(), namespace.into());
+    }
+
+    if let Some(node) = pod.spec.as_ref().and_then(|spec| spec.node_name.clone()) {
+        value.insert("node".into(), node.into());
+    }
+
+    if let Some(status) = &pod.status {
+        if let Some(phase) = &status.phase {
+            value.insert("phase".into(), phase.as_str().into());
+        }
+
+        if let Some(pod_ip) = &status.pod_ip {
+            value.insert("podIP".into(), pod_ip.as_str().into());
+        }
+
+        if let Some(qos_class) = &status.qos_class {
+            value.insert("qosClass".into(), qos_class.as_str().into());
+        }
+    }
+
+    value.into()
+}
+
+fn container_value(
+    container: &Container,
+    container_type: ContainerType,
+    status: Option<&ContainerStatus>,
+) -> Value {
+    let mut value = Mapping::new();
+
+    value.insert("name".into(), container.name.as_str().into());
+
+    if let Some(label) = container_type.label() {
+        value.insert("type".into(), label.into());
+    }
+
+    if let Some(image) = &container.image {
+        value.insert("image".into(), image.as_str().into());
+    }
+
+    if let Some(status) = status {
+        // imageIDにダイジェストが含まれる
+        if !status.image_id.is_empty() {
+            value.insert("imageID".into(), status.image_id.as_str().into());
+        }
+
+        value.insert("ready".into(), status.ready.into());
+        value.insert("restartCount".into(), status.restart_count.into());
+
+        if let Some(state) = status
+            .state
+            .as_ref()
+            .and_then(|state| serde_yaml::to_value(state).ok())
+        {
+            value.insert("state".into(), state);
+        }
+
+        if let Some(last_state) = status
+            .last_state
+            .as_ref()
+            .filter(|state| {
+                state.running.is_some() || state.terminated.is_some() || state.waiting.is_some()
+            })
+            .and_then(|state| serde_yaml::to_value(state).ok())
+        {
+            value.insert("lastState".into(), last_state);
+        }
+    }
+
+    if let Some(resources) = container
+        .resources
+        .as_ref()
+        .filter(|r| r.requests.is_some() || r.limits.is_some())
+    {
+        let mut resources_value = Mapping::new();
+
+        if let Some(requests) = resources.requests.as_ref() {
+            resources_value.insert(
+                "requests".into(),
+                serde_yaml::to_value(requests).unwrap_or_default(),
+            );
+        }
+
+        if let Some(limits) = resources.limits.as_ref() {
+            resources_value.insert(
+                "limits".into(),
+                serde_yaml::to_value(limits).unwrap_or_default(),
+            );
+        }
+
+        value.insert("resources".into(), resources_value.into());
+    }
+
+    let probes: Mapping = [
+        ("liveness", &container.liveness_probe),
+        ("readiness", &container.readiness_probe),
+        ("startup", &container.startup_probe),
+    ]
+    .into_iter()
+    .filter_map(|(name, probe)| {
+        probe
+            .as_ref()
+            .map(|probe| (name.into(), probe_to_string(probe).into()))
+    })
+    .collect();
+
+    if !probes.is_empty() {
+        value.insert("probes".into(), probes.into());
+    }
+
+    if let Some(mounts) = container.volume_mounts.as_ref().filter(|m| !m.is_empty()) {
+        let mounts: Vec<Value> = mounts
+            .iter()
+            .map(|m| {
+                let read_only = if m.read_only.unwrap_or_default() {
+                    " (ro)"
+                } else {
+                    ""
+                };
+
+                format!("{} from {}{}", m.mount_path, m.name, read_only).into()
+            })
+            .collect();
+
+        value.insert("volumeMounts".into(), mounts.into());
+    }
+
+    value.into()
+}
+
+fn int_or_string(value: &IntOrString) -> String {
+    match value {
+        IntOrString::Int(i) => i.to_string(),
+        IntOrString::String(s) => s.to_string(),
+    }
+}
+
+/// kubectl describeに近い形式でプローブを文字列にする
+fn probe_to_string(probe: &Probe) -> String {
+    let action = if let Some(http_get) = &probe.http_get {
+        format!(
+            "http-get {}://{}:{}{}",
+            http_get.scheme.as_deref().unwrap_or("HTTP").to_lowercase(),
+            http_get.host.as_deref().unwrap_or_default(),
+            int_or_string(&http_get.port),
+            http_get.path.as_deref().unwrap_or_default()
+        )
+    } else if let Some(tcp_socket) = &probe.tcp_socket {
+        format!(
+            "tcp-socket {}:{}",
+            tcp_socket.host.as_deref().unwrap_or_default(),
+            int_or_string(&tcp_socket.port)
+        )
+    } else if let Some(exec) = &probe.exec {
+        format!(
+            "exec [{}]",
+            exec.command.clone().unwrap_or_default().join(" ")
+        )
+    } else if let Some(grpc) = &probe.grpc {
+        format!(
+            "grpc <pod>:{} {}",
+            grpc.port,
+            grpc.service.as_deref().unwrap_or("<service>")
+        )
+    } else {
+        "unknown".to_string()
+    };
+
+    format!(
+        "{} delay={}s timeout={}s period={}s success={} failure={}",
+        action,
+        probe.initial_delay_seconds.unwrap_or(0),
+        probe.timeout_seconds.unwrap_or(1),
+        probe.period_seconds.unwrap_or(10),
+        probe.success_threshold.unwrap_or(1),
+        probe.failure_threshold.unwrap_or(3),
+    )
+}
+
+fn volume_value(volume: &Volume) -> Value {
+    let (kind, source) = if let Some(v) = &volume.config_map {
+        ("ConfigMap", v.name.clone())
+    } else if let Some(v) = &volume.secret {
+        ("Secret", v.secret_name.clone())
+    } else if let Some(v) = &volume.persistent_volume_claim {
+        ("PersistentVolumeClaim", Some(v.claim_name.clone()))
+    } else if volume.empty_dir.is_some() {
+        ("EmptyDir", None)
+    } else if let Some(v) = &volume.host_path {
+        ("HostPath", Some(v.path.clone()))
+    } else if volume.projected.is_some() {
+        ("Projected", None)
+    } else if volume.downward_api.is_some() {
+        ("DownwardAPI", None)
+    } else {
+        ("Other", None)
+    };
+
+    let mut value = Mapping::new();
+
+    value.insert("name".into(), volume.name.as_str().into());
+    value.insert("type".into(), kind.into());
+
+    if let Some(source) = source {
+        value.insert("source".into(), source.into());
+    }
+
+    value.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+    use chrono::TimeZone as _;
+    use crossbeam::channel::{bounded, Receiver};
+    use indoc::indoc;
+    use k8s_openapi::List;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    fn pod() -> Pod {
+        serde_yaml::from_str(indoc! {
+            r#"
+            metadata:
+              name: app
+              namespace: default
+              uid: pod-uid
+            spec:
+              nodeName: node-1
+              initContainers:
+              - name: setup
+                image: setup:v1
+              - name: proxy
+                image: proxy:v1
+                restartPolicy: Always
+              containers:
+              - name: app
+                image: app:v1
+                resources:
+                  requests:
+                    cpu: 100m
+                  limits:
+                    memory: 128Mi
+                livenessProbe:
+                  httpGet:
+                    path: /healthz
+                    port: 8080
+                readinessProbe:
+                  tcpSocket:
+                    port: http
+                  periodSeconds: 5
+                volumeMounts:
+                - name: config
+                  mountPath: /etc/app
+                  readOnly: true
+              volumes:
+              - name: config
+                configMap:
+                  name: app-config
+              ephemeralContainers:
+              - name: debugger
+                image: busybox
+                targetContainerName: app
+            status:
+              phase: Running
+              podIP: 10.0.0.1
+              qosClass: Burstable
+              initContainerStatuses:
+              - name: setup
+                image: setup:v1
+                imageID: ""
+                ready: false
+                restartCount: 0
+                state:
+                  terminated:
+                    exitCode: 0
+                    reason: Completed
+              - name: proxy
+                image: proxy:v1
+                imageID: ""
+                ready: true
+                restartCount: 0
+              ephemeralContainerStatuses:
+              - name: debugger
+                image: busybox
+                imageID: ""
+                ready: false
+                restartCount: 0
+              containerStatuses:
+              - name: app
+                image: app:v1
+                imageID: docker.io/library/app@sha256:abcdef
+                ready: true
+                restartCount: 2
+                state:
+                  running:
+                    startedAt: "2024-01-01T11:55:00Z"
+                lastState:
+                  terminated:
+                    exitCode: 137
+                    reason: OOMKilled
+                    startedAt: "2024-01-01T11:00:00Z"
+                    finishedAt: "2024-01-01T11:54:00Z"
+            "#
+        })
+        .unwrap()
+    }
+
+    fn events() -> List<Event> {
+        serde_yaml::from_str(indoc! {
+            r#"
+            items:
+            - metadata:
+                name: app.1
+              involvedObject:
+                kind: Pod
+                name: app
+              type: Normal
+              reason: Pulled
+              message: Container image "app:v1" already present on machine
+              count: 3
+              lastTimestamp: "2024-01-01T11:55:00Z"
+            - metadata:
+                name: app.2
+              involvedObject:
+                kind: Pod
+                name: app
+              type: Warning
+              reason: BackOff
+              message: Back-off restarting failed container
+              lastTimestamp: "2024-01-01T11:58:00Z"
+            "#
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn コンテナを実行順に状態とプローブとイベントを返す() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (Pod, eq("/api/v1/namespaces/default/pods/app"), Ok(pod())),
+                (
+                    List<Event>,
+                    eq("/api/v1/namespaces/default/events?fieldSelector=involvedObject.uid%3Dpod-uid"),
+                    Ok(events())
+                )
+            ]
+        );
+
+        let (tx, _rx): (Sender<Message>, Receiver<Message>) = bounded(3);
+
+        let worker = PodDetailWorker::new(
+            Arc::new(AtomicBool::new(false)),
+            tx,
+            client,
+            PodDetailRequest {
+                name: "app".into(),
+                namespace: "default".into(),
+            },
+        );
+
+        let actual = worker.fetch().await.unwrap();
+
+        let events_index = actual.iter().position(|l| l == "events:").unwrap();
+
+        let expected: Vec<String> = indoc! {
+            r#"
+            pod:
+              name: app
+              namespace: default
+              node: node-1
+              phase: Running
+              podIP: 10.0.0.1
+              qosClass: Burstable
+            containers:
+            - name: setup
+              type: init
+              image: setup:v1
+              ready: false
+              restartCount: 0
+              state:
+                terminated:
+                  exitCode: 0
+                  reason: Completed
+            - name: proxy
+              type: sidecar
+              image: proxy:v1
+              ready: true
+              restartCount: 0
+            - name: app
+              image: app:v1
+              imageID: docker.io/library/app@sha256:abcdef
+              ready: true
+              restartCount: 2
+              state:
+                running:
+                  startedAt: 2024-01-01T11:55:00Z
+              lastState:
+                terminated:
+                  exitCode: 137
+                  finishedAt: 2024-01-01T11:54:00Z
+                  reason: OOMKilled
+                  startedAt: 2024-01-01T11:00:00Z
+              resources:
+                requests:
+                  cpu: 100m
+                limits:
+                  memory: 128Mi
+              probes:
+                liveness: http-get http://:8080/healthz delay=0s timeout=1s period=10s success=1 failure=3
+                readiness: tcp-socket :http delay=0s timeout=1s period=5s success=1 failure=3
+              volumeMounts:
+              - /etc/app from config (ro)
+            - name: debugger
+              type: ephemeral
+              image: busybox
+              ready: false
+              restartCount: 0
+            volumes:
+            - name: config
+              type: ConfigMap
+              source: app-config
+            "#
+        }
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(actual[..events_index].to_vec(), expected);
+        assert_eq!(actual.len(), events_index + 3);
+    }
+
+    #[test]
+    fn イベントは最終発生日時の新しい順に並べる() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let actual = events_value(&events().items, now);
+
+        let expected = Value::from(vec![
+            "2m0s  Warning  BackOff  Back-off restarting failed container",
+            r#"5m0s  Normal  Pulled  Container image "app:v1" already present on machine (x3)"#,
+        ]);
+
+        assert_eq!(actual, expected);
+    }
+}
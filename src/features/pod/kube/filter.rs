@@ -21,6 +21,8 @@ pub struct Filter {
     pub exclude_pod: Option<Vec<Regex>>,
     pub container: Option<Regex>,
     pub exclude_container: Option<Vec<Regex>>,
+    pub exclude_init_container: bool,
+    pub exclude_ephemeral_container: bool,
     pub field_selector: Option<String>,
     pub label_selector: Option<LabelSelector>,
     pub include_log: Option<Vec<Regex>>,
@@ -67,6 +69,14 @@ impl Filter {
                     }
                 }
 
+                FilterAttribute::InitContainer(include) => {
+                    filter.exclude_init_container = !include;
+                }
+
+                FilterAttribute::EphemeralContainer(include) => {
+                    filter.exclude_ephemeral_container = !include;
+                }
+
                 FilterAttribute::Resource(resource) => match resource {
                     SpecifiedResource::Pod(name) => {
                         let regex = Regex::new(&format!("^{}$", name))?;
@@ -185,6 +195,14 @@ impl std::fmt::Display for Filter {
             }
         }
 
+        if self.exclude_init_container {
+            buf.push("init_container=false".to_string());
+        }
+
+        if self.exclude_ephemeral_container {
+            buf.push("ephemeral_container=false".to_string());
+        }
+
         if let Some(label_selector) = &self.label_selector {
             buf.push(label_selector.to_string());
         }
@@ -276,6 +294,10 @@ pub enum FilterAttribute<'a> {
     ExcludePod(Cow<'a, str>),
     Container(Cow<'a, str>),
     ExcludeContainer(Cow<'a, str>),
+    /// initContainerを対象に含めるか（サイドカーは対象外）
+    InitContainer(bool),
+    /// ephemeralContainerを対象に含めるか
+    EphemeralContainer(bool),
     Resource(SpecifiedResource<'a>),
     LabelSelector(Cow<'a, str>),
     FieldSelector(Cow<'a, str>),
@@ -179,6 +179,38 @@ fn exclude_log<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     Ok((remaining, FilterAttribute::ExcludeLog(value)))
 }
 
+fn boolean<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, bool, E> {
+    alt((value(true, tag("true")), value(false, tag("false"))))(s)
+}
+
+fn init_container<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, value)) = separated_pair(
+        alt((tag("initcontainers"), tag("initcontainer"), tag("init"))),
+        char(':'),
+        boolean,
+    )(s)?;
+    Ok((remaining, FilterAttribute::InitContainer(value)))
+}
+
+fn ephemeral_container<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, value)) = separated_pair(
+        alt((
+            tag("ephemeralcontainers"),
+            tag("ephemeralcontainer"),
+            tag("ephemeral"),
+        )),
+        char(':'),
+        boolean,
+    )(s)?;
+    Ok((remaining, FilterAttribute::EphemeralContainer(value)))
+}
+
 fn label_selector<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     s: &'a str,
 ) -> IResult<&'a str, FilterAttribute, E> {
@@ -307,6 +339,8 @@ fn attribute<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
         exclude_pod,
         container,
         exclude_container,
+        init_container,
+        ephemeral_container,
         include_log,
         exclude_log,
     ))(s)?;
@@ -392,6 +426,30 @@ mod tests {
         assert_eq!(remaining, "");
     }
 
+    #[rstest]
+    #[case("initcontainers:true", true)]
+    #[case("initcontainer:true", true)]
+    #[case("init:true", true)]
+    #[case("init:false", false)]
+    fn init_container(#[case] query: &str, #[case] expected: bool) {
+        let (remaining, actual) = super::init_container::<Error<_>>(query).unwrap();
+
+        assert_eq!(actual, FilterAttribute::InitContainer(expected));
+        assert_eq!(remaining, "");
+    }
+
+    #[rstest]
+    #[case("ephemeralcontainers:true", true)]
+    #[case("ephemeralcontainer:true", true)]
+    #[case("ephemeral:true", true)]
+    #[case("ephemeral:false", false)]
+    fn ephemeral_container(#[case] query: &str, #[case] expected: bool) {
+        let (remaining, actual) = super::ephemeral_container::<Error<_>>(query).unwrap();
+
+        assert_eq!(actual, FilterAttribute::EphemeralContainer(expected));
+        assert_eq!(remaining, "");
+    }
+
     /// Log
     #[rstest]
     #[case("logs:hoge", "hoge")]
@@ -647,6 +705,8 @@ mod tests {
     #[case("!pod:hoge", FilterAttribute::ExcludePod("hoge".into()))]
     #[case("container:hoge", FilterAttribute::Container("hoge".into()))]
     #[case("!container:hoge", FilterAttribute::ExcludeContainer("hoge".into()))]
+    #[case("init:false", FilterAttribute::InitContainer(false))]
+    #[case("ephemeral:false", FilterAttribute::EphemeralContainer(false))]
     #[case("log:hoge", FilterAttribute::IncludeLog("hoge".into()))]
     #[case("!log:hoge", FilterAttribute::ExcludeLog("hoge".into()))]
     #[case("labels:foo=bar", FilterAttribute::LabelSelector("foo=bar".into()))]
@@ -673,6 +733,8 @@ mod tests {
             "!pod:hoge",
             "container:hoge",
             "!container:hoge",
+            "init:false",
+            "ephemeral:false",
             "log:hoge",
             "!log:hoge",
             "labels:foo=bar",
@@ -695,6 +757,8 @@ mod tests {
             FilterAttribute::ExcludePod("hoge".into()),
             FilterAttribute::Container("hoge".into()),
             FilterAttribute::ExcludeContainer("hoge".into()),
+            FilterAttribute::InitContainer(false),
+            FilterAttribute::EphemeralContainer(false),
             FilterAttribute::IncludeLog("hoge".into()),
             FilterAttribute::ExcludeLog("hoge".into()),
             FilterAttribute::LabelSelector("foo=bar".into()),
@@ -739,6 +803,7 @@ mod tests {
     #[case("     ")]
     #[case("")]
     #[case("hoge:hoge")]
+    #[case("init:hoge")]
     fn parse_error(#[case] query: &str) {
         let actual = super::parse_attributes::<Error<_>>(query);
 
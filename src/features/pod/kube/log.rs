@@ -103,6 +103,8 @@ impl LogWorker {
                 exclude_pod: filter.exclude_pod.clone(),
                 container: filter.container.clone(),
                 exclude_container: filter.exclude_container.clone(),
+                exclude_init_container: filter.exclude_init_container,
+                exclude_ephemeral_container: filter.exclude_ephemeral_container,
             })
             .selector(PodWatcherSelector {
                 label_selector,
@@ -2,9 +2,16 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
-use tokio::{sync::Mutex, time};
+use tokio::sync::Mutex;
 
-use crate::{message::Message, send_response, workers::kube::Worker};
+use crate::{
+    message::Message,
+    send_response,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::Worker,
+    },
+};
 
 pub type LogBuffer = Arc<Mutex<Vec<String>>>;
 
@@ -25,7 +32,7 @@ impl LogCollector {
 impl Worker for LogCollector {
     type Output = ();
     async fn run(&self) -> Self::Output {
-        let mut interval = tokio::time::interval(time::Duration::from_millis(200));
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().log_collect);
 
         loop {
             interval.tick().await;
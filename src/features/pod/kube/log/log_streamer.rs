@@ -13,18 +13,25 @@ use chrono::{DateTime, Utc};
 use futures::{AsyncBufReadExt, TryStreamExt};
 use k8s_openapi::api::core::v1::Pod;
 use kube::{api::LogParams, Api};
+use ratatui::style::Color;
 use regex::Regex;
-use tokio::time;
+use serde::Deserialize;
 
 use crate::{
+    features::pod::kube::ContainerType,
     kube::KubeClient,
     logger,
-    workers::kube::{color::fg::Color, AbortWorker},
+    ui::theme::{paint, LogPrefixColor, Theme},
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::AbortWorker,
+    },
 };
 
 use super::log_collector::LogBuffer;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LogPrefixType {
     OnlyContainer,
     PodAndContainer,
@@ -37,39 +44,6 @@ impl Default for LogPrefixType {
     }
 }
 
-#[derive(Clone, Copy)]
-struct PrefixColor {
-    pub pod: Color,
-    pub container: Color,
-}
-
-const PREFIX_COLOR_LIST: [PrefixColor; 6] = [
-    PrefixColor {
-        pod: Color::LightGreen,
-        container: Color::Green,
-    },
-    PrefixColor {
-        pod: Color::LightYellow,
-        container: Color::Yellow,
-    },
-    PrefixColor {
-        pod: Color::LightBlue,
-        container: Color::Blue,
-    },
-    PrefixColor {
-        pod: Color::LightMagenta,
-        container: Color::Magenta,
-    },
-    PrefixColor {
-        pod: Color::LightCyan,
-        container: Color::Cyan,
-    },
-    PrefixColor {
-        pod: Color::White,
-        container: Color::Gray,
-    },
-];
-
 #[derive(Default, Clone)]
 pub struct LogStreamerOptions {
     pub prefix_type: LogPrefixType,
@@ -82,6 +56,7 @@ pub struct LogStreamerTarget {
     pub namespace: String,
     pub pod_name: String,
     pub container_name: String,
+    pub container_type: ContainerType,
 }
 
 #[derive(Clone)]
@@ -96,7 +71,7 @@ pub struct LogStreamer {
 #[async_trait]
 impl AbortWorker for LogStreamer {
     async fn run(&self) {
-        let mut interval = tokio::time::interval(time::Duration::from_secs(3));
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().log);
 
         let mut last_timestamp: Option<DateTime<Utc>> = None;
 
@@ -109,6 +84,8 @@ impl AbortWorker for LogStreamer {
 
             let result = self.fetch(&prefix, &mut last_timestamp).await;
 
+            interval.record(&result);
+
             if let Err(err) = result {
                 logger!(error, "{}", err)
             } else if self.is_terminated.load(Ordering::Relaxed) {
@@ -197,7 +174,7 @@ impl LogStreamer {
     }
 
     async fn send_started_message(&self) {
-        let sign = Color::LightGreen.wrap("+");
+        let sign = paint(Theme::global().success, "+");
 
         let mut buf = self.log_buffer.lock().await;
 
@@ -205,7 +182,7 @@ impl LogStreamer {
     }
 
     async fn send_finished_message(&self) {
-        let sign = Color::LightRed.wrap("-");
+        let sign = paint(Theme::global().error, "-");
 
         let mut buf = self.log_buffer.lock().await;
 
@@ -218,37 +195,57 @@ impl LogStreamer {
         let prefix_color = self.log_prefix_color();
 
         match self.options.prefix_type {
-            OnlyContainer => prefix_color.container.wrap(self.container_name()),
+            OnlyContainer => self.container_label(prefix_color.container),
             PodAndContainer => {
-                let container_name = prefix_color.container.wrap(self.container_name());
-                let pod_name = prefix_color.pod.wrap(self.pod_name());
+                let container_name = self.container_label(prefix_color.container);
+                let pod_name = paint(prefix_color.pod, self.pod_name());
 
-                prefix_color
-                    .pod
-                    .wrap(format!("{} {}", pod_name, container_name))
+                paint(prefix_color.pod, format!("{} {}", pod_name, container_name))
             }
             All => {
-                let container_name = prefix_color.container.wrap(self.container_name());
-                let pod_name = prefix_color.pod.wrap(self.pod_name());
-
-                prefix_color.pod.wrap(format!(
-                    "{} {} {}",
-                    self.namespace(),
-                    pod_name,
-                    container_name
-                ))
+                let container_name = self.container_label(prefix_color.container);
+                let pod_name = paint(prefix_color.pod, self.pod_name());
+
+                paint(
+                    prefix_color.pod,
+                    format!("{} {} {}", self.namespace(), pod_name, container_name),
+                )
             }
         }
     }
 
+    /// init, sidecar, ephemeralコンテナは種類ごとの色でラベルを付与する
+    fn container_label(&self, color: Color) -> String {
+        let container_name = paint(color, self.container_name());
+
+        let Some(label) = self.target.container_type.label() else {
+            return container_name;
+        };
+
+        let theme = Theme::global();
+
+        let label_color = match self.target.container_type {
+            ContainerType::Init => theme.muted,
+            ContainerType::Sidecar => theme.info,
+            ContainerType::Ephemeral => theme.error,
+            ContainerType::Regular => color,
+        };
+
+        format!(
+            "{}{}",
+            paint(label_color, format!("{}:", label)),
+            container_name
+        )
+    }
+
     fn log_prefix(&self) -> String {
         use LogPrefixType::*;
 
         let prefix_color = self.log_prefix_color();
         match self.options.prefix_type {
             OnlyContainer => {
-                let open_bracket = prefix_color.container.wrap("[");
-                let close_bracket = prefix_color.container.wrap("]");
+                let open_bracket = paint(prefix_color.container, "[");
+                let close_bracket = paint(prefix_color.container, "]");
 
                 format!(
                     "{}{}{} ",
@@ -258,8 +255,8 @@ impl LogStreamer {
                 )
             }
             PodAndContainer | All => {
-                let open_bracket = prefix_color.pod.wrap("[");
-                let close_bracket = prefix_color.pod.wrap("]");
+                let open_bracket = paint(prefix_color.pod, "[");
+                let close_bracket = paint(prefix_color.pod, "]");
                 format!(
                     "{}{}{} ",
                     open_bracket,
@@ -270,7 +267,7 @@ impl LogStreamer {
         }
     }
 
-    fn log_prefix_color(&self) -> PrefixColor {
+    fn log_prefix_color(&self) -> LogPrefixColor {
         use LogPrefixType::*;
 
         let index = match self.options.prefix_type {
@@ -290,7 +287,7 @@ impl LogStreamer {
             }
         };
 
-        PREFIX_COLOR_LIST[index % PREFIX_COLOR_LIST.len()]
+        Theme::global().log_prefix_color(index)
     }
 
     fn log_params(&self, last_timestamp: &Option<DateTime<Utc>>) -> LogParams {
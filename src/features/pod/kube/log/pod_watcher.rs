@@ -18,6 +18,7 @@ use regex::Regex;
 use tokio::task::AbortHandle;
 
 use crate::{
+    features::pod::kube::{container_statuses_in_execution_order, ContainerType},
     kube::KubeClient,
     logger,
     message::Message,
@@ -36,6 +37,8 @@ pub struct PodWatcherFilter {
     pub exclude_pod: Option<Vec<Regex>>,
     pub container: Option<Regex>,
     pub exclude_container: Option<Vec<Regex>>,
+    pub exclude_init_container: bool,
+    pub exclude_ephemeral_container: bool,
 }
 
 impl PodWatcherFilter {
@@ -56,6 +59,14 @@ impl PodWatcherFilter {
                 .as_ref()
                 .is_some_and(|exclude| exclude.iter().any(|re| re.is_match(container)))
     }
+
+    fn is_exclude_container_type(&self, container_type: ContainerType) -> bool {
+        match container_type {
+            ContainerType::Init => self.exclude_init_container,
+            ContainerType::Ephemeral => self.exclude_ephemeral_container,
+            ContainerType::Sidecar | ContainerType::Regular => false,
+        }
+    }
 }
 
 #[derive(Default, Clone)]
@@ -198,13 +209,15 @@ impl PodWatcher {
 
     fn spawn_tasks(&self, tasks: &mut Tasks, pod: &Pod, pod_name: String, pod_uid: String) {
         // コンテナステータスを集約
-        let container_statuses = Self::aggregate_container_statuses(pod);
+        let container_statuses = container_statuses_in_execution_order(pod);
 
         // コンテナごとにタスク生成
-        for status in container_statuses {
+        for (container_type, status) in container_statuses {
             let container_name = status.name.clone();
 
-            if self.filter.is_exclude_container(&container_name) {
+            if self.filter.is_exclude_container_type(container_type)
+                || self.filter.is_exclude_container(&container_name)
+            {
                 continue;
             }
 
@@ -255,6 +268,7 @@ impl PodWatcher {
                 namespace: self.namespace.clone(),
                 pod_name: pod_name.clone(),
                 container_name: container_name.clone(),
+                container_type,
             };
 
             let is_terminated = Arc::new(AtomicBool::new(Self::is_terminated(&status)));
@@ -300,25 +314,6 @@ impl PodWatcher {
         }
     }
 
-    fn aggregate_container_statuses(pod: &Pod) -> Vec<ContainerStatus> {
-        pod.status.as_ref().map_or(Vec::default(), |status| {
-            let init_container_statuses = status.init_container_statuses.iter().flatten().cloned();
-
-            let ephemeral_container_statuses = status
-                .ephemeral_container_statuses
-                .iter()
-                .flatten()
-                .cloned();
-
-            let container_statuses = status.container_statuses.iter().flatten().cloned();
-
-            init_container_statuses
-                .chain(ephemeral_container_statuses)
-                .chain(container_statuses)
-                .collect()
-        })
-    }
-
     fn is_container_log_available(status: &ContainerStatus) -> Option<String> {
         let last_state = &status.last_state;
 
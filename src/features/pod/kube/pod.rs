@@ -1,16 +1,30 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    time::Duration,
+};
+
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::Utc;
 use futures::future::try_join_all;
+use k8s_openapi::api::core::v1::{ContainerStatus, Pod};
+use kube::ResourceExt as _;
 
 use crate::{
+    features::{pod::kube::ContainerType, workload::kube::age},
     kube::{
         apis::v1_table::TableRow,
+        reflector::{NamespacedStore, ReflectorSignal},
         table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
     },
     message::Message,
-    workers::kube::{
-        message::Kube,
-        WorkerResult, {PollerBase, Worker},
+    ui::theme::{paint, Theme},
+    workers::{
+        interval::IntervalConfig,
+        kube::{
+            message::Kube,
+            ReflectorPoller, WorkerResult, {PollerBase, Worker},
+        },
     },
 };
 
@@ -30,55 +44,73 @@ impl Worker for PodPoller {
     type Output = WorkerResult;
 
     async fn run(&self) -> Self::Output {
-        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
-
-        let Self {
-            base: PollerBase {
-                is_terminated, tx, ..
-            },
-        } = self;
+        self.run_reflector().await
+    }
+}
 
-        while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
-            interval.tick().await;
+#[async_trait]
+impl ReflectorPoller for PodPoller {
+    type Stores = NamespacedStore<Pod>;
+    type Output = KubeTable;
 
-            let pod_info = self.get_pod_info().await;
+    fn base(&self) -> &PollerBase {
+        &self.base
+    }
 
-            tx.send(Message::Kube(Kube::Pod(pod_info)))
-                .expect("Failed to Kube::Pod");
-        }
+    fn interval(&self) -> Duration {
+        IntervalConfig::global().pod
+    }
 
-        WorkerResult::Terminated
+    fn spawn_stores(&self, namespaces: &[String], signal: &ReflectorSignal) -> Self::Stores {
+        NamespacedStore::spawn(&self.base.kube_client, namespaces, signal)
     }
-}
 
-impl PodPoller {
-    async fn get_pod_info(&self) -> Result<KubeTable> {
-        let namespaces = self.base.shared_target_namespaces.read().await;
+    fn render(&self, stores: &Self::Stores, namespaces: &[String]) -> Result<Self::Output> {
+        let now = Utc::now();
+        let insert_ns = insert_ns(namespaces);
 
-        let jobs = self.get_pods_per_namespace(&namespaces).await;
+        let rows = stores
+            .state()
+            .into_iter()
+            .flat_map(|(ns, pods)| {
+                pods.into_iter().map(move |pod| {
+                    let (ready, status) = pod_ready_and_status(&pod);
 
-        let ok_only: Vec<KubeTableRow> = jobs?.into_iter().flatten().collect();
+                    pod_row(
+                        ns,
+                        vec![
+                            pod.name_any(),
+                            ready,
+                            status,
+                            age(pod.creation_timestamp().as_ref(), now),
+                        ],
+                        pod_restarts(&pod),
+                        insert_ns,
+                    )
+                })
+            })
+            .collect();
 
-        let mut table = KubeTable {
-            header: if namespaces.len() == 1 {
-                ["NAME", "READY", "STATUS", "AGE"]
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect()
-            } else {
-                ["NAMESPACE", "NAME", "READY", "STATUS", "AGE"]
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect()
-            },
-            ..Default::default()
-        };
+        Ok(pod_table(namespaces, rows))
+    }
+
+    async fn poll(&self, namespaces: &[String]) -> Result<Self::Output> {
+        let jobs = self.get_pods_per_namespace(namespaces).await;
 
-        table.update_rows(ok_only);
+        let ok_only: Vec<KubeTableRow> = jobs?.into_iter().flatten().collect();
 
-        Ok(table)
+        Ok(pod_table(namespaces, ok_only))
     }
 
+    fn send(&self, output: Result<Self::Output>) {
+        self.base
+            .tx
+            .send(Message::Kube(Kube::Pod(output)))
+            .expect("Failed to Kube::Pod");
+    }
+}
+
+impl PodPoller {
     async fn get_pods_per_namespace(
         &self,
         namespaces: &[String],
@@ -88,42 +120,381 @@ impl PodPoller {
             get_resource_per_namespace(
                 &self.base.kube_client,
                 format!("api/v1/namespaces/{}/{}", ns, "pods"),
-                &["Name", "Ready", "Status", "Age"],
+                &["Name", "Ready", "Status", "Restarts", "Age"],
                 move |row: &TableRow, indexes: &[usize]| {
                     let mut row: Vec<String> =
                         indexes.iter().map(|i| row.cells[*i].to_string()).collect();
 
-                    let name = row[0].clone();
-
-                    let color = match row[2].as_str() {
-                        s if s == "Completed" || s.contains("Evicted") => Some(90),
-                        s if s.contains("BackOff")
-                            || s.contains("Err")
-                            || s.contains("Unknown") =>
-                        {
-                            Some(31)
-                        }
-                        _ => None,
-                    };
-
-                    if insert_ns {
-                        row.insert(0, ns.to_string())
-                    }
-
-                    if let Some(color) = color {
-                        row.iter_mut()
-                            .for_each(|r| *r = format!("\x1b[{}m{}\x1b[0m", color, r))
-                    }
-
-                    KubeTableRow {
-                        namespace: ns.to_string(),
-                        name,
-                        row,
-                        ..Default::default()
-                    }
+                    // "3 (5m ago)" の形式なので先頭の数値だけを使う
+                    let restarts = row.remove(3);
+                    let restarts = restarts
+                        .split_whitespace()
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .unwrap_or_default();
+
+                    pod_row(ns, row, restarts, insert_ns)
                 },
             )
         }))
         .await
     }
 }
+
+fn pod_table(namespaces: &[String], rows: Vec<KubeTableRow>) -> KubeTable {
+    let mut table = KubeTable {
+        header: if namespaces.len() == 1 {
+            ["NAME", "READY", "STATUS", "AGE"]
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        } else {
+            ["NAMESPACE", "NAME", "READY", "STATUS", "AGE"]
+                .iter()
+                .map(ToString::to_string)
+                .collect()
+        },
+        ..Default::default()
+    };
+
+    table.update_rows(rows);
+
+    table
+}
+
+/// [NAME, READY, STATUS, AGE] の行をステータスに応じて色付けする
+///
+/// メタデータのstatusとrestartsは通知の判定に、readyを含めて一覧の履歴の比較に使う
+fn pod_row(ns: &str, mut row: Vec<String>, restarts: u32, insert_ns: bool) -> KubeTableRow {
+    let name = row[0].clone();
+
+    let metadata = BTreeMap::from([
+        ("ready".to_string(), row[1].clone()),
+        ("status".to_string(), row[2].clone()),
+        ("restarts".to_string(), restarts.to_string()),
+    ]);
+
+    let theme = Theme::global();
+
+    let color = match row[2].as_str() {
+        s if s == "Completed" || s.contains("Evicted") => Some(theme.muted),
+        s if s.contains("BackOff") || s.contains("Err") || s.contains("Unknown") => {
+            Some(theme.error)
+        }
+        _ => None,
+    };
+
+    if insert_ns {
+        row.insert(0, ns.to_string())
+    }
+
+    if let Some(color) = color {
+        row.iter_mut().for_each(|r| *r = paint(color, &r))
+    }
+
+    KubeTableRow {
+        namespace: ns.to_string(),
+        name,
+        metadata: Some(metadata),
+        row,
+    }
+}
+
+/// initContainersを含めたすべてのコンテナの再起動回数の合計
+fn pod_restarts(pod: &Pod) -> u32 {
+    let Some(status) = &pod.status else {
+        return 0;
+    };
+
+    status
+        .init_container_statuses
+        .iter()
+        .chain(status.container_statuses.iter())
+        .flatten()
+        .map(|c| c.restart_count.max(0) as u32)
+        .sum()
+}
+
+/// kubectl get podsと同じ規則でREADYとSTATUSを求める
+fn pod_ready_and_status(pod: &Pod) -> (String, String) {
+    let spec = pod.spec.clone().unwrap_or_default();
+    let status = pod.status.clone().unwrap_or_default();
+
+    let sidecars: HashSet<&str> = spec
+        .init_containers
+        .iter()
+        .flatten()
+        .filter(|c| ContainerType::from_init_container(c) == ContainerType::Sidecar)
+        .map(|c| c.name.as_str())
+        .collect();
+
+    let total = spec.containers.len() + sidecars.len();
+    let mut ready = 0;
+
+    let mut reason = status
+        .reason
+        .clone()
+        .or(status.phase.clone())
+        .unwrap_or_default();
+
+    if status
+        .conditions
+        .iter()
+        .flatten()
+        .any(|c| c.type_ == "PodScheduled" && c.reason.as_deref() == Some("SchedulingGated"))
+    {
+        reason = "SchedulingGated".into();
+    }
+
+    let init_container_len = spec.init_containers.as_ref().map_or(0, Vec::len);
+
+    let mut initializing = false;
+
+    for (i, container) in status.init_container_statuses.iter().flatten().enumerate() {
+        let state = container.state.clone().unwrap_or_default();
+
+        if state.terminated.as_ref().is_some_and(|t| t.exit_code == 0) {
+            continue;
+        }
+
+        if sidecars.contains(container.name.as_str()) && container.started == Some(true) {
+            if container.ready {
+                ready += 1;
+            }
+            continue;
+        }
+
+        if let Some(terminated) = &state.terminated {
+            reason = match (&terminated.reason, terminated.signal) {
+                (Some(r), _) if !r.is_empty() => format!("Init:{}", r),
+                (_, Some(signal)) if signal != 0 => format!("Init:Signal:{}", signal),
+                _ => format!("Init:ExitCode:{}", terminated.exit_code),
+            };
+        } else if let Some(r) = state
+            .waiting
+            .as_ref()
+            .and_then(|w| w.reason.as_ref())
+            .filter(|r| !r.is_empty() && *r != "PodInitializing")
+        {
+            reason = format!("Init:{}", r);
+        } else {
+            reason = format!("Init:{}/{}", i, init_container_len);
+        }
+
+        initializing = true;
+        break;
+    }
+
+    let is_initialized = status
+        .conditions
+        .iter()
+        .flatten()
+        .any(|c| c.type_ == "Initialized" && c.status == "True");
+
+    if !initializing || is_initialized {
+        let mut has_running = false;
+
+        for container in status.container_statuses.iter().flatten().rev() {
+            if let Some(r) = container_reason(container) {
+                reason = r;
+            } else if container.ready
+                && container
+                    .state
+                    .as_ref()
+                    .is_some_and(|s| s.running.is_some())
+            {
+                has_running = true;
+                ready += 1;
+            }
+        }
+
+        if reason == "Completed" && has_running {
+            let is_ready = status
+                .conditions
+                .iter()
+                .flatten()
+                .any(|c| c.type_ == "Ready" && c.status == "True");
+
+            reason = if is_ready { "Running" } else { "NotReady" }.into();
+        }
+    }
+
+    if pod.metadata.deletion_timestamp.is_some() {
+        reason = if status.reason.as_deref() == Some("NodeLost") {
+            "Unknown".into()
+        } else {
+            "Terminating".into()
+        };
+    }
+
+    (format!("{}/{}", ready, total), reason)
+}
+
+fn container_reason(container: &ContainerStatus) -> Option<String> {
+    let state = container.state.as_ref()?;
+
+    if let Some(r) = state
+        .waiting
+        .as_ref()
+        .and_then(|w| w.reason.clone())
+        .filter(|r| !r.is_empty())
+    {
+        return Some(r);
+    }
+
+    let terminated = state.terminated.as_ref()?;
+
+    Some(match (&terminated.reason, terminated.signal) {
+        (Some(r), _) if !r.is_empty() => r.to_string(),
+        (_, Some(signal)) if signal != 0 => format!("Signal:{}", signal),
+        _ => format!("ExitCode:{}", terminated.exit_code),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::running(
+        indoc! {r#"
+            spec:
+              containers:
+              - name: app
+              - name: proxy
+            status:
+              phase: Running
+              containerStatuses:
+              - name: app
+                image: app
+                imageID: ""
+                ready: true
+                restartCount: 0
+                state:
+                  running: {}
+              - name: proxy
+                image: proxy
+                imageID: ""
+                ready: false
+                restartCount: 0
+                state:
+                  running: {}
+        "#},
+        ("1/2", "Running")
+    )]
+    #[case::crash_loop(
+        indoc! {r#"
+            spec:
+              containers:
+              - name: app
+            status:
+              phase: Running
+              containerStatuses:
+              - name: app
+                image: app
+                imageID: ""
+                ready: false
+                restartCount: 3
+                state:
+                  waiting:
+                    reason: CrashLoopBackOff
+        "#},
+        ("0/1", "CrashLoopBackOff")
+    )]
+    #[case::init(
+        indoc! {r#"
+            spec:
+              initContainers:
+              - name: setup
+              - name: migrate
+              containers:
+              - name: app
+            status:
+              phase: Pending
+              initContainerStatuses:
+              - name: setup
+                image: setup
+                imageID: ""
+                ready: false
+                restartCount: 0
+                state:
+                  terminated:
+                    exitCode: 0
+              - name: migrate
+                image: migrate
+                imageID: ""
+                ready: false
+                restartCount: 0
+                state:
+                  running: {}
+              containerStatuses:
+              - name: app
+                image: app
+                imageID: ""
+                ready: false
+                restartCount: 0
+                state:
+                  waiting:
+                    reason: PodInitializing
+        "#},
+        ("0/1", "Init:1/2")
+    )]
+    #[case::sidecar(
+        indoc! {r#"
+            spec:
+              initContainers:
+              - name: proxy
+                restartPolicy: Always
+              containers:
+              - name: app
+            status:
+              phase: Running
+              conditions:
+              - type: Initialized
+                status: "True"
+              initContainerStatuses:
+              - name: proxy
+                image: proxy
+                imageID: ""
+                ready: true
+                started: true
+                restartCount: 0
+                state:
+                  running: {}
+              containerStatuses:
+              - name: app
+                image: app
+                imageID: ""
+                ready: true
+                restartCount: 0
+                state:
+                  running: {}
+        "#},
+        ("2/2", "Running")
+    )]
+    #[case::terminating(
+        indoc! {r#"
+            metadata:
+              deletionTimestamp: "2024-01-01T00:00:00Z"
+            spec:
+              containers:
+              - name: app
+            status:
+              phase: Running
+        "#},
+        ("0/1", "Terminating")
+    )]
+    fn kubectlと同じ規則でreadyとstatusを返す(
+        #[case] pod: &str,
+        #[case] expected: (&str, &str),
+    ) {
+        let pod: Pod = serde_yaml::from_str(pod).unwrap();
+
+        let (ready, status) = pod_ready_and_status(&pod);
+
+        assert_eq!((ready.as_str(), status.as_str()), expected);
+    }
+}
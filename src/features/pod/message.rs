@@ -15,3 +15,27 @@ impl From<LogMessage> for Message {
         Message::Kube(Kube::Log(m))
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct PodDetailRequest {
+    pub name: String,
+    pub namespace: String,
+}
+
+#[derive(Debug)]
+pub enum PodDetailMessage {
+    Request(PodDetailRequest),
+    Response(Result<Vec<String>>),
+}
+
+impl From<PodDetailMessage> for Message {
+    fn from(m: PodDetailMessage) -> Message {
+        Message::Kube(Kube::PodDetail(m))
+    }
+}
+
+impl From<PodDetailRequest> for Message {
+    fn from(req: PodDetailRequest) -> Message {
+        PodDetailMessage::Request(req).into()
+    }
+}
@@ -0,0 +1,378 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use chrono::{DateTime, Duration, Local, Utc};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use ratatui::style::Color;
+
+use crate::{
+    features::{component_id::POD_WIDGET_ID, workload::kube::age},
+    kube::table::{KubeTable, KubeTableRow},
+    ui::{
+        theme::{paint, Theme},
+        widget::TableItem,
+        Window,
+    },
+};
+
+/// 保持するスナップショットの上限
+const MAX_SNAPSHOTS: usize = 360;
+
+/// スナップショットを保持する期間
+const RETENTION: Duration = Duration::hours(1);
+
+/// 変化として比較するメタデータ
+const COMPARED_KEYS: [&str; 3] = ["ready", "status", "restarts"];
+
+/// ある時点のPodの一覧
+#[derive(Debug, Clone)]
+pub struct PodSnapshot {
+    pub time: DateTime<Utc>,
+    pub table: KubeTable,
+}
+
+/// Podの一覧の履歴を保持して過去の状態に巻き戻す
+#[derive(Debug, Default)]
+pub struct PodTimeline {
+    /// Podの追加・削除やREADY、STATUS、RESTARTSが変化したときだけ記録する
+    snapshots: VecDeque<PodSnapshot>,
+    /// AGEの更新を含む最新の一覧
+    latest: Option<KubeTable>,
+    /// 表示中のスナップショットの位置（Noneのときは最新の状態を表示する）
+    cursor: Option<usize>,
+}
+
+impl PodTimeline {
+    pub fn record(&mut self, table: &KubeTable, now: DateTime<Utc>) {
+        let changed = match self.snapshots.back() {
+            Some(last) => signature(&last.table) != signature(table),
+            None => true,
+        };
+
+        if changed {
+            self.snapshots.push_back(PodSnapshot {
+                time: now,
+                table: table.clone(),
+            });
+        }
+
+        self.latest = Some(table.clone());
+
+        while MAX_SNAPSHOTS < self.snapshots.len()
+            || (1 < self.snapshots.len() && RETENTION < now - self.snapshots[0].time)
+        {
+            self.snapshots.pop_front();
+
+            if let Some(cursor) = &mut self.cursor {
+                *cursor = cursor.saturating_sub(1);
+            }
+        }
+
+        // 最後のスナップショットは最新の状態と同じなので巻き戻し先にしない
+        if self
+            .cursor
+            .is_some_and(|cursor| self.snapshots.len() <= cursor + 1)
+        {
+            self.cursor = None;
+        }
+    }
+
+    /// namespaceやcontextを切り替えたときに履歴を破棄する
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.cursor.is_none()
+    }
+
+    /// 1つ前のスナップショットに戻る
+    pub fn rewind(&mut self) {
+        self.cursor = match self.cursor {
+            Some(cursor) => Some(cursor.saturating_sub(1)),
+            None => self.snapshots.len().checked_sub(2),
+        };
+    }
+
+    /// 1つ後のスナップショットに進み、最後まで進んだら最新の状態に戻る
+    pub fn forward(&mut self) {
+        self.cursor = self
+            .cursor
+            .map(|cursor| cursor + 1)
+            .filter(|cursor| cursor + 1 < self.snapshots.len());
+    }
+
+    pub fn selected(&self) -> Option<&PodSnapshot> {
+        self.snapshots.get(self.cursor?)
+    }
+
+    /// 表示中のスナップショットの位置と件数
+    pub fn position(&self) -> Option<(usize, usize)> {
+        self.cursor.map(|cursor| (cursor + 1, self.snapshots.len()))
+    }
+
+    /// 表示中のスナップショットのタイトル
+    pub fn title(&self, now: DateTime<Utc>) -> Option<String> {
+        let snapshot = self.selected()?;
+        let (index, len) = self.position()?;
+
+        Some(format!(
+            " (rewind {}/{}: {}, {} ago, [: older, ]: newer)",
+            index,
+            len,
+            snapshot.time.with_timezone(&Local).format("%H:%M:%S"),
+            age(Some(&Time(snapshot.time)), now),
+        ))
+    }
+
+    /// 表示中のスナップショットを最新の状態と比較した行
+    ///
+    /// 消えた行、現れた行、変化した行を色付けしてCHANGE列に変化を表示する
+    fn diff(&self) -> Option<(Vec<String>, Vec<TableItem>)> {
+        let past = &self.selected()?.table;
+        let latest = self.latest.as_ref()?;
+
+        let theme = Theme::global();
+
+        let current: BTreeMap<(&str, &str), &KubeTableRow> = latest
+            .rows
+            .iter()
+            .map(|row| ((row.namespace.as_str(), row.name.as_str()), row))
+            .collect();
+
+        let past_keys: BTreeSet<(&str, &str)> = past
+            .rows
+            .iter()
+            .map(|row| (row.namespace.as_str(), row.name.as_str()))
+            .collect();
+
+        let mut rows: Vec<TableItem> = past
+            .rows
+            .iter()
+            .map(|row| {
+                let key = (row.namespace.as_str(), row.name.as_str());
+
+                match current.get(&key) {
+                    Some(now) => match changes(row, now) {
+                        Some(changes) => diff_row(row, Some(theme.warning), changes),
+                        None => diff_row(row, None, String::new()),
+                    },
+                    None => diff_row(row, Some(theme.error), "deleted".into()),
+                }
+            })
+            .collect();
+
+        rows.extend(
+            latest
+                .rows
+                .iter()
+                .filter(|row| !past_keys.contains(&(row.namespace.as_str(), row.name.as_str())))
+                .map(|row| diff_row(row, Some(theme.success), "created".into())),
+        );
+
+        let mut header = past.header.clone();
+        header.push("CHANGE".into());
+
+        Some((header, rows))
+    }
+}
+
+/// 表示中のスナップショット、または最新の一覧をPodのテーブルに反映する
+pub fn update_pod_widget(w: &mut Window, timeline: &PodTimeline) {
+    let table = w.find_widget_mut(POD_WIDGET_ID).as_mut_table();
+
+    if let Some((header, rows)) = timeline.diff() {
+        table.update_header_and_rows(&header, &rows);
+    } else if let Some(latest) = &timeline.latest {
+        let rows: Vec<TableItem> = latest.rows.iter().cloned().map(TableItem::from).collect();
+
+        table.update_header_and_rows(&latest.header, &rows);
+    }
+}
+
+fn signature(table: &KubeTable) -> Vec<(&str, &str, Vec<Option<&String>>)> {
+    table
+        .rows
+        .iter()
+        .map(|row| {
+            (
+                row.namespace.as_str(),
+                row.name.as_str(),
+                COMPARED_KEYS
+                    .iter()
+                    .map(|key| row.metadata.as_ref().and_then(|m| m.get(*key)))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// "status: Running -> Error" の形式で変化した項目を返す
+fn changes(past: &KubeTableRow, now: &KubeTableRow) -> Option<String> {
+    let value = |row: &KubeTableRow, key: &str| {
+        row.metadata
+            .as_ref()
+            .and_then(|m| m.get(key).cloned())
+            .unwrap_or_default()
+    };
+
+    let changes: Vec<String> = COMPARED_KEYS
+        .iter()
+        .filter_map(|key| {
+            let (before, after) = (value(past, key), value(now, key));
+
+            (before != after).then(|| format!("{}: {} -> {}", key, before, after))
+        })
+        .collect();
+
+    (!changes.is_empty()).then(|| changes.join(", "))
+}
+
+fn diff_row(row: &KubeTableRow, color: Option<Color>, change: String) -> TableItem {
+    let mut item = TableItem::from(row.clone());
+
+    item.item.push(change);
+
+    if let Some(color) = color {
+        item.item
+            .iter_mut()
+            .for_each(|cell| *cell = paint(color, &cell));
+    }
+
+    item
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone as _;
+    use pretty_assertions::assert_eq;
+
+    fn time(min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, min, 0).unwrap()
+    }
+
+    fn table(pods: &[(&str, &str, &str)]) -> KubeTable {
+        KubeTable {
+            header: vec!["NAME".into(), "STATUS".into()],
+            rows: pods
+                .iter()
+                .map(|(name, status, restarts)| KubeTableRow {
+                    namespace: "default".into(),
+                    name: name.to_string(),
+                    metadata: Some(BTreeMap::from([
+                        ("status".into(), status.to_string()),
+                        ("restarts".into(), restarts.to_string()),
+                    ])),
+                    row: vec![name.to_string(), status.to_string()],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn 変化がないときはスナップショットを追加しない() {
+        let mut timeline = PodTimeline::default();
+
+        timeline.record(&table(&[("app", "Running", "0")]), time(0));
+        timeline.record(&table(&[("app", "Running", "0")]), time(1));
+        timeline.record(&table(&[("app", "Error", "0")]), time(2));
+
+        assert_eq!(timeline.snapshots.len(), 2);
+    }
+
+    #[test]
+    fn 巻き戻しと早送りで最新の状態に戻る() {
+        let mut timeline = PodTimeline::default();
+
+        timeline.record(&table(&[("app", "Running", "0")]), time(0));
+
+        timeline.rewind();
+
+        assert!(timeline.is_live(), "比較するスナップショットがない");
+
+        timeline.record(&table(&[("app", "Error", "0")]), time(1));
+        timeline.record(&table(&[("app", "Running", "1")]), time(2));
+
+        timeline.rewind();
+        timeline.rewind();
+        timeline.rewind();
+
+        assert_eq!(timeline.position(), Some((1, 3)));
+
+        timeline.forward();
+
+        assert_eq!(timeline.position(), Some((2, 3)));
+
+        timeline.forward();
+
+        assert!(timeline.is_live());
+    }
+
+    #[test]
+    fn 保持期間を過ぎたスナップショットを削除する() {
+        let mut timeline = PodTimeline::default();
+
+        timeline.record(&table(&[("app", "Running", "0")]), time(0));
+        timeline.record(&table(&[("app", "Error", "0")]), time(30));
+
+        timeline.rewind();
+
+        assert_eq!(timeline.position(), Some((1, 2)));
+
+        timeline.record(
+            &table(&[("app", "Running", "1")]),
+            Utc.with_ymd_and_hms(2024, 1, 1, 13, 10, 0).unwrap(),
+        );
+
+        assert_eq!(timeline.snapshots.len(), 2);
+        assert_eq!(timeline.position(), Some((1, 2)));
+        assert_eq!(timeline.selected().map(|s| s.time), Some(time(30)));
+    }
+
+    #[test]
+    fn 最新の状態と比較して変化を表示する() {
+        let mut timeline = PodTimeline::default();
+
+        timeline.record(
+            &table(&[("app", "Running", "0"), ("db", "Running", "0")]),
+            time(0),
+        );
+        timeline.record(
+            &table(&[("app", "CrashLoopBackOff", "3"), ("web", "Running", "0")]),
+            time(5),
+        );
+
+        timeline.rewind();
+
+        let (header, rows) = timeline.diff().unwrap();
+
+        assert_eq!(header, vec!["NAME", "STATUS", "CHANGE"]);
+
+        let actual: Vec<(String, String)> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.metadata.as_ref().unwrap()["name"].clone(),
+                    row.item[2].clone(),
+                )
+            })
+            .collect();
+
+        let theme = Theme::global();
+
+        assert_eq!(
+            actual,
+            vec![
+                (
+                    "app".into(),
+                    paint(
+                        theme.warning,
+                        "status: Running -> CrashLoopBackOff, restarts: 0 -> 3"
+                    )
+                ),
+                ("db".into(), paint(theme.error, "deleted")),
+                ("web".into(), paint(theme.success, "created")),
+            ]
+        );
+    }
+}
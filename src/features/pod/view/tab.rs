@@ -5,7 +5,10 @@ use ratatui::layout::{Constraint, Direction};
 
 use crate::{
     clipboard::Clipboard,
-    features::component_id::{POD_TAB_ID, POD_WIDGET_ID},
+    features::{
+        component_id::{POD_TAB_ID, POD_WIDGET_ID},
+        pod::{kube::LogPrefixType, timeline::PodTimeline},
+    },
     kube::context::Namespace,
     message::Message,
     ui::{
@@ -15,11 +18,14 @@ use crate::{
     },
 };
 
-use super::widgets::{log_query_help_widget, log_query_widget, log_widget, pod_widget};
+use super::widgets::{
+    log_query_help_widget, log_query_widget, log_widget, pod_detail_widget, pod_widget,
+};
 
 pub struct PodTab {
     pub tab: Tab<'static>,
     pub log_query_help_popup: Widget<'static>,
+    pub pod_detail_popup: Widget<'static>,
 }
 
 impl PodTab {
@@ -29,11 +35,14 @@ impl PodTab {
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
         split_direction: Direction,
         namespaces: Rc<RefCell<Namespace>>,
+        log_prefix_type: Option<LogPrefixType>,
+        timeline: Rc<RefCell<PodTimeline>>,
     ) -> Self {
-        let pod_widget = pod_widget(tx);
-        let log_query_widget = log_query_widget(tx, namespaces);
+        let pod_widget = pod_widget(tx, log_prefix_type, timeline);
+        let log_query_widget = log_query_widget(tx, namespaces, log_prefix_type);
         let log_widget = log_widget(clipboard);
         let log_query_help_widget = log_query_help_widget();
+        let pod_detail_widget = pod_detail_widget(clipboard);
 
         let layout = layout(split_direction);
 
@@ -49,6 +58,7 @@ impl PodTab {
         Self {
             tab,
             log_query_help_popup: log_query_help_widget,
+            pod_detail_popup: pod_detail_widget,
         }
     }
 }
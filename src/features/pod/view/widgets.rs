@@ -2,8 +2,10 @@ mod log;
 mod log_query;
 mod log_query_help;
 mod pod;
+mod pod_detail;
 
 pub(super) use log::*;
 pub(super) use log_query::*;
 pub(super) use log_query_help::*;
 pub(super) use pod::*;
+pub(super) use pod_detail::*;
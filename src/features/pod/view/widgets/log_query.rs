@@ -25,13 +25,17 @@ use crate::{
 pub fn log_query_widget(
     tx: &Sender<Message>,
     namespaces: Rc<RefCell<Namespace>>,
+    prefix_type: Option<LogPrefixType>,
 ) -> Widget<'static> {
     let tx = tx.clone();
 
     InputFormBuilder::default()
         .id(POD_LOG_QUERY_WIDGET_ID)
         .widget_config(WidgetConfig::builder().title("Log Query").build())
-        .actions(UserEvent::from(KeyCode::Enter), exec_query(tx, namespaces))
+        .actions(
+            UserEvent::from(KeyCode::Enter),
+            exec_query(tx, namespaces, prefix_type),
+        )
         .build()
         .into()
 }
@@ -39,6 +43,7 @@ pub fn log_query_widget(
 fn exec_query(
     tx: Sender<Message>,
     namespaces: Rc<RefCell<Namespace>>,
+    prefix_type: Option<LogPrefixType>,
 ) -> impl Fn(&mut Window) -> EventResult {
     move |w: &mut Window| {
         let widget = w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID);
@@ -57,11 +62,11 @@ fn exec_query(
 
         let namespaces = namespaces.borrow();
 
-        let prefix_type = if 1 < namespaces.len() {
+        let prefix_type = prefix_type.unwrap_or(if 1 < namespaces.len() {
             LogPrefixType::All
         } else {
             LogPrefixType::PodAndContainer
-        };
+        });
 
         let config = LogConfig::new(item, namespaces.to_owned(), prefix_type);
 
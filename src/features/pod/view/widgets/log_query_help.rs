@@ -30,6 +30,8 @@ fn content() -> Vec<String> {
            !pod:<regex>          (alias: !pods, !po, p)
            container:<regex>     (alias: containers, co, c)
            !container:<regex>    (alias: !containers, !co, !c)
+           init:<bool>           (alias: initcontainers)
+           ephemeral:<bool>      (alias: ephemeralcontainers)
            log:<regex>           (alias: logs, lo, l)
            !log:<regex>          (alias: !logs, !lo, !l)
            label:<selector>      (alias: labels)
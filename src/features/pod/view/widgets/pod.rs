@@ -1,37 +1,52 @@
+use std::{cell::RefCell, rc::Rc};
+
+use chrono::Utc;
 use crossbeam::channel::Sender;
 
 use crate::{
     features::{
-        component_id::{POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID},
+        component_id::{
+            POD_DETAIL_POPUP_ID, POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID,
+        },
         pod::{
             kube::{LogConfig, LogPrefixType},
-            message::LogMessage,
+            message::{LogMessage, PodDetailRequest},
+            timeline::{update_pod_widget, PodTimeline},
         },
     },
     kube::context::Namespace,
     message::Message,
     ui::{
         event::EventResult,
-        widget::{config::WidgetConfig, Item, Table, TableItem, Widget, WidgetTrait as _},
+        widget::{
+            config::WidgetConfig, Item, SelectedItem, Table, TableItem, Widget, WidgetTrait as _,
+        },
         Window, WindowAction,
     },
 };
 
-pub fn pod_widget(tx: &Sender<Message>) -> Widget<'static> {
+pub fn pod_widget(
+    tx: &Sender<Message>,
+    prefix_type: Option<LogPrefixType>,
+    timeline: Rc<RefCell<PodTimeline>>,
+) -> Widget<'static> {
     let tx = tx.clone();
 
     Table::builder()
         .id(POD_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Pod").build())
         .filtered_key("NAME")
-        .block_injection(block_injection())
-        .on_select(on_select(tx))
+        .block_injection(block_injection(timeline.clone()))
+        .on_select(on_select(tx.clone(), prefix_type))
+        .action('d', open_pod_detail(tx))
+        .action('[', rewind(timeline.clone()))
+        .action(']', forward(timeline))
         .build()
         .into()
 }
 
-fn block_injection() -> impl Fn(&Table) -> WidgetConfig {
-    |table: &Table| {
+fn block_injection(timeline: Rc<RefCell<PodTimeline>>) -> impl Fn(&Table) -> WidgetConfig {
+    move |table: &Table| {
         let index = if let Some(index) = table.state().selected() {
             index + 1
         } else {
@@ -40,14 +55,49 @@ fn block_injection() -> impl Fn(&Table) -> WidgetConfig {
 
         let mut widget_config = table.widget_config().clone();
 
+        let rewind = timeline.borrow().title(Utc::now()).unwrap_or_default();
+
         *widget_config.append_title_mut() =
-            Some(format!(" [{}/{}]", index, table.items().len()).into());
+            Some(format!(" [{}/{}]{}", index, table.items().len(), rewind).into());
 
         widget_config
     }
 }
 
-fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+/// 1つ前のスナップショットを最新の状態と比較して表示する
+fn rewind(timeline: Rc<RefCell<PodTimeline>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut timeline = timeline.borrow_mut();
+
+        timeline.rewind();
+
+        update_pod_widget(w, &timeline);
+
+        EventResult::Nop
+    }
+}
+
+/// 1つ後のスナップショットに進み、最後まで進んだら最新の状態に戻る
+fn forward(timeline: Rc<RefCell<PodTimeline>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut timeline = timeline.borrow_mut();
+
+        if timeline.is_live() {
+            return EventResult::Ignore;
+        }
+
+        timeline.forward();
+
+        update_pod_widget(w, &timeline);
+
+        EventResult::Nop
+    }
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    prefix_type: Option<LogPrefixType>,
+) -> impl Fn(&mut Window, &TableItem) -> EventResult {
     move |w: &mut Window, v: &TableItem| {
         w.widget_clear(POD_LOG_WIDGET_ID);
 
@@ -72,7 +122,7 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventRe
         let config = LogConfig::new(
             format!("pod/{}", name),
             namespaces.to_owned(),
-            LogPrefixType::OnlyContainer,
+            prefix_type.unwrap_or(LogPrefixType::OnlyContainer),
         );
 
         tx.send(LogMessage::Request(config).into())
@@ -81,3 +131,37 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventRe
         EventResult::WindowAction(WindowAction::Continue)
     }
 }
+
+fn open_pod_detail(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = w.find_widget(POD_WIDGET_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let Some(namespace) = metadata.get("namespace") else {
+            return EventResult::Ignore;
+        };
+
+        let Some(name) = metadata.get("name") else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(
+            PodDetailRequest {
+                name: name.to_string(),
+                namespace: namespace.to_string(),
+            }
+            .into(),
+        )
+        .expect("Failed to send PodDetailMessage::Request");
+
+        w.widget_clear(POD_DETAIL_POPUP_ID);
+        w.open_popup(POD_DETAIL_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
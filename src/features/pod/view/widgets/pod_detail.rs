@@ -0,0 +1,29 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::POD_DETAIL_POPUP_ID,
+    ui::widget::{config::WidgetConfig, Text, Widget, WidgetTrait as _},
+};
+
+pub fn pod_detail_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    let mut builder = Text::builder()
+        .id(POD_DETAIL_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Pod Detail").build())
+        .block_injection(|text: &Text, is_active: bool, is_mouse_over: bool| {
+            let (index, size) = text.state();
+
+            let mut config = text.widget_config().clone();
+
+            *config.title_mut() = format!("Pod Detail [{}/{}]", index, size).into();
+
+            config.render_block(text.can_activate() && is_active, is_mouse_over)
+        })
+        .wrap();
+
+    if let Some(clipboard) = clipboard {
+        builder = builder.clipboard(clipboard.clone());
+    }
+
+    builder.build().into()
+}
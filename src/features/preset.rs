@@ -0,0 +1,4 @@
+mod store;
+pub mod view;
+
+pub use store::*;
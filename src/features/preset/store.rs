@@ -0,0 +1,188 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::config_dir;
+
+const PRESETS_FILE_NAME: &str = "presets.yaml";
+
+/// Listタブに表示するAPIリソースの組み合わせ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Preset {
+    pub name: String,
+    /// `pods`のようなリソース名、または`deployments.apps`のようなグループ付きのリソース名
+    pub api_resources: Vec<String>,
+    /// 指定したときはnamespaceも切り替える
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Vec<String>>,
+}
+
+/// 名前を付けて保存したプリセット
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Presets {
+    path: Option<PathBuf>,
+    presets: Vec<Preset>,
+}
+
+/// $XDG_CONFIG_HOME/kubetui/presets.yaml
+pub fn default_path() -> Option<PathBuf> {
+    Some(config_dir()?.join(PRESETS_FILE_NAME))
+}
+
+impl Presets {
+    /// ファイルがなければ空のプリセットにする（保存したときに作る）
+    pub fn load(path: Option<PathBuf>) -> Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::default());
+        };
+
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read presets file {}", path.display()))
+            }
+        };
+
+        let presets = Self::from_yaml(&content)
+            .with_context(|| format!("invalid presets file {}", path.display()))?;
+
+        Ok(Self {
+            path: Some(path),
+            presets,
+        })
+    }
+
+    fn from_yaml(content: &str) -> Result<Vec<Preset>> {
+        if content.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Preset> {
+        self.presets.iter()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Preset> {
+        self.presets.iter().find(|preset| preset.name == name)
+    }
+
+    /// 同じ名前のプリセットは上書きする（並び順は変えない）
+    pub fn upsert(&mut self, preset: Preset) {
+        match self.presets.iter_mut().find(|p| p.name == preset.name) {
+            Some(p) => *p = preset,
+            None => self.presets.push(preset),
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.presets.retain(|preset| preset.name != name);
+    }
+
+    pub fn save(&self) -> Result<&Path> {
+        let path = self
+            .path
+            .as_deref()
+            .ok_or_else(|| anyhow!("Can't find the config directory for {}", PRESETS_FILE_NAME))?;
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+
+        std::fs::write(path, serde_yaml::to_string(&self.presets)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn preset(name: &str, api_resources: &[&str], namespaces: Option<&[&str]>) -> Preset {
+        Preset {
+            name: name.into(),
+            api_resources: api_resources.iter().map(ToString::to_string).collect(),
+            namespaces: namespaces.map(|ns| ns.iter().map(ToString::to_string).collect()),
+        }
+    }
+
+    #[test]
+    fn プリセットを読み込む() {
+        let content = indoc! { r#"
+            - name: networking
+              api_resources:
+                - services
+                - ingresses.networking.k8s.io
+              namespaces:
+                - default
+            - name: storage
+              api_resources:
+                - persistentvolumeclaims
+        "# };
+
+        assert_eq!(
+            Presets::from_yaml(content).unwrap(),
+            vec![
+                preset(
+                    "networking",
+                    &["services", "ingresses.networking.k8s.io"],
+                    Some(&["default"])
+                ),
+                preset("storage", &["persistentvolumeclaims"], None),
+            ]
+        );
+    }
+
+    #[test]
+    fn 同じ名前のプリセットは並び順を変えずに上書きする() {
+        let mut presets = Presets::default();
+
+        presets.upsert(preset("networking", &["services"], None));
+        presets.upsert(preset("storage", &["persistentvolumeclaims"], None));
+        presets.upsert(preset("networking", &["ingresses.networking.k8s.io"], None));
+
+        assert_eq!(
+            presets.iter().cloned().collect::<Vec<_>>(),
+            vec![
+                preset("networking", &["ingresses.networking.k8s.io"], None),
+                preset("storage", &["persistentvolumeclaims"], None),
+            ]
+        );
+
+        presets.remove("networking");
+
+        assert_eq!(presets.get("networking"), None);
+    }
+
+    #[test]
+    fn 保存したプリセットを読み込める() {
+        let path = std::env::temp_dir()
+            .join(format!("kubetui-presets-{}", std::process::id()))
+            .join(PRESETS_FILE_NAME);
+
+        let mut presets = Presets::load(Some(path.clone())).unwrap();
+
+        presets.upsert(preset("networking", &["services"], Some(&["default"])));
+
+        presets.save().unwrap();
+
+        let actual = Presets::load(Some(path.clone())).unwrap();
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        assert_eq!(actual, presets);
+    }
+}
@@ -0,0 +1,289 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use anyhow::{anyhow, Result};
+use crossbeam::channel::Sender;
+use crossterm::event::KeyCode;
+
+use crate::{
+    features::{
+        api_resources::{kube::ApiResource, message::ApiRequest},
+        component_id::{
+            LIST_POPUP_ID, LIST_TAB_ID, MULTIPLE_NAMESPACES_POPUP_ID, PRESET_POPUP_ID,
+            PRESET_SAVE_POPUP_ID,
+        },
+        namespace::{message::NamespaceRequest, view::clear_namespaced_widgets},
+    },
+    kube::context::Namespace,
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{
+            config::WidgetConfig, input::InputFormBuilder, Item, LiteralItem, SelectedItem, Table,
+            TableItem, Widget, WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+use super::{Preset, Presets};
+
+const PRESET_TITLE: &str = " (Enter: apply, s: save, d: delete)";
+
+pub struct PresetPopup {
+    pub popup: Widget<'static>,
+    pub save_popup: Widget<'static>,
+}
+
+impl PresetPopup {
+    pub fn new(
+        tx: &Sender<Message>,
+        presets: &Rc<RefCell<Presets>>,
+        namespaces: &Rc<RefCell<Namespace>>,
+    ) -> Self {
+        let with_namespaces = Rc::new(RefCell::new(false));
+
+        Self {
+            popup: popup(tx.clone(), presets.clone(), with_namespaces.clone()),
+            save_popup: save_popup(presets.clone(), namespaces.clone(), with_namespaces),
+        }
+    }
+}
+
+fn popup(
+    tx: Sender<Message>,
+    presets: Rc<RefCell<Presets>>,
+    with_namespaces: Rc<RefCell<bool>>,
+) -> Widget<'static> {
+    let delete_presets = presets.clone();
+
+    let items = rows(&presets.borrow());
+
+    Table::builder()
+        .id(PRESET_POPUP_ID)
+        .widget_config(
+            &WidgetConfig::builder()
+                .title("Preset")
+                .append_title(PRESET_TITLE)
+                .build(),
+        )
+        .header(header())
+        .items(items)
+        .on_select(move |w: &mut Window, v: &TableItem| {
+            let Some(name) = v.metadata.as_ref().and_then(|m| m.get("name")) else {
+                return EventResult::Ignore;
+            };
+
+            let Some(preset) = presets.borrow().get(name).cloned() else {
+                return EventResult::Ignore;
+            };
+
+            apply(w, &tx, preset);
+
+            EventResult::Nop
+        })
+        .action('s', move |w: &mut Window| {
+            *with_namespaces.borrow_mut() = false;
+
+            let name = selected_name(w).unwrap_or_default();
+
+            let widget = w.find_widget_mut(PRESET_SAVE_POPUP_ID);
+
+            widget.update_widget_item(Item::Single(LiteralItem::from(name)));
+
+            *widget.widget_config_mut().append_title_mut() = Some(save_title(false).into());
+
+            w.open_popup(PRESET_SAVE_POPUP_ID);
+
+            EventResult::Nop
+        })
+        .action('d', move |w: &mut Window| {
+            let Some(name) = selected_name(w) else {
+                return EventResult::Ignore;
+            };
+
+            let mut presets = delete_presets.borrow_mut();
+
+            presets.remove(&name);
+
+            let result = presets
+                .save()
+                .map(|_| format!(" : deleted {}", name))
+                .unwrap_or_else(|err| format!(" : {:#}", err));
+
+            update_preset_popup(w, &presets, result);
+
+            EventResult::Nop
+        })
+        .build()
+        .into()
+}
+
+fn header() -> Vec<String> {
+    ["NAME", "API RESOURCES", "NAMESPACES"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+fn rows(presets: &Presets) -> Vec<TableItem> {
+    presets
+        .iter()
+        .map(|preset| {
+            TableItem::new(
+                vec![
+                    preset.name.to_string(),
+                    preset.api_resources.join(", "),
+                    preset
+                        .namespaces
+                        .as_ref()
+                        .map(|ns| ns.join(", "))
+                        .unwrap_or_else(|| "-".into()),
+                ],
+                Some(BTreeMap::from([("name".into(), preset.name.to_string())])),
+            )
+        })
+        .collect()
+}
+
+fn selected_name(w: &mut Window) -> Option<String> {
+    let Some(SelectedItem::TableRow {
+        metadata: Some(metadata),
+        ..
+    }) = w.find_widget(PRESET_POPUP_ID).widget_item()
+    else {
+        return None;
+    };
+
+    metadata.get("name").cloned()
+}
+
+fn update_preset_popup(w: &mut Window, presets: &Presets, title: String) {
+    let widget = w.find_widget_mut(PRESET_POPUP_ID);
+
+    widget
+        .as_mut_table()
+        .update_header_and_rows(&header(), &rows(presets));
+
+    *widget.widget_config_mut().append_title_mut() =
+        Some(format!("{}{}", PRESET_TITLE, title).into());
+}
+
+/// プリセットのAPIリソースを表示する（namespaceを指定したプリセットはnamespaceも切り替える）
+fn apply(w: &mut Window, tx: &Sender<Message>, preset: Preset) {
+    if let Some(namespaces) = preset.namespaces {
+        tx.send(NamespaceRequest::Set(namespaces.clone()).into())
+            .expect("Failed to send NamespaceRequest::Set");
+
+        clear_namespaced_widgets(w);
+
+        let widget = w
+            .find_widget_mut(MULTIPLE_NAMESPACES_POPUP_ID)
+            .as_mut_multiple_select();
+
+        widget.update_widget_item(Item::Array(
+            namespaces.into_iter().map(LiteralItem::from).collect(),
+        ));
+
+        widget.select_all();
+    }
+
+    tx.send(ApiRequest::SetByKeys(preset.api_resources).into())
+        .expect("Failed to send ApiRequest::SetByKeys");
+
+    w.close_popup();
+
+    w.activate_tab_by_id(LIST_TAB_ID);
+}
+
+fn save_title(with_namespaces: bool) -> String {
+    let namespaces = if with_namespaces {
+        "with namespaces"
+    } else {
+        "api resources only"
+    };
+
+    format!(" [{}] (Tab: toggle namespaces, Enter: save)", namespaces)
+}
+
+fn save_popup(
+    presets: Rc<RefCell<Presets>>,
+    namespaces: Rc<RefCell<Namespace>>,
+    with_namespaces: Rc<RefCell<bool>>,
+) -> Widget<'static> {
+    let toggle = with_namespaces.clone();
+
+    InputFormBuilder::default()
+        .id(PRESET_SAVE_POPUP_ID)
+        .widget_config(WidgetConfig::builder().title("Save Preset").build())
+        .actions(UserEvent::from(KeyCode::Tab), move |w: &mut Window| {
+            let mut with_namespaces = toggle.borrow_mut();
+
+            *with_namespaces = !*with_namespaces;
+
+            *w.find_widget_mut(PRESET_SAVE_POPUP_ID)
+                .widget_config_mut()
+                .append_title_mut() = Some(save_title(*with_namespaces).into());
+
+            EventResult::Nop
+        })
+        .actions(UserEvent::from(KeyCode::Enter), move |w: &mut Window| {
+            let namespaces = with_namespaces
+                .borrow()
+                .then(|| namespaces.borrow().to_vec());
+
+            let mut presets = presets.borrow_mut();
+
+            match save(w, &mut presets, namespaces) {
+                Ok(title) => {
+                    update_preset_popup(w, &presets, title);
+
+                    w.open_popup(PRESET_POPUP_ID);
+                }
+                Err(err) => {
+                    *w.find_widget_mut(PRESET_SAVE_POPUP_ID)
+                        .widget_config_mut()
+                        .append_title_mut() = Some(format!(" : {:#}", err).into());
+                }
+            }
+
+            EventResult::Nop
+        })
+        .build()
+        .into()
+}
+
+/// Listタブで選択しているAPIリソースを入力した名前で保存する
+fn save(w: &mut Window, presets: &mut Presets, namespaces: Option<Vec<String>>) -> Result<String> {
+    let name = match w.find_widget(PRESET_SAVE_POPUP_ID).widget_item() {
+        Some(SelectedItem::Literal { item, .. }) => item.trim().to_string(),
+        _ => String::new(),
+    };
+
+    if name.is_empty() {
+        return Err(anyhow!("Preset name is empty"));
+    }
+
+    let api_resources: Vec<String> = w
+        .find_widget_mut(LIST_POPUP_ID)
+        .as_mut_multiple_select()
+        .selected_items()
+        .iter()
+        .filter_map(|item| item.metadata.as_ref()?.get("key"))
+        .filter_map(|key| serde_json::from_str::<ApiResource>(key).ok())
+        .map(|api| api.key())
+        .collect();
+
+    if api_resources.is_empty() {
+        return Err(anyhow!("No API resources are selected in the List tab"));
+    }
+
+    presets.upsert(Preset {
+        name: name.to_string(),
+        api_resources,
+        namespaces,
+    });
+
+    let path = presets.save()?;
+
+    Ok(format!(" : saved {} to {}", name, path.display()))
+}
@@ -0,0 +1,4 @@
+mod history;
+pub mod view;
+
+pub use history::*;
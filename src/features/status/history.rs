@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Duration, Utc};
+
+/// 保持するエラーの上限
+const MAX_ERRORS: usize = 100;
+
+/// ステータスバーに最新のエラーを表示し続ける時間
+const DISPLAY_DURATION: Duration = Duration::seconds(10);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorEntry {
+    /// エラーが発生したウィジェットのタイトルなど
+    pub source: String,
+    pub message: String,
+    /// 同じエラーが続けて発生した回数
+    pub count: u32,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+}
+
+impl ErrorEntry {
+    /// ステータスバーに表示する1行目
+    pub fn summary(&self) -> &str {
+        self.message.lines().next().unwrap_or_default()
+    }
+}
+
+/// 最近発生したエラーの履歴
+///
+/// ポーリングで同じエラーが繰り返し発生しても1件にまとめる
+#[derive(Debug, Default)]
+pub struct ErrorHistory {
+    entries: VecDeque<ErrorEntry>,
+}
+
+impl ErrorHistory {
+    pub fn push(&mut self, source: impl Into<String>, err: &anyhow::Error, now: DateTime<Utc>) {
+        let source = source.into();
+        // Debugはバックトレースを含むことがあり同じエラーをまとめられないため使わない
+        let message = format!("{:#}", err);
+
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.source == source && entry.message == message)
+        {
+            entry.count = entry.count.saturating_add(1);
+            entry.last_seen = now;
+        } else {
+            self.entries.push_back(ErrorEntry {
+                source,
+                message,
+                count: 1,
+                first_seen: now,
+                last_seen: now,
+            });
+
+            if MAX_ERRORS < self.entries.len() {
+                self.entries.pop_front();
+            }
+        }
+    }
+
+    /// 最近発生したものから順に返す
+    pub fn entries(&self) -> impl Iterator<Item = &ErrorEntry> {
+        let mut entries: Vec<&ErrorEntry> = self.entries.iter().collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.last_seen));
+        entries.into_iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// ステータスバーに表示する直近のエラー
+    pub fn latest(&self, now: DateTime<Utc>) -> Option<&ErrorEntry> {
+        self.entries
+            .iter()
+            .max_by_key(|entry| entry.last_seen)
+            .filter(|entry| now - entry.last_seen < DISPLAY_DURATION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use chrono::TimeZone as _;
+    use pretty_assertions::assert_eq;
+
+    fn time(sec: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, sec).unwrap()
+    }
+
+    #[test]
+    fn 同じエラーは回数をまとめて最近のものから並べる() {
+        let mut history = ErrorHistory::default();
+
+        history.push("Pod", &anyhow!("connection refused"), time(0));
+        history.push("Event", &anyhow!("forbidden"), time(1));
+        history.push("Pod", &anyhow!("connection refused"), time(2));
+
+        let actual: Vec<(&str, &str, u32)> = history
+            .entries()
+            .map(|entry| (entry.source.as_str(), entry.summary(), entry.count))
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![("Pod", "connection refused", 2), ("Event", "forbidden", 1)]
+        );
+    }
+
+    #[test]
+    fn 一定時間が経過したエラーはステータスバーに表示しない() {
+        let mut history = ErrorHistory::default();
+
+        history.push("Pod", &anyhow!("connection refused"), time(0));
+
+        assert_eq!(
+            history.latest(time(9)).map(ErrorEntry::summary),
+            Some("connection refused")
+        );
+        assert_eq!(history.latest(time(10)), None);
+    }
+
+    #[test]
+    fn 上限を超えたら古いエラーから削除する() {
+        let mut history = ErrorHistory::default();
+
+        for i in 0..=MAX_ERRORS {
+            history.push("Pod", &anyhow!("error {}", i), time(0));
+        }
+
+        assert_eq!(history.len(), MAX_ERRORS);
+        assert!(history.entries().all(|entry| entry.message != "error 0"));
+    }
+}
@@ -0,0 +1,213 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use chrono::{DateTime, Local, Utc};
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+
+use crate::{
+    features::component_id::ERROR_HISTORY_POPUP_ID,
+    kube::api_status::{ApiStatus, ConnectionState},
+    ui::{
+        event::EventResult,
+        theme::{paint, Theme},
+        widget::{config::WidgetConfig, Item, LiteralItem, Text, Widget, WidgetTrait as _},
+        StatusBarCallback, Window,
+    },
+};
+
+use super::ErrorHistory;
+
+pub struct ErrorHistoryPopup {
+    pub popup: Widget<'static>,
+}
+
+impl ErrorHistoryPopup {
+    pub fn new(history: &Rc<RefCell<ErrorHistory>>) -> Self {
+        Self {
+            popup: popup(history),
+        }
+    }
+}
+
+fn popup(history: &Rc<RefCell<ErrorHistory>>) -> Widget<'static> {
+    let history = history.clone();
+
+    Text::builder()
+        .id(ERROR_HISTORY_POPUP_ID)
+        .widget_config(
+            &WidgetConfig::builder()
+                .title("Errors")
+                .append_title(" (c: clear)")
+                .build(),
+        )
+        .action('c', move |w: &mut Window| {
+            let mut history = history.borrow_mut();
+
+            history.clear();
+
+            update_error_history_popup(w, &history);
+
+            EventResult::Nop
+        })
+        .wrap()
+        .build()
+        .into()
+}
+
+/// 接続状態とレイテンシ、直近のエラーをステータスバーに表示する
+pub fn status_bar(history: Rc<RefCell<ErrorHistory>>) -> StatusBarCallback {
+    (move || status_line(ApiStatus::snapshot(), &history.borrow(), Utc::now())).into()
+}
+
+/// エラーの履歴を最新の状態にする
+pub fn update_error_history_popup(w: &mut Window, history: &ErrorHistory) {
+    w.find_widget_mut(ERROR_HISTORY_POPUP_ID)
+        .update_widget_item(Item::Array(history_items(history)));
+}
+
+fn status_line(status: ApiStatus, history: &ErrorHistory, now: DateTime<Utc>) -> Line<'static> {
+    let theme = Theme::global();
+
+    let connection = match status.state {
+        ConnectionState::Unknown => Span::styled("connecting", Style::default().fg(theme.muted)),
+        ConnectionState::Connected => Span::styled(
+            match status.latency {
+                Some(latency) => format!("connected {}", format_latency(latency)),
+                None => "connected".into(),
+            },
+            Style::default().fg(theme.success),
+        ),
+        ConnectionState::Disconnected => Span::styled(
+            format!("disconnected ({} failures)", status.failures),
+            Style::default().fg(theme.error),
+        ),
+    };
+
+    let mut spans = vec![Span::raw(" "), connection];
+
+    if !history.is_empty() {
+        spans.push(Span::styled(
+            format!("  errors: {}", history.len()),
+            Style::default().fg(theme.warning),
+        ));
+    }
+
+    if let Some(entry) = history.latest(now) {
+        let count = match entry.count {
+            1 => String::new(),
+            n => format!(" (x{})", n),
+        };
+
+        spans.push(Span::styled(
+            format!("  {}: {}{}", entry.source, entry.summary(), count),
+            Style::default().fg(theme.error),
+        ));
+    }
+
+    Line::from(spans)
+}
+
+fn format_latency(latency: Duration) -> String {
+    if latency < Duration::from_secs(1) {
+        format!("{}ms", latency.as_millis())
+    } else {
+        format!("{:.1}s", latency.as_secs_f64())
+    }
+}
+
+fn history_items(history: &ErrorHistory) -> Vec<LiteralItem> {
+    let theme = Theme::global();
+
+    history
+        .entries()
+        .flat_map(|entry| {
+            let count = match entry.count {
+                1 => String::new(),
+                n => format!(" (x{}, since {})", n, local_time(entry.first_seen)),
+            };
+
+            let header = paint(
+                theme.error,
+                format!(
+                    "{} [{}]{}",
+                    local_time(entry.last_seen),
+                    entry.source,
+                    count
+                ),
+            );
+
+            std::iter::once(header)
+                .chain(entry.message.lines().map(|line| format!("  {}", line)))
+                .chain(std::iter::once(String::new()))
+                .collect::<Vec<_>>()
+        })
+        .map(LiteralItem::from)
+        .collect()
+}
+
+fn local_time(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local).format("%H:%M:%S").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use chrono::TimeZone as _;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn time(sec: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, sec).unwrap()
+    }
+
+    #[rstest]
+    #[case(Duration::from_millis(35), "35ms")]
+    #[case(Duration::from_millis(1250), "1.2s")]
+    fn レイテンシを単位付きで表示する(
+        #[case] latency: Duration,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(format_latency(latency), expected);
+    }
+
+    #[test]
+    fn ステータスバーに接続状態と直近のエラーを表示する() {
+        let mut history = ErrorHistory::default();
+
+        history.push(
+            "Pod",
+            &anyhow!("connection refused\ncaused by: io"),
+            time(0),
+        );
+        history.push(
+            "Pod",
+            &anyhow!("connection refused\ncaused by: io"),
+            time(1),
+        );
+
+        let status = ApiStatus {
+            state: ConnectionState::Disconnected,
+            latency: Some(Duration::from_millis(20)),
+            failures: 2,
+        };
+
+        assert_eq!(
+            status_line(status, &history, time(2)).to_string(),
+            " disconnected (2 failures)  errors: 1  Pod: connection refused (x2)"
+        );
+
+        let status = ApiStatus {
+            state: ConnectionState::Connected,
+            latency: Some(Duration::from_millis(20)),
+            failures: 0,
+        };
+
+        assert_eq!(
+            status_line(status, &history, time(30)).to_string(),
+            " connected 20ms  errors: 1"
+        );
+    }
+}
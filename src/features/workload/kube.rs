@@ -0,0 +1,7 @@
+mod description;
+mod owner_tree;
+mod workload;
+
+pub use description::*;
+pub use owner_tree::*;
+pub use workload::*;
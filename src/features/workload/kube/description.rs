@@ -0,0 +1,644 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use crossbeam::channel::Sender;
+use k8s_openapi::{
+    api::{
+        apps::v1::{ControllerRevision, DaemonSet, Deployment, ReplicaSet, StatefulSet},
+        batch::v1::{CronJob, Job},
+        core::v1::Pod,
+    },
+    List, ListableResource,
+};
+use kube::{Resource, ResourceExt as _};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_yaml::{Mapping, Value};
+
+use crate::{
+    features::workload::message::{WorkloadKind, WorkloadResponse, WorkloadTarget},
+    kube::KubeClientRequest,
+    message::Message,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::AbortWorker,
+    },
+};
+
+use super::workload::age;
+
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+#[derive(Clone)]
+pub struct WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    client: C,
+    req: WorkloadTarget,
+}
+
+impl<C> WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        client: C,
+        req: WorkloadTarget,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            client,
+            req,
+        }
+    }
+}
+
+#[async_trait]
+impl<C> AbortWorker for WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn run(&self) {
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().description);
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            let fetched_data = self.fetch().await;
+
+            interval.record(&fetched_data);
+
+            self.tx
+                .send(WorkloadResponse::Description(fetched_data).into())
+                .expect("Failed to send WorkloadResponse::Description");
+        }
+    }
+}
+
+/// 選択したワークロードに紐づくPodとリビジョン履歴
+struct Description {
+    name: String,
+    namespace: String,
+    status: Value,
+    pods: Vec<Pod>,
+    history: Vec<Revision>,
+}
+
+struct Revision {
+    revision: i64,
+    name: String,
+    images: Vec<String>,
+    age: String,
+}
+
+impl<C> WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn fetch(&self) -> Result<Vec<String>> {
+        let description = match self.req.kind {
+            WorkloadKind::Deployment => self.fetch_deployment().await?,
+            WorkloadKind::StatefulSet => {
+                self.fetch_with_controller_revisions::<StatefulSet>()
+                    .await?
+            }
+            WorkloadKind::DaemonSet => self.fetch_with_controller_revisions::<DaemonSet>().await?,
+            WorkloadKind::ReplicaSet => self.fetch_without_history::<ReplicaSet>().await?,
+            WorkloadKind::Job => self.fetch_without_history::<Job>().await?,
+            WorkloadKind::CronJob => self.fetch_cronjob().await?,
+        };
+
+        description.to_lines(self.req.kind)
+    }
+
+    async fn get<K>(&self) -> Result<K>
+    where
+        K: Resource<DynamicType = ()> + DeserializeOwned + 'static,
+    {
+        let url = format!(
+            "{}/{}",
+            K::url_path(&(), Some(&self.req.namespace)),
+            self.req.name
+        );
+
+        self.client.request(&url).await
+    }
+
+    async fn list<K>(&self) -> Result<List<K>>
+    where
+        K: Resource<DynamicType = ()> + ListableResource + DeserializeOwned + 'static,
+    {
+        self.client
+            .request(&K::url_path(&(), Some(&self.req.namespace)))
+            .await
+    }
+
+    async fn fetch_deployment(&self) -> Result<Description> {
+        let deployment: Deployment = self.get().await?;
+        let replicasets: List<ReplicaSet> = self.list().await?;
+        let pods: List<Pod> = self.list().await?;
+
+        let now = Utc::now();
+
+        let owned_replicasets: Vec<&ReplicaSet> = replicasets
+            .items
+            .iter()
+            .filter(|rs| is_owned_by(*rs, &deployment))
+            .collect();
+
+        let mut history: Vec<Revision> = owned_replicasets
+            .iter()
+            .map(|rs| Revision {
+                revision: rs
+                    .annotations()
+                    .get(REVISION_ANNOTATION)
+                    .and_then(|r| r.parse().ok())
+                    .unwrap_or_default(),
+                name: rs.name_any(),
+                images: rs
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| spec.template.as_ref())
+                    .and_then(|template| template.spec.as_ref())
+                    .map(|spec| container_images(&spec.containers))
+                    .unwrap_or_default(),
+                age: age(rs.metadata.creation_timestamp.as_ref(), now),
+            })
+            .collect();
+
+        history.sort_by_key(|h| std::cmp::Reverse(h.revision));
+
+        let pods = pods
+            .items
+            .into_iter()
+            .filter(|pod| owned_replicasets.iter().any(|rs| is_owned_by(pod, *rs)))
+            .collect();
+
+        Ok(Description {
+            name: deployment.name_any(),
+            namespace: deployment.namespace().unwrap_or_default(),
+            status: serde_yaml::to_value(&deployment.status)?,
+            pods,
+            history,
+        })
+    }
+
+    async fn fetch_with_controller_revisions<K>(&self) -> Result<Description>
+    where
+        K: Resource<DynamicType = ()> + DeserializeOwned + Serialize + 'static,
+        K: HasStatus,
+    {
+        let workload: K = self.get().await?;
+        let revisions: List<ControllerRevision> = self.list().await?;
+        let pods: List<Pod> = self.list().await?;
+
+        let now = Utc::now();
+
+        let mut history: Vec<Revision> = revisions
+            .items
+            .iter()
+            .filter(|rev| is_owned_by(*rev, &workload))
+            .map(|rev| Revision {
+                revision: rev.revision,
+                name: rev.name_any(),
+                images: rev
+                    .data
+                    .as_ref()
+                    .map(|data| raw_extension_images(&data.0))
+                    .unwrap_or_default(),
+                age: age(rev.metadata.creation_timestamp.as_ref(), now),
+            })
+            .collect();
+
+        history.sort_by_key(|h| std::cmp::Reverse(h.revision));
+
+        let pods = pods
+            .items
+            .into_iter()
+            .filter(|pod| is_owned_by(pod, &workload))
+            .collect();
+
+        Ok(Description {
+            name: workload.meta().name.clone().unwrap_or_default(),
+            namespace: workload.meta().namespace.clone().unwrap_or_default(),
+            status: workload.status_value()?,
+            pods,
+            history,
+        })
+    }
+
+    async fn fetch_without_history<K>(&self) -> Result<Description>
+    where
+        K: Resource<DynamicType = ()> + DeserializeOwned + Serialize + 'static,
+        K: HasStatus,
+    {
+        let workload: K = self.get().await?;
+        let pods: List<Pod> = self.list().await?;
+
+        let pods = pods
+            .items
+            .into_iter()
+            .filter(|pod| is_owned_by(pod, &workload))
+            .collect();
+
+        Ok(Description {
+            name: workload.meta().name.clone().unwrap_or_default(),
+            namespace: workload.meta().namespace.clone().unwrap_or_default(),
+            status: workload.status_value()?,
+            pods,
+            history: Vec::new(),
+        })
+    }
+
+    async fn fetch_cronjob(&self) -> Result<Description> {
+        let cronjob: CronJob = self.get().await?;
+        let jobs: List<Job> = self.list().await?;
+        let pods: List<Pod> = self.list().await?;
+
+        let now = Utc::now();
+
+        let owned_jobs: Vec<&Job> = jobs
+            .items
+            .iter()
+            .filter(|job| is_owned_by(*job, &cronjob))
+            .collect();
+
+        let mut history: Vec<(Option<_>, Revision)> = owned_jobs
+            .iter()
+            .enumerate()
+            .map(|(i, job)| {
+                (
+                    job.metadata.creation_timestamp.clone(),
+                    Revision {
+                        revision: i as i64,
+                        name: job.name_any(),
+                        images: job
+                            .spec
+                            .as_ref()
+                            .and_then(|spec| spec.template.spec.as_ref())
+                            .map(|spec| container_images(&spec.containers))
+                            .unwrap_or_default(),
+                        age: age(job.metadata.creation_timestamp.as_ref(), now),
+                    },
+                )
+            })
+            .collect();
+
+        // Jobにはリビジョン番号がないため作成日時の新しい順に番号を振り直す
+        history.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let len = history.len() as i64;
+        let history = history
+            .into_iter()
+            .enumerate()
+            .map(|(i, (_, mut rev))| {
+                rev.revision = len - i as i64;
+                rev
+            })
+            .collect();
+
+        let pods = pods
+            .items
+            .into_iter()
+            .filter(|pod| owned_jobs.iter().any(|job| is_owned_by(pod, *job)))
+            .collect();
+
+        Ok(Description {
+            name: cronjob.name_any(),
+            namespace: cronjob.namespace().unwrap_or_default(),
+            status: serde_yaml::to_value(&cronjob.status)?,
+            pods,
+            history,
+        })
+    }
+}
+
+pub trait HasStatus {
+    fn status_value(&self) -> Result<Value>;
+}
+
+macro_rules! impl_has_status {
+    ($($ty:ty),*) => {
+        $(
+            impl HasStatus for $ty {
+                fn status_value(&self) -> Result<Value> {
+                    Ok(serde_yaml::to_value(&self.status)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_has_status!(StatefulSet, DaemonSet, ReplicaSet, Job);
+
+fn is_owned_by<O, K>(object: &O, owner: &K) -> bool
+where
+    O: Resource,
+    K: Resource,
+{
+    let Some(uid) = owner.meta().uid.as_ref() else {
+        return false;
+    };
+
+    object
+        .meta()
+        .owner_references
+        .as_ref()
+        .is_some_and(|refs| refs.iter().any(|r| &r.uid == uid))
+}
+
+fn container_images(containers: &[k8s_openapi::api::core::v1::Container]) -> Vec<String> {
+    containers.iter().filter_map(|c| c.image.clone()).collect()
+}
+
+/// ControllerRevisionのdataからコンテナイメージを取り出す
+fn raw_extension_images(data: &serde_json::Value) -> Vec<String> {
+    data.pointer("/spec/template/spec/containers")
+        .and_then(|containers| containers.as_array())
+        .map(|containers| {
+            containers
+                .iter()
+                .filter_map(|c| c.get("image").and_then(|i| i.as_str()))
+                .map(ToString::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn pod_value(pod: &Pod) -> Value {
+    let mut value = Mapping::new();
+
+    value.insert("name".into(), pod.name_any().into());
+
+    let status = pod.status.clone().unwrap_or_default();
+
+    if let Some(phase) = status.phase {
+        value.insert("phase".into(), phase.into());
+    }
+
+    let container_statuses = status.container_statuses.unwrap_or_default();
+
+    let ready = container_statuses.iter().filter(|c| c.ready).count();
+    let restarts: i32 = container_statuses.iter().map(|c| c.restart_count).sum();
+
+    value.insert(
+        "ready".into(),
+        format!("{}/{}", ready, container_statuses.len()).into(),
+    );
+    value.insert("restarts".into(), restarts.into());
+
+    if let Some(node) = pod.spec.as_ref().and_then(|spec| spec.node_name.clone()) {
+        value.insert("node".into(), node.into());
+    }
+
+    value.into()
+}
+
+impl Description {
+    fn to_lines(&self, kind: WorkloadKind) -> Result<Vec<String>> {
+        let mut workload = Mapping::new();
+
+        workload.insert("kind".into(), kind.to_string().into());
+        workload.insert("name".into(), self.name.clone().into());
+        workload.insert("namespace".into(), self.namespace.clone().into());
+
+        if !self.status.is_null() {
+            workload.insert("status".into(), self.status.clone());
+        }
+
+        let mut root = Mapping::new();
+
+        root.insert("workload".into(), workload.into());
+
+        if !self.pods.is_empty() {
+            root.insert(
+                "pods".into(),
+                Value::Sequence(self.pods.iter().map(pod_value).collect()),
+            );
+        }
+
+        if !self.history.is_empty() {
+            let history = self
+                .history
+                .iter()
+                .map(|rev| {
+                    let mut value = Mapping::new();
+                    value.insert("revision".into(), rev.revision.into());
+                    value.insert("name".into(), rev.name.clone().into());
+                    value.insert("images".into(), rev.images.clone().into());
+                    value.insert("age".into(), rev.age.clone().into());
+                    Value::from(value)
+                })
+                .collect();
+
+            root.insert("history".into(), Value::Sequence(history));
+        }
+
+        Ok(serde_yaml::to_string(&root)?
+            .lines()
+            .map(ToString::to_string)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+    use crossbeam::channel::{bounded, Receiver};
+    use indoc::indoc;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    fn worker(
+        client: MockTestKubeClient,
+        kind: WorkloadKind,
+    ) -> WorkloadDescriptionWorker<MockTestKubeClient> {
+        let (tx, _rx): (Sender<Message>, Receiver<Message>) = bounded(3);
+
+        WorkloadDescriptionWorker::new(
+            Arc::new(AtomicBool::new(false)),
+            tx,
+            client,
+            WorkloadTarget {
+                kind,
+                name: "app".into(),
+                namespace: "default".into(),
+            },
+        )
+    }
+
+    fn deployment() -> Deployment {
+        serde_yaml::from_str(indoc! {
+            r#"
+            metadata:
+              name: app
+              namespace: default
+              uid: deploy-uid
+            status:
+              replicas: 1
+              readyReplicas: 1
+            "#
+        })
+        .unwrap()
+    }
+
+    fn replicasets() -> List<ReplicaSet> {
+        serde_yaml::from_str(indoc! {
+            r#"
+            items:
+            - metadata:
+                name: app-1
+                uid: rs-1
+                annotations:
+                  deployment.kubernetes.io/revision: "1"
+                ownerReferences:
+                - apiVersion: apps/v1
+                  kind: Deployment
+                  name: app
+                  uid: deploy-uid
+              spec:
+                selector: {}
+                template:
+                  spec:
+                    containers:
+                    - name: app
+                      image: app:v1
+            - metadata:
+                name: app-2
+                uid: rs-2
+                annotations:
+                  deployment.kubernetes.io/revision: "2"
+                ownerReferences:
+                - apiVersion: apps/v1
+                  kind: Deployment
+                  name: app
+                  uid: deploy-uid
+              spec:
+                selector: {}
+                template:
+                  spec:
+                    containers:
+                    - name: app
+                      image: app:v2
+            - metadata:
+                name: other
+                uid: rs-3
+              spec:
+                selector: {}
+            "#
+        })
+        .unwrap()
+    }
+
+    fn pods() -> List<Pod> {
+        serde_yaml::from_str(indoc! {
+            r#"
+            items:
+            - metadata:
+                name: app-2-abcde
+                ownerReferences:
+                - apiVersion: apps/v1
+                  kind: ReplicaSet
+                  name: app-2
+                  uid: rs-2
+              spec:
+                nodeName: node-1
+                containers:
+                - name: app
+              status:
+                phase: Running
+                containerStatuses:
+                - name: app
+                  image: app:v2
+                  imageID: ""
+                  ready: true
+                  restartCount: 2
+            - metadata:
+                name: other-abcde
+                ownerReferences:
+                - apiVersion: apps/v1
+                  kind: ReplicaSet
+                  name: other
+                  uid: rs-3
+              spec:
+                containers:
+                - name: other
+            "#
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn deploymentに紐づくpodとリビジョン履歴を返す() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Deployment,
+                    eq("/apis/apps/v1/namespaces/default/deployments/app"),
+                    Ok(deployment())
+                ),
+                (
+                    List<ReplicaSet>,
+                    eq("/apis/apps/v1/namespaces/default/replicasets"),
+                    Ok(replicasets())
+                ),
+                (
+                    List<Pod>,
+                    eq("/api/v1/namespaces/default/pods"),
+                    Ok(pods())
+                )
+            ]
+        );
+
+        let actual = worker(client, WorkloadKind::Deployment)
+            .fetch()
+            .await
+            .unwrap();
+
+        let history_index = actual.iter().position(|l| l == "history:").unwrap();
+
+        let expected: Vec<String> = indoc! {
+            "
+            workload:
+              kind: Deployment
+              name: app
+              namespace: default
+              status:
+                readyReplicas: 1
+                replicas: 1
+            pods:
+            - name: app-2-abcde
+              phase: Running
+              ready: 1/1
+              restarts: 2
+              node: node-1
+            history:
+            - revision: 2
+              name: app-2
+              images:
+              - app:v2
+            "
+        }
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(actual[..history_index + 5].to_vec(), expected);
+    }
+}
@@ -0,0 +1,738 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crossbeam::channel::Sender;
+use futures::future::join_all;
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
+        batch::v1::{CronJob, Job},
+        core::v1::Pod,
+    },
+    apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference},
+};
+use ratatui::style::Color;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+use crate::{
+    features::{
+        api_resources::kube::{ApiResource, ApiResources, SharedApiResources},
+        workload::message::WorkloadResponse,
+    },
+    kube::KubeClientRequest,
+    logger,
+    message::Message,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::{AbortWorker, SharedTargetApiResources},
+    },
+};
+
+use super::workload::{RolloutStatus, WorkloadRow};
+
+/// ownerReferencesを辿る起点のリソース
+#[derive(Debug, Clone)]
+pub struct OwnerTreeTarget {
+    pub kind: String,
+    /// 分からないときはkindから探す
+    pub api_version: Option<String>,
+    pub name: String,
+    pub namespace: String,
+}
+
+/// ownerReferencesで繋がったリソースのツリー
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnerTreeNode {
+    pub kind: String,
+    pub name: String,
+    pub status: String,
+    pub color: Option<Color>,
+    /// 起点のリソースかどうか
+    pub is_target: bool,
+    pub children: Vec<OwnerTreeNode>,
+}
+
+/// 子を探すために常に一覧を取得する組み込みのリソース
+const BUILTIN_OWNER_KINDS: [(&str, &str); 7] = [
+    ("v1", "Pod"),
+    ("apps/v1", "ReplicaSet"),
+    ("apps/v1", "Deployment"),
+    ("apps/v1", "StatefulSet"),
+    ("apps/v1", "DaemonSet"),
+    ("batch/v1", "Job"),
+    ("batch/v1", "CronJob"),
+];
+
+#[derive(Clone)]
+pub struct OwnerTreeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    client: C,
+    shared_api_resources: SharedApiResources,
+    shared_target_api_resources: SharedTargetApiResources,
+    req: OwnerTreeTarget,
+}
+
+impl<C> OwnerTreeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        client: C,
+        shared_api_resources: SharedApiResources,
+        shared_target_api_resources: SharedTargetApiResources,
+        req: OwnerTreeTarget,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            client,
+            shared_api_resources,
+            shared_target_api_resources,
+            req,
+        }
+    }
+}
+
+#[async_trait]
+impl<C> AbortWorker for OwnerTreeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn run(&self) {
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().description);
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            let fetched_data = self.fetch().await;
+
+            interval.record(&fetched_data);
+
+            self.tx
+                .send(WorkloadResponse::OwnerTree(fetched_data).into())
+                .expect("Failed to send WorkloadResponse::OwnerTree");
+        }
+    }
+}
+
+/// 一覧のitemsだけを取り出す
+#[derive(Deserialize)]
+struct ObjectList {
+    #[serde(default)]
+    items: Vec<Value>,
+}
+
+impl<C> OwnerTreeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    /// 起点から親を辿って経路上のAPIを集め、それらと組み込みのワークロード、
+    /// Listタブで選択中のリソースの一覧からツリーを作る
+    async fn fetch(&self) -> Result<Vec<OwnerTreeNode>> {
+        let OwnerTreeTarget {
+            kind,
+            api_version,
+            name,
+            ..
+        } = &self.req;
+
+        let api_resources = self.shared_api_resources.read().await.clone();
+
+        let Some(target) = api_resources.resolve(api_version.as_deref(), kind) else {
+            bail!("Can't find {} in API resources", kind);
+        };
+
+        let mut apis: BTreeSet<ApiResource> = BUILTIN_OWNER_KINDS
+            .iter()
+            .filter_map(|(api_version, kind)| api_resources.find_by_api_version(api_version, kind))
+            .cloned()
+            .collect();
+
+        apis.extend(
+            self.shared_target_api_resources
+                .read()
+                .await
+                .iter()
+                .filter(|api| api.is_namespaced())
+                .cloned(),
+        );
+
+        apis.insert(target.clone());
+
+        apis.extend(self.owner_apis(&api_resources, target).await);
+
+        let lists = join_all(
+            apis.iter()
+                .map(|api| async move { (api, self.list(api).await) }),
+        )
+        .await;
+
+        let now = Utc::now();
+
+        let mut graph = OwnerGraph::default();
+
+        for (api, list) in lists {
+            match list {
+                Ok(items) => graph.extend(api, items, now),
+                // 起点以外は権限がないなどで取得できなくてもツリーを表示する
+                Err(e) if api != target => {
+                    logger!(warn, "Failed to list {} for owner tree: {}", api, e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        graph.tree(&target.api_version(), target.kind(), name)
+    }
+
+    /// 起点からcontrollerの参照を遡り、親のAPIを返す
+    ///
+    /// APIを解決できない、または取得できない親で止める
+    async fn owner_apis(
+        &self,
+        api_resources: &ApiResources,
+        target: &ApiResource,
+    ) -> Vec<ApiResource> {
+        let mut apis = Vec::new();
+
+        let mut visited = HashSet::new();
+
+        let mut current = (target.clone(), self.req.name.clone());
+
+        loop {
+            let (api, name) = &current;
+
+            let Ok(object) = self.get(api, name).await else {
+                return apis;
+            };
+
+            let metadata: ObjectMeta =
+                serde_json::from_value(object["metadata"].clone()).unwrap_or_default();
+
+            let owner_references = metadata.owner_references.unwrap_or_default();
+
+            let Some(owner) = controller_owner(&owner_references) else {
+                return apis;
+            };
+
+            if !visited.insert(owner.uid.clone()) {
+                return apis;
+            }
+
+            let Some(owner_api) = api_resources.resolve(Some(&owner.api_version), &owner.kind)
+            else {
+                return apis;
+            };
+
+            apis.push(owner_api.clone());
+
+            current = (owner_api.clone(), owner.name.clone());
+        }
+    }
+
+    fn url(&self, api: &ApiResource) -> String {
+        if api.is_namespaced() {
+            api.api_url_with_namespace(&self.req.namespace)
+        } else {
+            api.api_url()
+        }
+    }
+
+    async fn get(&self, api: &ApiResource, name: &str) -> Result<Value> {
+        self.client
+            .request(&format!("{}/{}", self.url(api), name))
+            .await
+    }
+
+    async fn list(&self, api: &ApiResource) -> Result<Vec<Value>> {
+        let list: ObjectList = self.client.request(&self.url(api)).await?;
+
+        Ok(list.items)
+    }
+}
+
+/// controller: trueの参照を優先する
+fn controller_owner(owner_references: &[OwnerReference]) -> Option<&OwnerReference> {
+    owner_references
+        .iter()
+        .find(|r| r.controller.unwrap_or_default())
+        .or_else(|| owner_references.first())
+}
+
+/// 組み込みのワークロードはWorkloadタブと同じ状態を返し、それ以外はReady条件から決める
+fn object_status(api: &ApiResource, object: Value, now: DateTime<Utc>) -> Option<RolloutStatus> {
+    match (api.api_version().as_str(), api.kind()) {
+        ("v1", "Pod") => typed_status(object, pod_status),
+        ("apps/v1", "ReplicaSet") => typed_status(object, |r: &ReplicaSet| {
+            WorkloadRow::from_replicaset(r, now).status
+        }),
+        ("apps/v1", "Deployment") => typed_status(object, |d: &Deployment| {
+            WorkloadRow::from_deployment(d, now).status
+        }),
+        ("apps/v1", "StatefulSet") => typed_status(object, |s: &StatefulSet| {
+            WorkloadRow::from_statefulset(s, now).status
+        }),
+        ("apps/v1", "DaemonSet") => typed_status(object, |d: &DaemonSet| {
+            WorkloadRow::from_daemonset(d, now).status
+        }),
+        ("batch/v1", "Job") => typed_status(object, |j: &Job| WorkloadRow::from_job(j, now).status),
+        ("batch/v1", "CronJob") => typed_status(object, |c: &CronJob| {
+            WorkloadRow::from_cronjob(c, now).status
+        }),
+        _ => ready_condition_status(&object),
+    }
+}
+
+fn typed_status<K, F>(object: Value, status: F) -> Option<RolloutStatus>
+where
+    K: DeserializeOwned,
+    F: Fn(&K) -> RolloutStatus,
+{
+    serde_json::from_value::<K>(object)
+        .ok()
+        .map(|object| status(&object))
+}
+
+/// Ready条件がないリソースは状態なしにする
+fn ready_condition_status(object: &Value) -> Option<RolloutStatus> {
+    let ready = object["status"]["conditions"]
+        .as_array()?
+        .iter()
+        .find(|c| c["type"] == "Ready")?;
+
+    let reason = ready["reason"].as_str().filter(|r| !r.is_empty());
+
+    let status = match ready["status"].as_str() {
+        Some("True") => RolloutStatus::Available("Ready".into()),
+        Some("False") => RolloutStatus::Failed(reason.unwrap_or("NotReady").into()),
+        _ => RolloutStatus::Progressing(reason.unwrap_or("Unknown").into()),
+    };
+
+    Some(status)
+}
+
+/// kubectl get podのSTATUS列に近い値を返す
+fn pod_status(pod: &Pod) -> RolloutStatus {
+    if pod.metadata.deletion_timestamp.is_some() {
+        return RolloutStatus::Progressing("Terminating".into());
+    }
+
+    let status = pod.status.clone().unwrap_or_default();
+
+    let container_statuses = status.container_statuses.unwrap_or_default();
+
+    let reason = container_statuses.iter().find_map(|c| {
+        let state = c.state.as_ref()?;
+
+        state
+            .waiting
+            .as_ref()
+            .and_then(|w| w.reason.clone())
+            .or_else(|| {
+                state
+                    .terminated
+                    .as_ref()
+                    .and_then(|t| t.reason.clone())
+                    .filter(|reason| reason != "Completed")
+            })
+    });
+
+    if let Some(reason) = reason {
+        return if reason.contains("BackOff") || reason.contains("Err") || reason == "Error" {
+            RolloutStatus::Failed(reason)
+        } else {
+            RolloutStatus::Progressing(reason)
+        };
+    }
+
+    match status.phase.as_deref() {
+        Some("Running") if container_statuses.iter().all(|c| c.ready) => {
+            RolloutStatus::Available("Running".into())
+        }
+        Some("Running") => RolloutStatus::Progressing("Running".into()),
+        Some("Succeeded") => RolloutStatus::Inactive("Completed".into()),
+        Some("Failed") => RolloutStatus::Failed(status.reason.unwrap_or_else(|| "Failed".into())),
+        Some(phase) => RolloutStatus::Progressing(phase.to_string()),
+        None => RolloutStatus::Progressing("Unknown".into()),
+    }
+}
+
+struct OwnerGraphNode {
+    uid: String,
+    api_version: String,
+    kind: String,
+    name: String,
+    owner_references: Vec<OwnerReference>,
+    status: Option<RolloutStatus>,
+}
+
+#[derive(Default)]
+struct OwnerGraph {
+    nodes: Vec<OwnerGraphNode>,
+}
+
+impl OwnerGraph {
+    /// 一覧のitemsにはapiVersionとkindが含まれないため補ってから状態を求める
+    fn extend(&mut self, api: &ApiResource, items: Vec<Value>, now: DateTime<Utc>) {
+        let api_version = api.api_version();
+
+        self.nodes.extend(items.into_iter().map(|mut item| {
+            item["apiVersion"] = api_version.clone().into();
+            item["kind"] = api.kind().into();
+
+            let metadata: ObjectMeta =
+                serde_json::from_value(item["metadata"].clone()).unwrap_or_default();
+
+            OwnerGraphNode {
+                uid: metadata.uid.unwrap_or_default(),
+                api_version: api_version.clone(),
+                kind: api.kind().to_string(),
+                name: metadata.name.unwrap_or_default(),
+                owner_references: metadata.owner_references.unwrap_or_default(),
+                status: object_status(api, item, now),
+            }
+        }));
+    }
+
+    fn find_by_uid(&self, uid: &str) -> Option<&OwnerGraphNode> {
+        self.nodes.iter().find(|node| node.uid == uid)
+    }
+
+    /// 起点からownerReferencesを遡ってルートを見つけ、ルート配下のツリーを返す
+    fn tree(&self, api_version: &str, kind: &str, name: &str) -> Result<Vec<OwnerTreeNode>> {
+        let Some(target) = self
+            .nodes
+            .iter()
+            .find(|node| node.api_version == api_version && node.kind == kind && node.name == name)
+        else {
+            bail!("{}/{} not found", kind, name);
+        };
+
+        let mut visited = HashSet::from([target.uid.as_str()]);
+
+        let mut current = target;
+
+        loop {
+            let Some(owner) = controller_owner(&current.owner_references) else {
+                return Ok(vec![self.subtree(
+                    current,
+                    &target.uid,
+                    &mut HashSet::new(),
+                )]);
+            };
+
+            match self.find_by_uid(&owner.uid) {
+                Some(node) if visited.insert(node.uid.as_str()) => {
+                    current = node;
+                }
+                Some(_) => {
+                    return Ok(vec![self.subtree(
+                        current,
+                        &target.uid,
+                        &mut HashSet::new(),
+                    )]);
+                }
+                // 解決や取得ができなかったリソースは状態なしのノードとして扱う
+                None => {
+                    return Ok(vec![OwnerTreeNode {
+                        kind: owner.kind.clone(),
+                        name: owner.name.clone(),
+                        status: "-".into(),
+                        color: None,
+                        is_target: false,
+                        children: vec![self.subtree(current, &target.uid, &mut HashSet::new())],
+                    }]);
+                }
+            }
+        }
+    }
+
+    fn subtree<'a>(
+        &'a self,
+        node: &'a OwnerGraphNode,
+        target_uid: &str,
+        visited: &mut HashSet<&'a str>,
+    ) -> OwnerTreeNode {
+        visited.insert(&node.uid);
+
+        let mut children: Vec<&OwnerGraphNode> = self
+            .nodes
+            .iter()
+            .filter(|child| !visited.contains(child.uid.as_str()))
+            .filter(|child| child.owner_references.iter().any(|r| r.uid == node.uid))
+            .collect();
+
+        children.sort_by(|a, b| (&a.kind, &a.name).cmp(&(&b.kind, &b.name)));
+
+        OwnerTreeNode {
+            kind: node.kind.clone(),
+            name: node.name.clone(),
+            status: node
+                .status
+                .as_ref()
+                .map_or("-", |status| status.message())
+                .to_string(),
+            color: node.status.as_ref().and_then(|status| status.color()),
+            is_target: node.uid == target_uid,
+            children: children
+                .into_iter()
+                .map(|child| self.subtree(child, target_uid, visited))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use kube::discovery::Scope;
+    use pretty_assertions::assert_eq;
+
+    fn api(api_version: &str, kind: &str) -> ApiResource {
+        let name = format!("{}s", kind.to_lowercase());
+
+        match api_version.split_once('/') {
+            Some((group, version)) => ApiResource::Apis {
+                name,
+                kind: kind.into(),
+                group: group.into(),
+                version: version.into(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            },
+            None => ApiResource::Api {
+                name,
+                kind: kind.into(),
+                version: api_version.into(),
+                scope: Scope::Namespaced,
+            },
+        }
+    }
+
+    fn items(yaml: &str) -> Vec<Value> {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn graph() -> OwnerGraph {
+        let cronjobs = items(indoc! {
+            r#"
+            - metadata:
+                name: batch
+                uid: cj-uid
+            "#
+        });
+
+        let jobs = items(indoc! {
+            r#"
+            - metadata:
+                name: batch-1
+                uid: job-1
+                ownerReferences:
+                - apiVersion: batch/v1
+                  kind: CronJob
+                  name: batch
+                  uid: cj-uid
+                  controller: true
+              status:
+                conditions:
+                - type: Complete
+                  status: "True"
+            - metadata:
+                name: batch-2
+                uid: job-2
+                ownerReferences:
+                - apiVersion: batch/v1
+                  kind: CronJob
+                  name: batch
+                  uid: cj-uid
+                  controller: true
+            "#
+        });
+
+        let pods = items(indoc! {
+            r#"
+            - metadata:
+                name: batch-2-abcde
+                uid: pod-1
+                ownerReferences:
+                - apiVersion: batch/v1
+                  kind: Job
+                  name: batch-2
+                  uid: job-2
+                  controller: true
+              status:
+                phase: Running
+                containerStatuses:
+                - name: main
+                  image: batch
+                  imageID: ""
+                  ready: false
+                  restartCount: 3
+                  state:
+                    waiting:
+                      reason: CrashLoopBackOff
+            - metadata:
+                name: rollout-abcde
+                uid: pod-2
+                ownerReferences:
+                - apiVersion: argoproj.io/v1alpha1
+                  kind: Rollout
+                  name: rollout
+                  uid: rollout-uid
+                  controller: true
+              status:
+                phase: Succeeded
+            - metadata:
+                name: cluster-1
+                uid: pod-3
+                ownerReferences:
+                - apiVersion: example.com/v1
+                  kind: Cluster
+                  name: cluster
+                  uid: cluster-uid
+                  controller: true
+              status:
+                phase: Succeeded
+            "#
+        });
+
+        let clusters = items(indoc! {
+            r#"
+            - metadata:
+                name: cluster
+                uid: cluster-uid
+              status:
+                conditions:
+                - type: Ready
+                  status: "False"
+                  reason: Reconciling
+            "#
+        });
+
+        let now = Utc::now();
+
+        let mut graph = OwnerGraph::default();
+        graph.extend(&api("v1", "Pod"), pods, now);
+        graph.extend(&api("batch/v1", "Job"), jobs, now);
+        graph.extend(&api("batch/v1", "CronJob"), cronjobs, now);
+        graph.extend(&api("example.com/v1", "Cluster"), clusters, now);
+        graph
+    }
+
+    #[test]
+    fn podからルートまで遡ってツリーを返す() {
+        let actual = graph().tree("v1", "Pod", "batch-2-abcde").unwrap();
+
+        let expected = vec![OwnerTreeNode {
+            kind: "CronJob".into(),
+            name: "batch".into(),
+            status: "Scheduled".into(),
+            color: None,
+            is_target: false,
+            children: vec![
+                OwnerTreeNode {
+                    kind: "Job".into(),
+                    name: "batch-1".into(),
+                    status: "Complete".into(),
+                    color: Some(Color::DarkGray),
+                    is_target: false,
+                    children: vec![],
+                },
+                OwnerTreeNode {
+                    kind: "Job".into(),
+                    name: "batch-2".into(),
+                    status: "Running 0/1".into(),
+                    color: Some(Color::Yellow),
+                    is_target: false,
+                    children: vec![OwnerTreeNode {
+                        kind: "Pod".into(),
+                        name: "batch-2-abcde".into(),
+                        status: "CrashLoopBackOff".into(),
+                        color: Some(Color::Red),
+                        is_target: true,
+                        children: vec![],
+                    }],
+                },
+            ],
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 取得対象外のownerは状態なしのノードにする() {
+        let actual = graph().tree("v1", "Pod", "rollout-abcde").unwrap();
+
+        let expected = vec![OwnerTreeNode {
+            kind: "Rollout".into(),
+            name: "rollout".into(),
+            status: "-".into(),
+            color: None,
+            is_target: false,
+            children: vec![OwnerTreeNode {
+                kind: "Pod".into(),
+                name: "rollout-abcde".into(),
+                status: "Completed".into(),
+                color: Some(Color::DarkGray),
+                is_target: true,
+                children: vec![],
+            }],
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn カスタムリソースのownerはready条件を状態にする() {
+        let actual = graph().tree("v1", "Pod", "cluster-1").unwrap();
+
+        let expected = vec![OwnerTreeNode {
+            kind: "Cluster".into(),
+            name: "cluster".into(),
+            status: "Reconciling".into(),
+            color: Some(Color::Red),
+            is_target: false,
+            children: vec![OwnerTreeNode {
+                kind: "Pod".into(),
+                name: "cluster-1".into(),
+                status: "Completed".into(),
+                color: Some(Color::DarkGray),
+                is_target: true,
+                children: vec![],
+            }],
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn apiversionが異なる同じkindのリソースは起点にしない() {
+        let actual = graph().tree("example.com/v2", "Cluster", "cluster");
+
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn 起点が見つからないときエラーを返す() {
+        let actual = graph().tree("apps/v1", "Deployment", "app");
+
+        assert!(actual.is_err());
+    }
+}
@@ -0,0 +1,729 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::try_join_all;
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
+        batch::v1::{CronJob, Job},
+    },
+    apimachinery::pkg::apis::meta::v1::Time,
+    List,
+};
+use kube::{Resource, ResourceExt as _};
+use ratatui::style::Color;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    features::workload::message::{WorkloadKind, WorkloadResponse},
+    kube::{
+        table::{insert_ns, KubeTable, KubeTableRow},
+        KubeClient, KubeClientRequest as _,
+    },
+    ui::theme::{paint, Theme},
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::{PollerBase, Worker, WorkerResult},
+    },
+};
+
+#[derive(Clone)]
+pub struct WorkloadPoller {
+    base: PollerBase,
+}
+
+impl WorkloadPoller {
+    pub fn new(base: PollerBase) -> Self {
+        Self { base }
+    }
+}
+
+#[async_trait]
+impl Worker for WorkloadPoller {
+    type Output = WorkerResult;
+
+    async fn run(&self) -> Self::Output {
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().workload);
+
+        let is_terminated = &self.base.is_terminated;
+        let tx = &self.base.tx;
+
+        while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
+            interval.tick().await;
+
+            let table = self.polling().await;
+
+            interval.record(&table);
+
+            tx.send(WorkloadResponse::List(table).into())
+                .expect("Failed to send WorkloadResponse::List");
+        }
+
+        WorkerResult::Terminated
+    }
+}
+
+impl WorkloadPoller {
+    async fn polling(&self) -> Result<KubeTable> {
+        let target_namespaces = self.base.shared_target_namespaces.read().await;
+
+        let header = [
+            "KIND",
+            "NAME",
+            "DESIRED",
+            "READY",
+            "UP-TO-DATE",
+            "AVAILABLE",
+            "STATUS",
+            "AGE",
+        ];
+
+        let mut table = KubeTable {
+            header: if target_namespaces.len() == 1 {
+                header.iter().map(ToString::to_string).collect()
+            } else {
+                ["NAMESPACE"]
+                    .iter()
+                    .chain(header.iter())
+                    .map(ToString::to_string)
+                    .collect()
+            },
+            ..Default::default()
+        };
+
+        let insert_ns = insert_ns(&target_namespaces);
+
+        let jobs = try_join_all(target_namespaces.iter().map(|ns| async move {
+            let rows = self.fetch_per_namespace(ns).await?;
+
+            anyhow::Ok(
+                rows.into_iter()
+                    .map(|row| row.into_kube_table_row(ns, insert_ns))
+                    .collect::<Vec<_>>(),
+            )
+        }))
+        .await?;
+
+        table.update_rows(jobs.into_iter().flatten().collect());
+
+        Ok(table)
+    }
+
+    async fn fetch_per_namespace(&self, ns: &str) -> Result<Vec<WorkloadRow>> {
+        let client = &self.base.kube_client;
+        let now = Utc::now();
+
+        let (deployments, statefulsets, daemonsets, replicasets, jobs, cronjobs) = futures::try_join!(
+            fetch_list::<Deployment>(client, ns),
+            fetch_list::<StatefulSet>(client, ns),
+            fetch_list::<DaemonSet>(client, ns),
+            fetch_list::<ReplicaSet>(client, ns),
+            fetch_list::<Job>(client, ns),
+            fetch_list::<CronJob>(client, ns),
+        )?;
+
+        let rows = deployments
+            .items
+            .iter()
+            .map(|r| WorkloadRow::from_deployment(r, now))
+            .chain(
+                statefulsets
+                    .items
+                    .iter()
+                    .map(|r| WorkloadRow::from_statefulset(r, now)),
+            )
+            .chain(
+                daemonsets
+                    .items
+                    .iter()
+                    .map(|r| WorkloadRow::from_daemonset(r, now)),
+            )
+            .chain(
+                replicasets
+                    .items
+                    .iter()
+                    .map(|r| WorkloadRow::from_replicaset(r, now)),
+            )
+            .chain(jobs.items.iter().map(|r| WorkloadRow::from_job(r, now)))
+            .chain(
+                cronjobs
+                    .items
+                    .iter()
+                    .map(|r| WorkloadRow::from_cronjob(r, now)),
+            )
+            .collect();
+
+        Ok(rows)
+    }
+}
+
+async fn fetch_list<K>(client: &KubeClient, ns: &str) -> Result<List<K>>
+where
+    K: Resource<DynamicType = ()> + k8s_openapi::ListableResource + DeserializeOwned + 'static,
+{
+    client.request(&K::url_path(&(), Some(ns))).await
+}
+
+/// ロールアウトの状態
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum RolloutStatus {
+    /// 期待する状態に収束している
+    Available(String),
+    /// ロールアウト中
+    Progressing(String),
+    /// 完了済み（Job, CronJobのsuspendなど）
+    Inactive(String),
+    /// 失敗
+    Failed(String),
+}
+
+impl RolloutStatus {
+    pub(super) fn color(&self) -> Option<Color> {
+        let theme = Theme::global();
+
+        match self {
+            Self::Available(_) => None,
+            Self::Progressing(_) => Some(theme.warning),
+            Self::Inactive(_) => Some(theme.muted),
+            Self::Failed(_) => Some(theme.error),
+        }
+    }
+
+    pub(super) fn message(&self) -> &str {
+        match self {
+            Self::Available(s) | Self::Progressing(s) | Self::Inactive(s) | Self::Failed(s) => s,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct WorkloadRow {
+    kind: WorkloadKind,
+    name: String,
+    desired: Option<i32>,
+    ready: Option<i32>,
+    updated: Option<i32>,
+    available: Option<i32>,
+    pub(super) status: RolloutStatus,
+    age: String,
+}
+
+fn count(n: Option<i32>) -> String {
+    n.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+impl WorkloadRow {
+    fn into_kube_table_row(self, ns: &str, insert_ns: bool) -> KubeTableRow {
+        let color = self.status.color();
+
+        let mut row = vec![
+            self.kind.to_string(),
+            self.name.to_string(),
+            count(self.desired),
+            count(self.ready),
+            count(self.updated),
+            count(self.available),
+            self.status.message().to_string(),
+            self.age,
+        ];
+
+        if insert_ns {
+            row.insert(0, ns.to_string());
+        }
+
+        if let Some(color) = color {
+            row.iter_mut().for_each(|r| *r = paint(color, &r))
+        }
+
+        KubeTableRow {
+            namespace: ns.to_string(),
+            name: self.name,
+            metadata: Some(BTreeMap::from([(
+                "kind".to_string(),
+                self.kind.to_string(),
+            )])),
+            row,
+        }
+    }
+
+    pub(super) fn from_deployment(deployment: &Deployment, now: DateTime<Utc>) -> Self {
+        let desired = deployment
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.replicas)
+            .unwrap_or(1);
+
+        let status = deployment.status.clone().unwrap_or_default();
+
+        let updated = status.updated_replicas.unwrap_or_default();
+        let available = status.available_replicas.unwrap_or_default();
+        let replicas = status.replicas.unwrap_or_default();
+
+        let conditions = status.conditions.unwrap_or_default();
+
+        let rollout_status = if deployment.metadata.generation > status.observed_generation {
+            RolloutStatus::Progressing("Waiting".into())
+        } else if let Some(cond) = conditions.iter().find(|c| {
+            c.type_ == "Progressing" && c.reason.as_deref() == Some("ProgressDeadlineExceeded")
+        }) {
+            RolloutStatus::Failed(cond.reason.clone().unwrap_or_default())
+        } else if updated < desired || updated < replicas || available < updated {
+            RolloutStatus::Progressing("Progressing".into())
+        } else if conditions
+            .iter()
+            .any(|c| c.type_ == "Available" && c.status == "False")
+        {
+            RolloutStatus::Failed("Unavailable".into())
+        } else {
+            RolloutStatus::Available("Available".into())
+        };
+
+        Self {
+            kind: WorkloadKind::Deployment,
+            name: deployment.name_any(),
+            desired: Some(desired),
+            ready: Some(status.ready_replicas.unwrap_or_default()),
+            updated: Some(updated),
+            available: Some(available),
+            status: rollout_status,
+            age: age(deployment.metadata.creation_timestamp.as_ref(), now),
+        }
+    }
+
+    pub(super) fn from_statefulset(statefulset: &StatefulSet, now: DateTime<Utc>) -> Self {
+        let desired = statefulset
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.replicas)
+            .unwrap_or(1);
+
+        let status = statefulset.status.clone().unwrap_or_default();
+
+        let ready = status.ready_replicas.unwrap_or_default();
+        let updated = status.updated_replicas.unwrap_or_default();
+
+        let rollout_status = if statefulset.metadata.generation > status.observed_generation {
+            RolloutStatus::Progressing("Waiting".into())
+        } else if status.update_revision.is_some()
+            && status.current_revision != status.update_revision
+            || ready < desired
+        {
+            RolloutStatus::Progressing("Progressing".into())
+        } else {
+            RolloutStatus::Available("Available".into())
+        };
+
+        Self {
+            kind: WorkloadKind::StatefulSet,
+            name: statefulset.name_any(),
+            desired: Some(desired),
+            ready: Some(ready),
+            updated: Some(updated),
+            available: status.available_replicas,
+            status: rollout_status,
+            age: age(statefulset.metadata.creation_timestamp.as_ref(), now),
+        }
+    }
+
+    pub(super) fn from_daemonset(daemonset: &DaemonSet, now: DateTime<Utc>) -> Self {
+        let status = daemonset.status.clone().unwrap_or_default();
+
+        let desired = status.desired_number_scheduled;
+        let updated = status.updated_number_scheduled.unwrap_or_default();
+        let available = status.number_available.unwrap_or_default();
+
+        let rollout_status = if daemonset.metadata.generation > status.observed_generation {
+            RolloutStatus::Progressing("Waiting".into())
+        } else if updated < desired || available < desired {
+            RolloutStatus::Progressing("Progressing".into())
+        } else {
+            RolloutStatus::Available("Available".into())
+        };
+
+        Self {
+            kind: WorkloadKind::DaemonSet,
+            name: daemonset.name_any(),
+            desired: Some(desired),
+            ready: Some(status.number_ready),
+            updated: Some(updated),
+            available: Some(available),
+            status: rollout_status,
+            age: age(daemonset.metadata.creation_timestamp.as_ref(), now),
+        }
+    }
+
+    pub(super) fn from_replicaset(replicaset: &ReplicaSet, now: DateTime<Utc>) -> Self {
+        let desired = replicaset
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.replicas)
+            .unwrap_or(1);
+
+        let status = replicaset.status.clone().unwrap_or_default();
+
+        let ready = status.ready_replicas.unwrap_or_default();
+
+        let rollout_status = if let Some(cond) = status
+            .conditions
+            .unwrap_or_default()
+            .iter()
+            .find(|c| c.type_ == "ReplicaFailure" && c.status == "True")
+        {
+            RolloutStatus::Failed(cond.reason.clone().unwrap_or_else(|| cond.type_.clone()))
+        } else if desired == 0 {
+            RolloutStatus::Inactive("ScaledDown".into())
+        } else if ready < desired {
+            RolloutStatus::Progressing("Progressing".into())
+        } else {
+            RolloutStatus::Available("Available".into())
+        };
+
+        Self {
+            kind: WorkloadKind::ReplicaSet,
+            name: replicaset.name_any(),
+            desired: Some(desired),
+            ready: Some(ready),
+            updated: None,
+            available: status.available_replicas,
+            status: rollout_status,
+            age: age(replicaset.metadata.creation_timestamp.as_ref(), now),
+        }
+    }
+
+    pub(super) fn from_job(job: &Job, now: DateTime<Utc>) -> Self {
+        let spec = job.spec.clone().unwrap_or_default();
+        let status = job.status.clone().unwrap_or_default();
+
+        let completions = spec.completions.unwrap_or(1);
+        let succeeded = status.succeeded.unwrap_or_default();
+
+        let conditions = status.conditions.unwrap_or_default();
+
+        let is_true = |type_: &str| {
+            conditions
+                .iter()
+                .find(|c| c.type_ == type_ && c.status == "True")
+        };
+
+        let rollout_status = if let Some(cond) = is_true("Failed") {
+            RolloutStatus::Failed(cond.reason.clone().unwrap_or_else(|| "Failed".into()))
+        } else if is_true("Complete").is_some() {
+            RolloutStatus::Inactive("Complete".into())
+        } else if is_true("Suspended").is_some() {
+            RolloutStatus::Inactive("Suspended".into())
+        } else {
+            RolloutStatus::Progressing(format!("Running {}/{}", succeeded, completions))
+        };
+
+        Self {
+            kind: WorkloadKind::Job,
+            name: job.name_any(),
+            desired: Some(completions),
+            ready: status.ready,
+            updated: None,
+            available: Some(succeeded),
+            status: rollout_status,
+            age: age(job.metadata.creation_timestamp.as_ref(), now),
+        }
+    }
+
+    pub(super) fn from_cronjob(cronjob: &CronJob, now: DateTime<Utc>) -> Self {
+        let suspend = cronjob
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.suspend)
+            .unwrap_or_default();
+
+        let status = cronjob.status.clone().unwrap_or_default();
+
+        let active = status.active.unwrap_or_default().len() as i32;
+
+        let rollout_status = if suspend {
+            RolloutStatus::Inactive("Suspended".into())
+        } else if 0 < active {
+            RolloutStatus::Progressing(format!("Active {}", active))
+        } else if let Some(last) = status.last_schedule_time.as_ref() {
+            RolloutStatus::Available(format!("Scheduled {} ago", age(Some(last), now)))
+        } else {
+            RolloutStatus::Available("Scheduled".into())
+        };
+
+        Self {
+            kind: WorkloadKind::CronJob,
+            name: cronjob.name_any(),
+            desired: None,
+            ready: Some(active),
+            updated: None,
+            available: None,
+            status: rollout_status,
+            age: age(cronjob.metadata.creation_timestamp.as_ref(), now),
+        }
+    }
+}
+
+/// kubectlと同じような形式で経過時間を返す
+pub fn age(timestamp: Option<&Time>, now: DateTime<Utc>) -> String {
+    let Some(Time(timestamp)) = timestamp else {
+        return "<unknown>".to_string();
+    };
+
+    let seconds = now.signed_duration_since(*timestamp).num_seconds().max(0);
+
+    let minutes = seconds / 60;
+    let hours = minutes / 60;
+    let days = hours / 24;
+
+    if seconds < 120 {
+        format!("{}s", seconds)
+    } else if minutes < 10 {
+        format!("{}m{}s", minutes, seconds % 60)
+    } else if hours < 3 {
+        format!("{}m", minutes)
+    } else if hours < 8 {
+        format!("{}h{}m", hours, minutes % 60)
+    } else if hours < 48 {
+        format!("{}h", hours)
+    } else if days < 8 {
+        format!("{}d{}h", days, hours % 24)
+    } else if days < 365 * 2 {
+        format!("{}d", days)
+    } else {
+        format!("{}y", days / 365)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::TimeZone as _;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    mod age {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case("2024-01-01T11:59:30Z", "30s")]
+        #[case("2024-01-01T11:55:00Z", "5m0s")]
+        #[case("2024-01-01T11:00:00Z", "60m")]
+        #[case("2024-01-01T07:30:00Z", "4h30m")]
+        #[case("2023-12-31T00:00:00Z", "36h")]
+        #[case("2023-12-29T06:00:00Z", "3d6h")]
+        #[case("2023-10-01T12:00:00Z", "92d")]
+        #[case("2021-01-01T12:00:00Z", "3y")]
+        fn 経過時間を返す(#[case] timestamp: &str, #[case] expected: &str) {
+            let timestamp: Time = serde_json::from_value(timestamp.into()).unwrap();
+
+            assert_eq!(age(Some(&timestamp), now()), expected);
+        }
+
+        #[test]
+        fn timestampがないときunknownを返す() {
+            assert_eq!(age(None, now()), "<unknown>");
+        }
+    }
+
+    mod deployment {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn ロールアウトが完了しているときavailableを返す() {
+            let deployment: Deployment = serde_yaml::from_str(indoc! {
+                r#"
+                metadata:
+                  name: app
+                  generation: 2
+                  creationTimestamp: "2024-01-01T11:00:00Z"
+                spec:
+                  replicas: 3
+                status:
+                  observedGeneration: 2
+                  replicas: 3
+                  readyReplicas: 3
+                  updatedReplicas: 3
+                  availableReplicas: 3
+                  conditions:
+                  - type: Available
+                    status: "True"
+                  - type: Progressing
+                    status: "True"
+                    reason: NewReplicaSetAvailable
+                "#
+            })
+            .unwrap();
+
+            let actual = WorkloadRow::from_deployment(&deployment, now());
+
+            assert_eq!(
+                actual,
+                WorkloadRow {
+                    kind: WorkloadKind::Deployment,
+                    name: "app".into(),
+                    desired: Some(3),
+                    ready: Some(3),
+                    updated: Some(3),
+                    available: Some(3),
+                    status: RolloutStatus::Available("Available".into()),
+                    age: "60m".into(),
+                }
+            );
+        }
+
+        #[test]
+        fn 更新中のレプリカがあるときprogressingを返す() {
+            let deployment: Deployment = serde_yaml::from_str(indoc! {
+                r#"
+                metadata:
+                  name: app
+                  generation: 3
+                spec:
+                  replicas: 3
+                status:
+                  observedGeneration: 3
+                  replicas: 4
+                  readyReplicas: 3
+                  updatedReplicas: 1
+                  availableReplicas: 3
+                "#
+            })
+            .unwrap();
+
+            let actual = WorkloadRow::from_deployment(&deployment, now());
+
+            assert_eq!(
+                actual.status,
+                RolloutStatus::Progressing("Progressing".into())
+            );
+        }
+
+        #[test]
+        fn progress_deadlineを超えたときfailedを返す() {
+            let deployment: Deployment = serde_yaml::from_str(indoc! {
+                r#"
+                metadata:
+                  name: app
+                  generation: 3
+                spec:
+                  replicas: 3
+                status:
+                  observedGeneration: 3
+                  replicas: 4
+                  updatedReplicas: 1
+                  conditions:
+                  - type: Progressing
+                    status: "False"
+                    reason: ProgressDeadlineExceeded
+                "#
+            })
+            .unwrap();
+
+            let actual = WorkloadRow::from_deployment(&deployment, now());
+
+            assert_eq!(
+                actual.status,
+                RolloutStatus::Failed("ProgressDeadlineExceeded".into())
+            );
+        }
+    }
+
+    mod job {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn 完了したjobはcompleteを返す() {
+            let job: Job = serde_yaml::from_str(indoc! {
+                r#"
+                metadata:
+                  name: job
+                spec:
+                  completions: 2
+                  template: {}
+                status:
+                  succeeded: 2
+                  conditions:
+                  - type: Complete
+                    status: "True"
+                "#
+            })
+            .unwrap();
+
+            let actual = WorkloadRow::from_job(&job, now());
+
+            assert_eq!(actual.status, RolloutStatus::Inactive("Complete".into()));
+            assert_eq!(actual.available, Some(2));
+        }
+
+        #[test]
+        fn 実行中のjobは進捗を返す() {
+            let job: Job = serde_yaml::from_str(indoc! {
+                r#"
+                metadata:
+                  name: job
+                spec:
+                  completions: 3
+                  template: {}
+                status:
+                  active: 1
+                  succeeded: 1
+                "#
+            })
+            .unwrap();
+
+            let actual = WorkloadRow::from_job(&job, now());
+
+            assert_eq!(
+                actual.status,
+                RolloutStatus::Progressing("Running 1/3".into())
+            );
+        }
+    }
+
+    #[test]
+    fn 複数namespaceのときnamespace列を先頭に追加する() {
+        let row = WorkloadRow {
+            kind: WorkloadKind::CronJob,
+            name: "cron".into(),
+            desired: None,
+            ready: Some(0),
+            updated: None,
+            available: None,
+            status: RolloutStatus::Available("Scheduled".into()),
+            age: "1d".into(),
+        };
+
+        let actual = row.into_kube_table_row("default", true);
+
+        assert_eq!(
+            actual.row,
+            vec![
+                "default",
+                "CronJob",
+                "cron",
+                "-",
+                "0",
+                "-",
+                "-",
+                "Scheduled",
+                "1d"
+            ]
+        );
+        assert_eq!(
+            actual.metadata,
+            Some(BTreeMap::from([(
+                "kind".to_string(),
+                "CronJob".to_string()
+            )]))
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Kube};
+
+pub use super::kube::{OwnerTreeNode, OwnerTreeTarget};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkloadKind {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    ReplicaSet,
+    Job,
+    CronJob,
+}
+
+impl WorkloadKind {
+    pub fn from_kind(kind: &str) -> Option<Self> {
+        match kind {
+            "Deployment" => Some(Self::Deployment),
+            "StatefulSet" => Some(Self::StatefulSet),
+            "DaemonSet" => Some(Self::DaemonSet),
+            "ReplicaSet" => Some(Self::ReplicaSet),
+            "Job" => Some(Self::Job),
+            "CronJob" => Some(Self::CronJob),
+            _ => None,
+        }
+    }
+
+    /// ログクエリで利用できるリソース名
+    pub fn log_query_resource(&self) -> Option<&'static str> {
+        match self {
+            Self::Deployment => Some("deploy"),
+            Self::StatefulSet => Some("sts"),
+            Self::DaemonSet => Some("ds"),
+            Self::ReplicaSet => Some("rs"),
+            Self::Job => Some("job"),
+            Self::CronJob => None,
+        }
+    }
+}
+
+impl Display for WorkloadKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Deployment => write!(f, "Deployment"),
+            Self::StatefulSet => write!(f, "StatefulSet"),
+            Self::DaemonSet => write!(f, "DaemonSet"),
+            Self::ReplicaSet => write!(f, "ReplicaSet"),
+            Self::Job => write!(f, "Job"),
+            Self::CronJob => write!(f, "CronJob"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkloadTarget {
+    pub kind: WorkloadKind,
+    pub name: String,
+    pub namespace: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum WorkloadRequest {
+    Description(WorkloadTarget),
+    OwnerTree(OwnerTreeTarget),
+}
+
+#[derive(Debug)]
+pub enum WorkloadResponse {
+    List(Result<KubeTable>),
+    Description(Result<Vec<String>>),
+    OwnerTree(Result<Vec<OwnerTreeNode>>),
+}
+
+#[derive(Debug)]
+pub enum WorkloadMessage {
+    Request(WorkloadRequest),
+    Response(WorkloadResponse),
+}
+
+impl From<WorkloadMessage> for Kube {
+    fn from(m: WorkloadMessage) -> Self {
+        Self::Workload(m)
+    }
+}
+
+impl From<WorkloadMessage> for Message {
+    fn from(m: WorkloadMessage) -> Self {
+        Self::Kube(m.into())
+    }
+}
+
+impl From<WorkloadRequest> for Message {
+    fn from(req: WorkloadRequest) -> Self {
+        WorkloadMessage::Request(req).into()
+    }
+}
+
+impl From<WorkloadTarget> for Message {
+    fn from(target: WorkloadTarget) -> Self {
+        WorkloadRequest::Description(target).into()
+    }
+}
+
+impl From<OwnerTreeTarget> for Message {
+    fn from(target: OwnerTreeTarget) -> Self {
+        WorkloadRequest::OwnerTree(target).into()
+    }
+}
+
+impl From<WorkloadResponse> for Message {
+    fn from(res: WorkloadResponse) -> Self {
+        WorkloadMessage::Response(res).into()
+    }
+}
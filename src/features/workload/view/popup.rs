@@ -0,0 +1,22 @@
+use crate::{
+    features::component_id::OWNER_TREE_POPUP_ID,
+    ui::widget::{config::WidgetConfig, Tree, Widget},
+};
+
+pub struct OwnerTreePopup {
+    pub popup: Widget<'static>,
+}
+
+impl OwnerTreePopup {
+    pub fn new() -> Self {
+        Self { popup: popup() }
+    }
+}
+
+fn popup() -> Widget<'static> {
+    Tree::builder()
+        .id(OWNER_TREE_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Owner Tree").build())
+        .build()
+        .into()
+}
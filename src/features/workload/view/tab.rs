@@ -0,0 +1,52 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::layout::{Constraint, Direction};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::WORKLOAD_TAB_ID,
+    message::Message,
+    ui::{
+        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
+        Tab,
+    },
+};
+
+use super::widgets::{description_widget, workload_widget};
+
+pub struct WorkloadTab {
+    pub tab: Tab<'static>,
+}
+
+impl WorkloadTab {
+    pub fn new(
+        title: &'static str,
+        tx: &Sender<Message>,
+        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        split_mode: Direction,
+    ) -> Self {
+        let workload_widget = workload_widget(tx);
+        let description_widget = description_widget(clipboard);
+
+        let layout = layout(split_mode);
+
+        WorkloadTab {
+            tab: Tab::new(
+                WORKLOAD_TAB_ID,
+                title,
+                [workload_widget, description_widget],
+                layout,
+            ),
+        }
+    }
+}
+
+fn layout(split_direction: Direction) -> NestedWidgetLayout {
+    NestedWidgetLayout::default()
+        .direction(split_direction)
+        .nested_widget_layout([
+            NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(0)),
+            NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(1)),
+        ])
+}
@@ -0,0 +1,92 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{
+            POD_LOG_QUERY_WIDGET_ID, WORKLOAD_DESCRIPTION_WIDGET_ID, WORKLOAD_WIDGET_ID,
+        },
+        workload::message::{WorkloadKind, WorkloadTarget},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, Item, Table, TableItem, Widget, WidgetTrait as _},
+        Window, WindowAction,
+    },
+};
+
+pub fn workload_widget(tx: &Sender<Message>) -> Widget<'static> {
+    let tx = tx.clone();
+
+    Table::builder()
+        .id(WORKLOAD_WIDGET_ID)
+        .widget_config(&WidgetConfig::builder().title("Workload").build())
+        .filtered_key("NAME")
+        .block_injection(block_injection())
+        .on_select(on_select(tx))
+        .build()
+        .into()
+}
+
+fn block_injection() -> impl Fn(&Table) -> WidgetConfig {
+    |table: &Table| {
+        let index = if let Some(index) = table.state().selected() {
+            index + 1
+        } else {
+            0
+        };
+
+        let mut widget_config = table.widget_config().clone();
+
+        *widget_config.append_title_mut() =
+            Some(format!(" [{}/{}]", index, table.items().len()).into());
+
+        widget_config
+    }
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        w.widget_clear(WORKLOAD_DESCRIPTION_WIDGET_ID);
+
+        let Some(metadata) = v.metadata.as_ref() else {
+            return EventResult::Ignore;
+        };
+
+        let Some(namespace) = metadata.get("namespace") else {
+            return EventResult::Ignore;
+        };
+
+        let Some(name) = metadata.get("name") else {
+            return EventResult::Ignore;
+        };
+
+        let Some(kind) = metadata
+            .get("kind")
+            .and_then(|kind| WorkloadKind::from_kind(kind))
+        else {
+            return EventResult::Ignore;
+        };
+
+        *(w.find_widget_mut(WORKLOAD_DESCRIPTION_WIDGET_ID)
+            .widget_config_mut()
+            .append_title_mut()) = Some((format!(" : {}/{}", kind, name)).into());
+
+        if let Some(resource) = kind.log_query_resource() {
+            w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+                .update_widget_item(Item::Single(format!("{}/{}", resource, name).into()));
+        }
+
+        tx.send(
+            WorkloadTarget {
+                kind,
+                name: name.to_string(),
+                namespace: namespace.to_string(),
+            }
+            .into(),
+        )
+        .expect("Failed to send WorkloadRequest::Description");
+
+        EventResult::WindowAction(WindowAction::Continue)
+    }
+}
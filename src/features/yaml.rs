@@ -1,3 +1,8 @@
+pub mod diff;
 pub mod kube;
-pub mod view;
 pub mod message;
+pub mod neat;
+pub mod output;
+pub mod revision;
+pub mod save;
+pub mod view;
@@ -0,0 +1,490 @@
+use anyhow::Result;
+use chrono::Local;
+
+use std::{cell::RefCell, rc::Rc};
+
+use crate::ui::{
+    event::EventResult,
+    highlight::highlight_yaml,
+    theme::{paint, Theme},
+    widget::{Item, LiteralItem, WidgetTrait as _},
+    Window,
+};
+
+use super::{
+    output::Output,
+    revision::{YamlContent, YamlRevision},
+};
+
+/// 差分の前後に表示する行数
+pub const CONTEXT_LINES: usize = 3;
+
+/// yamlの表示と過去のリビジョンとの差分表示を切り替える
+///
+/// 表示の設定はビューごとに持ち、表示するときに取得したリソースを変換する
+#[derive(Debug, Default)]
+pub struct YamlDiff {
+    content: YamlContent,
+    /// 比較元のリビジョンのresourceVersion（Noneのときはyamlをそのまま表示する）
+    base: Option<String>,
+    output: Output,
+}
+
+impl YamlDiff {
+    /// 比較元のリビジョンがなくなったとき（別のリソースを選択したときなど）は差分表示をやめる
+    pub fn update(&mut self, content: YamlContent) {
+        self.content = content;
+
+        if self.base_index().is_none() {
+            self.base = None;
+        }
+    }
+
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    pub fn output_mut(&mut self) -> &mut Output {
+        &mut self.output
+    }
+
+    /// 表示中のリソース
+    pub fn object(&self) -> Option<&serde_yaml::Value> {
+        self.content.object.as_ref()
+    }
+
+    pub fn is_diff(&self) -> bool {
+        self.base.is_some()
+    }
+
+    /// 比較元を1つ古いリビジョンにする
+    pub fn older(&mut self) {
+        let revisions = &self.content.revisions;
+
+        let index = match self.base_index() {
+            Some(index) => Some(index.saturating_sub(1)),
+            None => revisions.len().checked_sub(2),
+        };
+
+        self.base = index.map(|i| revisions[i].resource_version.clone());
+    }
+
+    /// 比較元を1つ新しいリビジョンにして、最新まで進んだら差分表示をやめる
+    pub fn newer(&mut self) {
+        let revisions = &self.content.revisions;
+
+        self.base = self
+            .base_index()
+            .map(|index| index + 1)
+            .filter(|index| index + 1 < revisions.len())
+            .map(|index| revisions[index].resource_version.clone());
+    }
+
+    /// 差分表示中のタイトル
+    pub fn title(&self) -> Option<String> {
+        let (base, latest) = self.compared()?;
+
+        Some(format!(
+            " (diff {}/{}: {} -> {}, [: older, ]: newer)",
+            self.base_index()? + 1,
+            self.content.revisions.len() - 1,
+            base.resource_version,
+            latest.resource_version
+        ))
+    }
+
+    /// 現在の表示の設定でリソース（差分表示中は比較する2つのリビジョン）を変換する
+    pub fn lines(&self) -> Result<Vec<String>> {
+        let Some((base, latest)) = self.compared() else {
+            let Some(object) = &self.content.object else {
+                return Ok(self.content.events.clone());
+            };
+
+            let lines = self.output.render(object)?;
+
+            let lines = if self.output.is_yaml() {
+                highlight_yaml(&lines)
+            } else {
+                lines
+            };
+
+            return Ok(lines
+                .into_iter()
+                .chain(self.content.events.iter().cloned())
+                .collect());
+        };
+
+        let theme = Theme::global();
+
+        let header = [
+            format!("--- {}", revision_label(base)),
+            format!("+++ {}", revision_label(latest)),
+        ];
+
+        let diff = unified_diff(
+            &self.output.render(&base.object)?,
+            &self.output.render(&latest.object)?,
+            CONTEXT_LINES,
+        );
+
+        Ok(header
+            .into_iter()
+            .map(|line| paint(theme.muted, line))
+            .chain(diff.into_iter().map(paint_diff_line))
+            .collect())
+    }
+
+    fn base_index(&self) -> Option<usize> {
+        let base = self.base.as_ref()?;
+
+        self.content
+            .revisions
+            .iter()
+            .position(|r| &r.resource_version == base)
+    }
+
+    fn compared(&self) -> Option<(&YamlRevision, &YamlRevision)> {
+        let base = self.content.revisions.get(self.base_index()?)?;
+        let latest = self.content.revisions.last()?;
+
+        Some((base, latest))
+    }
+}
+
+/// yamlまたは差分をウィジェットに反映する
+pub fn update_yaml_widget(w: &mut Window, id: &str, diff: &YamlDiff) {
+    let lines = diff.lines().unwrap_or_else(|err| {
+        format!("{:?}", err)
+            .lines()
+            .map(|line| paint(Theme::global().error, line))
+            .collect()
+    });
+
+    w.find_widget_mut(id).update_widget_item(Item::Array(
+        lines.into_iter().map(LiteralItem::from).collect(),
+    ));
+}
+
+/// 比較元を1つ古いリビジョンにして差分を表示する
+pub fn older_revision(
+    diff: Rc<RefCell<YamlDiff>>,
+    id: &'static str,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut diff = diff.borrow_mut();
+
+        diff.older();
+
+        update_yaml_widget(w, id, &diff);
+
+        EventResult::Nop
+    }
+}
+
+/// 比較元を1つ新しいリビジョンにして、最新まで進んだらyamlの表示に戻る
+pub fn newer_revision(
+    diff: Rc<RefCell<YamlDiff>>,
+    id: &'static str,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut diff = diff.borrow_mut();
+
+        if !diff.is_diff() {
+            return EventResult::Ignore;
+        }
+
+        diff.newer();
+
+        update_yaml_widget(w, id, &diff);
+
+        EventResult::Nop
+    }
+}
+
+/// クリーンモードを切り替える（取得し直さずに表示中のリソースを変換し直す）
+pub fn toggle_neat_mode(
+    diff: Rc<RefCell<YamlDiff>>,
+    id: &'static str,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut diff = diff.borrow_mut();
+
+        diff.output_mut().toggle_neat();
+
+        update_yaml_widget(w, id, &diff);
+
+        EventResult::Nop
+    }
+}
+
+/// YAMLとJSONを切り替える
+pub fn toggle_json_mode(
+    diff: Rc<RefCell<YamlDiff>>,
+    id: &'static str,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut diff = diff.borrow_mut();
+
+        diff.output_mut().toggle_json();
+
+        update_yaml_widget(w, id, &diff);
+
+        EventResult::Nop
+    }
+}
+
+/// 差分の行を追加と削除で色分けする
+pub fn paint_diff_line(line: String) -> String {
+    let theme = Theme::global();
+
+    match line.chars().next() {
+        Some('-') => paint(theme.error, line),
+        Some('+') => paint(theme.success, line),
+        Some('@') => paint(theme.info, line),
+        _ => line,
+    }
+}
+
+fn revision_label(revision: &YamlRevision) -> String {
+    format!(
+        "resourceVersion: {} ({})",
+        revision.resource_version,
+        revision.time.with_timezone(&Local).format("%H:%M:%S")
+    )
+}
+
+/// diff -u と同じ形式の差分
+pub fn unified_diff(old: &[String], new: &[String], context: usize) -> Vec<String> {
+    use diff::Result::{Both, Left, Right};
+
+    let ops = diff::slice(old, new);
+
+    // 各操作の直前の行番号
+    let mut positions = Vec::with_capacity(ops.len());
+    let (mut old_line, mut new_line) = (0, 0);
+
+    for op in &ops {
+        positions.push((old_line, new_line));
+
+        match op {
+            Left(_) => old_line += 1,
+            Right(_) => new_line += 1,
+            Both(..) => {
+                old_line += 1;
+                new_line += 1;
+            }
+        }
+    }
+
+    // 前後の行を含めて重なる変更を1つのハンクにまとめる
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+
+    for (i, _) in ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, Both(..)))
+    {
+        let start = i.saturating_sub(context);
+        let end = (i + 1 + context).min(ops.len());
+
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let range = |start: usize, len: usize| match len {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, len),
+    };
+
+    hunks
+        .into_iter()
+        .flat_map(|(start, end)| {
+            let ops = &ops[start..end];
+            let (old_start, new_start) = positions[start];
+
+            let old_len = ops.iter().filter(|op| !matches!(op, Right(_))).count();
+            let new_len = ops.iter().filter(|op| !matches!(op, Left(_))).count();
+
+            std::iter::once(format!(
+                "@@ -{} +{} @@",
+                range(old_start, old_len),
+                range(new_start, new_len)
+            ))
+            .chain(ops.iter().map(|op| match op {
+                Left(line) => format!("-{}", line),
+                Right(line) => format!("+{}", line),
+                Both(line, _) => format!(" {}", line),
+            }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone as _, Utc};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn lines(s: &str) -> Vec<String> {
+        s.lines().map(ToString::to_string).collect()
+    }
+
+    fn revision(resource_version: &str, yaml: &str) -> YamlRevision {
+        YamlRevision {
+            resource_version: resource_version.into(),
+            time: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            object: serde_yaml::from_str(yaml).unwrap(),
+        }
+    }
+
+    #[test]
+    fn 変更の前後3行を含めてハンクにまとめる() {
+        let old = lines(indoc! {"
+            a
+            b
+            c
+            d
+            e
+            f
+            g
+            h
+            i
+            j
+            k
+            l
+        "});
+
+        let new = lines(indoc! {"
+            a
+            B
+            c
+            d
+            e
+            f
+            g
+            h
+            i
+            j
+            l
+            m
+        "});
+
+        let actual = unified_diff(&old, &new, 3);
+
+        assert_eq!(
+            actual,
+            lines(indoc! {"
+                @@ -1,5 +1,5 @@
+                 a
+                -b
+                +B
+                 c
+                 d
+                 e
+                @@ -8,5 +8,5 @@
+                 h
+                 i
+                 j
+                -k
+                 l
+                +m
+            "})
+        );
+    }
+
+    #[test]
+    fn 差分がないときは何も返さない() {
+        let yaml = lines("a\nb");
+
+        assert_eq!(unified_diff(&yaml, &yaml, 3), Vec::<String>::new());
+    }
+
+    #[test]
+    fn 比較元を古いリビジョンに移動して最新まで進むと差分表示をやめる() {
+        let mut diff = YamlDiff::default();
+
+        diff.update(YamlContent {
+            object: Some("current".into()),
+            revisions: vec![
+                revision("1", "replicas: 1"),
+                revision("2", "replicas: 2"),
+                revision("3", "replicas: 3"),
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(diff.lines().unwrap(), highlight_yaml(&["current"]));
+
+        diff.older();
+        diff.older();
+        diff.older();
+
+        assert_eq!(
+            diff.title().as_deref(),
+            Some(" (diff 1/2: 1 -> 3, [: older, ]: newer)")
+        );
+
+        diff.newer();
+
+        assert_eq!(
+            diff.title().as_deref(),
+            Some(" (diff 2/2: 2 -> 3, [: older, ]: newer)")
+        );
+
+        diff.newer();
+
+        assert!(!diff.is_diff());
+    }
+
+    #[test]
+    fn 比較元のリビジョンがなくなったら差分表示をやめる() {
+        let mut diff = YamlDiff::default();
+
+        diff.update(YamlContent {
+            object: None,
+            revisions: vec![revision("1", "a"), revision("2", "b")],
+            ..Default::default()
+        });
+
+        diff.older();
+
+        assert!(diff.is_diff());
+
+        diff.update(YamlContent {
+            object: None,
+            revisions: vec![revision("10", "x")],
+            ..Default::default()
+        });
+
+        assert!(!diff.is_diff());
+    }
+
+    #[test]
+    fn 表示の設定を変えると差分も変換し直す() {
+        let mut diff = YamlDiff::default();
+
+        diff.update(YamlContent {
+            object: Some(serde_yaml::from_str("spec:\n  replicas: 2").unwrap()),
+            events: vec!["events".into()],
+            revisions: vec![
+                revision("1", "spec:\n  replicas: 1"),
+                revision("2", "spec:\n  replicas: 2"),
+            ],
+        });
+
+        diff.output_mut().set_json_path(".spec.replicas").unwrap();
+
+        assert_eq!(diff.lines().unwrap(), lines("2\nevents"));
+
+        diff.older();
+
+        assert_eq!(
+            diff.lines().unwrap()[2..],
+            ["@@ -1 +1 @@", "-1", "+2"].map(|line| paint_diff_line(line.into()))
+        );
+    }
+}
@@ -1,5 +1,7 @@
+mod bundle;
 mod fetch;
 mod worker;
 
+pub use bundle::*;
 pub use fetch::*;
 pub use worker::*;
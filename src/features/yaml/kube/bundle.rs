@@ -0,0 +1,159 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use serde_yaml::{Mapping, Value};
+
+use crate::{
+    features::{
+        api_resources::kube::ApiResource,
+        yaml::save::{to_bundle, write},
+    },
+    kube::KubeClientRequest,
+    logger,
+};
+
+/// Listタブで選択しているリソースをすべて取得し、再適用できる形式のマルチドキュメントで保存する
+pub async fn save_bundle<C: KubeClientRequest>(
+    client: &C,
+    apis: &[ApiResource],
+    namespaces: &[String],
+    path: PathBuf,
+    overwrite: bool,
+) -> Result<PathBuf> {
+    if apis.is_empty() {
+        bail!("No API resources are selected in the List tab");
+    }
+
+    let objects = fetch_objects(client, apis, namespaces).await?;
+
+    logger!(
+        info,
+        "Saving {} resources to {}",
+        objects.len(),
+        path.display()
+    );
+
+    write(path, &to_bundle(&objects, true)?, overwrite)
+}
+
+async fn fetch_objects<C: KubeClientRequest>(
+    client: &C,
+    apis: &[ApiResource],
+    namespaces: &[String],
+) -> Result<Vec<Value>> {
+    let mut objects = Vec::new();
+
+    for api in apis {
+        let paths = if api.is_namespaced() {
+            namespaces
+                .iter()
+                .map(|ns| api.api_url_with_namespace(ns))
+                .collect()
+        } else {
+            vec![api.api_url()]
+        };
+
+        for path in paths {
+            let list: Value = client.request(&path).await?;
+
+            let Some(items) = list["items"].as_sequence() else {
+                continue;
+            };
+
+            objects.extend(items.iter().map(|item| to_object(api, item)));
+        }
+    }
+
+    Ok(objects)
+}
+
+/// リストの要素にはapiVersionとkindが含まれないため先頭に付け足す
+fn to_object(api: &ApiResource, item: &Value) -> Value {
+    let mut object = Mapping::from_iter([
+        ("apiVersion".into(), api.api_version().into()),
+        ("kind".into(), api.kind().into()),
+    ]);
+
+    if let Value::Mapping(item) = item {
+        object.extend(item.clone());
+    }
+
+    if let Some(Value::Mapping(metadata)) = object.get_mut("metadata") {
+        metadata.remove("managedFields");
+    }
+
+    Value::Mapping(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use kube::discovery::Scope;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    fn yaml(s: &str) -> Value {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    #[tokio::test]
+    async fn 選択したリソースをnamespaceごとに取得してkindを付け足す() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Value,
+                    eq("apis/apps/v1/namespaces/a/deployments"),
+                    Ok(yaml(indoc! {"
+                        kind: DeploymentList
+                        items:
+                          - metadata:
+                              name: app
+                              namespace: a
+                              managedFields: []
+                            spec:
+                              replicas: 1
+                    "}))
+                ),
+                (
+                    Value,
+                    eq("apis/apps/v1/namespaces/b/deployments"),
+                    Ok(yaml("{kind: DeploymentList, items: []}"))
+                )
+            ]
+        );
+
+        let apis = [ApiResource::Apis {
+            name: "deployments".into(),
+            kind: "Deployment".into(),
+            group: "apps".into(),
+            version: "v1".into(),
+            preferred_version: true,
+            scope: Scope::Namespaced,
+        }];
+
+        let actual = fetch_objects(&client, &apis, &["a".into(), "b".into()])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actual,
+            vec![yaml(indoc! {"
+                apiVersion: apps/v1
+                kind: Deployment
+                metadata:
+                  name: app
+                  namespace: a
+                spec:
+                  replicas: 1
+            "})]
+        );
+    }
+}
@@ -1,18 +1,26 @@
 use std::sync::{atomic::AtomicBool, Arc};
 
 use anyhow::{anyhow, Result};
+use chrono::Utc;
 use crossbeam::channel::Sender;
 use serde_yaml::Value;
 
 use crate::{
     features::{
         api_resources::kube::{ApiResource, ApiResources, SharedApiResources},
-        yaml::message::YamlResponse,
+        event::kube::{related_events, InvolvedObject},
+        yaml::{
+            message::YamlResponse,
+            revision::{YamlContent, YamlRevisions},
+        },
     },
     kube::KubeClientRequest,
     logger,
     message::Message,
-    workers::kube::AbortWorker,
+    workers::{
+        interval::{AdaptiveInterval, IntervalConfig},
+        kube::AbortWorker,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -55,7 +63,7 @@ impl<C: KubeClientRequest> YamlWorker<C> {
 #[async_trait::async_trait]
 impl<C: KubeClientRequest> AbortWorker for YamlWorker<C> {
     async fn run(&self) {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3));
+        let mut interval = AdaptiveInterval::new(IntervalConfig::global().description);
 
         let YamlTarget {
             kind,
@@ -63,6 +71,8 @@ impl<C: KubeClientRequest> AbortWorker for YamlWorker<C> {
             namespace,
         } = &self.req;
 
+        let mut revisions = YamlRevisions::default();
+
         while !self
             .is_terminated
             .load(std::sync::atomic::Ordering::Relaxed)
@@ -77,9 +87,12 @@ impl<C: KubeClientRequest> AbortWorker for YamlWorker<C> {
                 kind,
                 name.to_string(),
                 namespace.to_string(),
+                &mut revisions,
             )
             .await;
 
+            interval.record(&fetched_data);
+
             self.tx
                 .send(YamlResponse::Yaml(fetched_data).into())
                 .expect("Failed to send YamlResponse::Yaml");
@@ -87,14 +100,15 @@ impl<C: KubeClientRequest> AbortWorker for YamlWorker<C> {
     }
 }
 
-/// 選択されているリソースのyamlを取得する
-async fn fetch_resource_yaml<C: KubeClientRequest>(
+/// 選択されているリソースのyamlを取得してリビジョンを記録する
+pub async fn fetch_resource_yaml<C: KubeClientRequest>(
     client: &C,
     api_resources: &ApiResources,
     kind: &ApiResource,
     name: String,
     ns: String,
-) -> Result<Vec<String>> {
+    revisions: &mut YamlRevisions,
+) -> Result<YamlContent> {
     logger!(
         info,
         "Fetching resource target [kind={} ns={} name={}]",
@@ -133,10 +147,18 @@ async fn fetch_resource_yaml<C: KubeClientRequest>(
         md.remove("managedFields");
     }
 
-    let yaml_string = serde_yaml::to_string(&yaml_data)?
-        .lines()
-        .map(ToString::to_string)
-        .collect();
+    // 関連するイベントは差分に含めない
+    revisions.record(
+        yaml_data["metadata"]["resourceVersion"].as_str(),
+        &yaml_data,
+        Utc::now(),
+    );
+
+    let uid = yaml_data["metadata"]["uid"].as_str();
+    let kind = yaml_data["kind"].as_str().unwrap_or_default();
+    let namespace = api.is_namespaced().then_some(ns.as_str());
+
+    let events = related_events(client, namespace, InvolvedObject::new(uid, kind, &name)).await;
 
-    Ok(yaml_string)
+    Ok(revisions.content(events).with_object(yaml_data))
 }
@@ -1,9 +1,13 @@
+use std::path::PathBuf;
+
 use anyhow::Result;
 
 use crate::{
     features::api_resources::kube::ApiResource, message::Message, workers::kube::message::Kube,
 };
 
+use super::revision::YamlContent;
+
 pub use super::kube::YamlTarget;
 
 #[derive(Debug, Clone)]
@@ -30,6 +34,11 @@ pub enum YamlRequest {
     APIs,
     Resource(ApiResource),
     Yaml(YamlTarget),
+    /// Listタブで選択しているリソースをまとめて保存する
+    SaveBundle {
+        path: PathBuf,
+        overwrite: bool,
+    },
 }
 
 impl From<YamlRequest> for Message {
@@ -42,7 +51,8 @@ impl From<YamlRequest> for Message {
 pub enum YamlResponse {
     APIs(Result<Vec<ApiResource>>),
     Resource(Result<YamlResourceList>),
-    Yaml(Result<Vec<String>>),
+    Yaml(Result<YamlContent>),
+    Saved(Result<PathBuf>),
 }
 
 impl From<YamlResponse> for Message {
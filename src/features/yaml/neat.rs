@@ -0,0 +1,371 @@
+use indoc::indoc;
+use once_cell::sync::Lazy;
+use serde_yaml::{Mapping, Value};
+
+/// サーバーが付与するメタデータ
+const SERVER_METADATA: [&str; 9] = [
+    "managedFields",
+    "uid",
+    "resourceVersion",
+    "creationTimestamp",
+    "generation",
+    "selfLink",
+    "deletionTimestamp",
+    "deletionGracePeriodSeconds",
+    "generateName",
+];
+
+/// サーバーやコントローラーが付与するアノテーション
+const SERVER_ANNOTATIONS: [&str; 2] = [
+    "kubectl.kubernetes.io/last-applied-configuration",
+    "deployment.kubernetes.io/revision",
+];
+
+/// ServiceAccountのトークンとしてadmissionが追加するボリュームの接頭辞
+const SERVICE_ACCOUNT_VOLUME_PREFIX: &str = "kube-api-access-";
+
+/// 省略したときにサーバーが設定する値
+///
+/// kindごとのspec、PodSpec、コンテナ、プローブの既定値で、同じ値のときだけ削除する
+static DEFAULTS: Lazy<Mapping> = Lazy::new(|| {
+    serde_yaml::from_str(indoc! {"
+        Deployment:
+          progressDeadlineSeconds: 600
+          revisionHistoryLimit: 10
+          strategy:
+            type: RollingUpdate
+            rollingUpdate:
+              maxSurge: 25%
+              maxUnavailable: 25%
+        StatefulSet:
+          podManagementPolicy: OrderedReady
+          revisionHistoryLimit: 10
+          updateStrategy:
+            type: RollingUpdate
+            rollingUpdate:
+              partition: 0
+          persistentVolumeClaimRetentionPolicy:
+            whenDeleted: Retain
+            whenScaled: Retain
+        DaemonSet:
+          revisionHistoryLimit: 10
+          updateStrategy:
+            type: RollingUpdate
+            rollingUpdate:
+              maxSurge: 0
+              maxUnavailable: 1
+        Service:
+          type: ClusterIP
+          sessionAffinity: None
+          ipFamilyPolicy: SingleStack
+          internalTrafficPolicy: Cluster
+        PodSpec:
+          dnsPolicy: ClusterFirst
+          restartPolicy: Always
+          schedulerName: default-scheduler
+          securityContext: {}
+          terminationGracePeriodSeconds: 30
+          enableServiceLinks: true
+          preemptionPolicy: PreemptLowerPriority
+          priority: 0
+        Container:
+          terminationMessagePath: /dev/termination-log
+          terminationMessagePolicy: File
+          resources: {}
+        Probe:
+          timeoutSeconds: 1
+          periodSeconds: 10
+          successThreshold: 1
+          failureThreshold: 3
+    "})
+    .expect("Failed to parse neat defaults")
+});
+
+/// ステータスやサーバーが付与したフィールド、既定値を削除して再適用できるマニフェストにする
+pub fn neat(value: &mut Value) {
+    let Value::Mapping(root) = value else {
+        return;
+    };
+
+    root.remove("status");
+
+    if let Some(Value::Mapping(metadata)) = root.get_mut("metadata") {
+        neat_metadata(metadata);
+    }
+
+    let kind = root
+        .get("kind")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    if let Some(Value::Mapping(spec)) = root.get_mut("spec") {
+        remove_defaults(spec, &kind);
+
+        if kind == "Service" {
+            neat_service_spec(spec);
+        }
+    }
+
+    walk(value);
+}
+
+fn neat_metadata(metadata: &mut Mapping) {
+    for key in SERVER_METADATA {
+        metadata.remove(key);
+    }
+
+    if let Some(Value::Mapping(annotations)) = metadata.get_mut("annotations") {
+        for key in SERVER_ANNOTATIONS {
+            annotations.remove(key);
+        }
+    }
+
+    remove_if_empty(metadata, "annotations");
+}
+
+/// クラスタが割り当てたIPを削除する（ヘッドレスサービスのNoneは残す）
+fn neat_service_spec(spec: &mut Mapping) {
+    if spec.get("clusterIP").and_then(Value::as_str) != Some("None") {
+        spec.remove("clusterIP");
+        spec.remove("clusterIPs");
+    }
+
+    spec.remove("ipFamilies");
+
+    if let Some(Value::Sequence(ports)) = spec.get_mut("ports") {
+        for port in ports.iter_mut().filter_map(Value::as_mapping_mut) {
+            if port.get("protocol").and_then(Value::as_str) == Some("TCP") {
+                port.remove("protocol");
+            }
+        }
+    }
+}
+
+/// PodSpecを探して既定値とadmissionが追加したボリュームを削除する
+fn walk(value: &mut Value) {
+    match value {
+        Value::Mapping(map) => {
+            if map.get("creationTimestamp") == Some(&Value::Null) {
+                map.remove("creationTimestamp");
+            }
+
+            if map.get("containers").is_some_and(Value::is_sequence) {
+                neat_pod_spec(map);
+            }
+
+            map.iter_mut().for_each(|(_, v)| walk(v));
+        }
+        Value::Sequence(seq) => seq.iter_mut().for_each(walk),
+        _ => {}
+    }
+}
+
+fn neat_pod_spec(spec: &mut Mapping) {
+    remove_defaults(spec, "PodSpec");
+
+    spec.remove("nodeName");
+
+    if spec.get("serviceAccount") == spec.get("serviceAccountName") {
+        spec.remove("serviceAccount");
+    }
+
+    retain_seq(spec, "volumes", |volume| !is_service_account_volume(volume));
+
+    for key in ["initContainers", "containers"] {
+        let Some(Value::Sequence(containers)) = spec.get_mut(key) else {
+            continue;
+        };
+
+        for container in containers.iter_mut().filter_map(Value::as_mapping_mut) {
+            remove_defaults(container, "Container");
+
+            for probe in ["livenessProbe", "readinessProbe", "startupProbe"] {
+                if let Some(Value::Mapping(probe)) = container.get_mut(probe) {
+                    remove_defaults(probe, "Probe");
+                }
+            }
+
+            retain_seq(container, "volumeMounts", |mount| {
+                !is_service_account_volume(mount)
+            });
+        }
+    }
+}
+
+fn is_service_account_volume(value: &Value) -> bool {
+    value["name"]
+        .as_str()
+        .is_some_and(|name| name.starts_with(SERVICE_ACCOUNT_VOLUME_PREFIX))
+}
+
+fn remove_defaults(map: &mut Mapping, kind: &str) {
+    let Some(Value::Mapping(defaults)) = DEFAULTS.get(kind) else {
+        return;
+    };
+
+    for (key, default) in defaults {
+        if map.get(key) == Some(default) {
+            map.remove(key);
+        }
+    }
+}
+
+/// 条件に合う要素だけ残し、空になったら削除する
+fn retain_seq(map: &mut Mapping, key: &str, f: impl Fn(&Value) -> bool) {
+    if let Some(Value::Sequence(seq)) = map.get_mut(key) {
+        seq.retain(f);
+    }
+
+    remove_if_empty(map, key);
+}
+
+fn remove_if_empty(map: &mut Mapping, key: &str) {
+    let is_empty = match map.get(key) {
+        Some(Value::Mapping(m)) => m.is_empty(),
+        Some(Value::Sequence(s)) => s.is_empty(),
+        _ => false,
+    };
+
+    if is_empty {
+        map.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn yaml(s: &str) -> Value {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn サーバーが付与したフィールドと既定値を削除する() {
+        let mut actual = yaml(indoc! {r#"
+            apiVersion: apps/v1
+            kind: Deployment
+            metadata:
+              annotations:
+                deployment.kubernetes.io/revision: "3"
+                kubectl.kubernetes.io/last-applied-configuration: '{}'
+              creationTimestamp: "2024-01-01T00:00:00Z"
+              generation: 3
+              name: app
+              namespace: default
+              resourceVersion: "1234"
+              uid: 5c7e1f1e-0000-0000-0000-000000000000
+            spec:
+              progressDeadlineSeconds: 600
+              replicas: 2
+              revisionHistoryLimit: 10
+              strategy:
+                rollingUpdate:
+                  maxSurge: 25%
+                  maxUnavailable: 25%
+                type: RollingUpdate
+              template:
+                metadata:
+                  creationTimestamp: null
+                  labels:
+                    app: app
+                spec:
+                  containers:
+                  - image: nginx
+                    name: nginx
+                    readinessProbe:
+                      failureThreshold: 3
+                      httpGet:
+                        path: /
+                        port: 80
+                      periodSeconds: 5
+                    resources: {}
+                    terminationMessagePath: /dev/termination-log
+                    terminationMessagePolicy: File
+                    volumeMounts:
+                    - mountPath: /var/run/secrets/kubernetes.io/serviceaccount
+                      name: kube-api-access-abcde
+                  dnsPolicy: ClusterFirst
+                  restartPolicy: Always
+                  schedulerName: default-scheduler
+                  securityContext: {}
+                  terminationGracePeriodSeconds: 60
+                  volumes:
+                  - name: kube-api-access-abcde
+                    projected: {}
+            status:
+              replicas: 2
+        "#});
+
+        neat(&mut actual);
+
+        let expected = yaml(indoc! {r#"
+            apiVersion: apps/v1
+            kind: Deployment
+            metadata:
+              name: app
+              namespace: default
+            spec:
+              replicas: 2
+              template:
+                metadata:
+                  labels:
+                    app: app
+                spec:
+                  containers:
+                  - image: nginx
+                    name: nginx
+                    readinessProbe:
+                      httpGet:
+                        path: /
+                        port: 80
+                      periodSeconds: 5
+                  terminationGracePeriodSeconds: 60
+        "#});
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn サービスに割り当てられたipを削除する() {
+        let mut actual = yaml(indoc! {r#"
+            apiVersion: v1
+            kind: Service
+            metadata:
+              name: db
+            spec:
+              clusterIP: None
+              clusterIPs:
+              - None
+              ipFamilies:
+              - IPv4
+              ipFamilyPolicy: SingleStack
+              ports:
+              - port: 5432
+                protocol: TCP
+              - port: 53
+                protocol: UDP
+              type: ClusterIP
+        "#});
+
+        neat(&mut actual);
+
+        let expected = yaml(indoc! {r#"
+            apiVersion: v1
+            kind: Service
+            metadata:
+              name: db
+            spec:
+              clusterIP: None
+              clusterIPs:
+              - None
+              ports:
+              - port: 5432
+              - port: 53
+                protocol: UDP
+        "#});
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,232 @@
+use anyhow::{anyhow, Result};
+use jsonpath_rust::{path::config::JsonPathConfig, JsonPathInst};
+use serde_json::Value as JsonValue;
+use serde_yaml::Value;
+
+use super::neat::neat;
+
+/// リソースの表示形式
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Yaml,
+    Json,
+    /// JSONPath（kubectlの {.metadata.name} やjqの .metadata.name の形式も受け付ける）
+    JsonPath(String),
+}
+
+/// 表示の設定
+///
+/// YamlタブとYamlポップアップはそれぞれの設定を持ち、取得したリソースを表示するときに変換する
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Output {
+    pub format: OutputFormat,
+    /// ステータスやサーバーが付与したフィールドを削除する
+    pub neat: bool,
+}
+
+impl Output {
+    pub fn toggle_neat(&mut self) {
+        self.neat = !self.neat;
+    }
+
+    /// YAMLとJSONを切り替える（JSONPathのときはYAMLに戻す）
+    pub fn toggle_json(&mut self) {
+        self.format = match self.format {
+            OutputFormat::Yaml => OutputFormat::Json,
+            _ => OutputFormat::Yaml,
+        }
+    }
+
+    /// 空のときはYAMLに戻す
+    pub fn set_json_path(&mut self, expr: &str) -> Result<()> {
+        let expr = expr.trim();
+
+        if expr.is_empty() {
+            self.format = OutputFormat::Yaml;
+        } else {
+            parse_json_path(expr)?;
+
+            self.format = OutputFormat::JsonPath(expr.to_string());
+        }
+
+        Ok(())
+    }
+
+    /// 入力フォームに表示するJSONPath
+    pub fn json_path(&self) -> Option<&str> {
+        match &self.format {
+            OutputFormat::JsonPath(expr) => Some(expr),
+            _ => None,
+        }
+    }
+
+    pub fn is_yaml(&self) -> bool {
+        self.format == OutputFormat::Yaml
+    }
+
+    /// タイトルに付ける表示形式
+    pub fn title(&self) -> String {
+        let neat = if self.neat { " (neat)" } else { "" };
+
+        match &self.format {
+            OutputFormat::Yaml => neat.to_string(),
+            OutputFormat::Json => format!("{} (json)", neat),
+            OutputFormat::JsonPath(expr) => format!("{} (jsonpath: {})", neat, expr),
+        }
+    }
+
+    /// 取得したリソースを表示する行に変換する
+    pub fn render(&self, value: &Value) -> Result<Vec<String>> {
+        let mut value = value.clone();
+
+        if self.neat {
+            neat(&mut value);
+        }
+
+        let text = match &self.format {
+            OutputFormat::Yaml => serde_yaml::to_string(&value)?,
+            OutputFormat::Json => serde_json::to_string_pretty(&value)?,
+            OutputFormat::JsonPath(expr) => {
+                return json_path(expr, &serde_json::to_value(&value)?);
+            }
+        };
+
+        Ok(text.lines().map(ToString::to_string).collect())
+    }
+}
+
+pub fn parse_json_path(expr: &str) -> Result<JsonPathInst> {
+    let path = expr
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .trim();
+
+    let path = if path.starts_with('$') {
+        path.to_string()
+    } else {
+        format!("${}", path)
+    };
+
+    path.parse::<JsonPathInst>()
+        .map_err(|err| anyhow!("Invalid JSONPath {}: {}", expr, err))
+}
+
+/// 一致した値を1行ずつ出力する（文字列はそのまま、オブジェクトと配列は整形したJSON）
+pub fn json_path(expr: &str, value: &JsonValue) -> Result<Vec<String>> {
+    let path = parse_json_path(expr)?;
+
+    let mut lines = Vec::new();
+
+    for found in path.find_slice(value, JsonPathConfig::default()) {
+        let text = match &*found {
+            JsonValue::String(s) => s.clone(),
+            v @ (JsonValue::Object(_) | JsonValue::Array(_)) => serde_json::to_string_pretty(v)?,
+            v => v.to_string(),
+        };
+
+        lines.extend(text.lines().map(ToString::to_string));
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn pod() -> Value {
+        serde_yaml::from_str(indoc! {r#"
+            apiVersion: v1
+            kind: Pod
+            metadata:
+              name: app
+            spec:
+              containers:
+              - image: nginx:1.25
+                name: nginx
+              - image: busybox
+                name: sidecar
+            status:
+              conditions:
+              - status: "True"
+                type: Ready
+              - status: "False"
+                type: PodScheduled
+        "#})
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("{.metadata.name}", vec!["app"])]
+    #[case(".spec.containers[*].image", vec!["nginx:1.25", "busybox"])]
+    #[case(
+        "$.status.conditions[?(@.type == 'Ready')].status",
+        vec!["True"]
+    )]
+    #[case(".metadata.labels", vec![])]
+    fn jsonpathに一致した値を出力する(#[case] expr: &str, #[case] expected: Vec<&str>) {
+        let output = Output {
+            format: OutputFormat::JsonPath(expr.into()),
+            neat: false,
+        };
+
+        assert_eq!(output.render(&pod()).unwrap(), expected);
+    }
+
+    #[test]
+    fn オブジェクトは整形したjsonで出力する() {
+        let output = Output {
+            format: OutputFormat::JsonPath(".spec.containers[1]".into()),
+            neat: false,
+        };
+
+        assert_eq!(
+            output.render(&pod()).unwrap(),
+            vec![
+                "{",
+                r#"  "image": "busybox","#,
+                r#"  "name": "sidecar""#,
+                "}"
+            ]
+        );
+    }
+
+    #[test]
+    fn クリーンモードのjsonはステータスを含まない() {
+        let output = Output {
+            format: OutputFormat::Json,
+            neat: true,
+        };
+
+        let actual = output.render(&pod()).unwrap();
+
+        assert_eq!(actual.first().map(String::as_str), Some("{"));
+        assert!(actual.iter().all(|line| !line.contains("status")));
+    }
+
+    #[test]
+    fn 不正なjsonpathはエラーになる() {
+        assert!(parse_json_path(".spec[").is_err());
+    }
+
+    #[test]
+    fn 不正なjsonpathのときは表示の設定を変えない() {
+        let mut output = Output {
+            format: OutputFormat::JsonPath(".metadata.name".into()),
+            neat: true,
+        };
+
+        assert!(output.set_json_path(".spec[").is_err());
+        assert_eq!(output.json_path(), Some(".metadata.name"));
+
+        output.set_json_path(" ").unwrap();
+
+        assert_eq!(output.format, OutputFormat::Yaml);
+        assert!(output.neat);
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Utc};
+use serde_yaml::Value;
+
+/// 保持するリビジョンの上限
+const MAX_REVISIONS: usize = 20;
+
+/// resourceVersionごとのリソース
+///
+/// 表示の設定を変えても比較できるように、変換する前のリソースを保持する
+#[derive(Debug, Clone, PartialEq)]
+pub struct YamlRevision {
+    pub resource_version: String,
+    /// このリビジョンを初めて取得した時刻
+    pub time: DateTime<Utc>,
+    pub object: Value,
+}
+
+/// ワーカーが取得したリソースと過去のリビジョン
+///
+/// 表示する行には、表示するときにビューの設定で変換する
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct YamlContent {
+    /// 取得したリソース（managedFieldsを除く）
+    pub object: Option<Value>,
+    /// リソースのあとに表示する関連するイベントなどの行
+    pub events: Vec<String>,
+    /// 古いものから順に並べたリビジョン
+    pub revisions: Vec<YamlRevision>,
+}
+
+impl YamlContent {
+    pub fn with_object(self, object: Value) -> Self {
+        Self {
+            object: Some(object),
+            ..self
+        }
+    }
+}
+
+/// ワーカーで取得したリビジョンを保持する
+#[derive(Debug, Default)]
+pub struct YamlRevisions {
+    revisions: VecDeque<YamlRevision>,
+}
+
+impl YamlRevisions {
+    /// resourceVersionが変わったときだけ追加する
+    pub fn record(&mut self, resource_version: Option<&str>, object: &Value, now: DateTime<Utc>) {
+        let Some(resource_version) = resource_version else {
+            return;
+        };
+
+        if self
+            .revisions
+            .back()
+            .is_some_and(|last| last.resource_version == resource_version)
+        {
+            return;
+        }
+
+        self.revisions.push_back(YamlRevision {
+            resource_version: resource_version.to_string(),
+            time: now,
+            object: object.clone(),
+        });
+
+        if MAX_REVISIONS < self.revisions.len() {
+            self.revisions.pop_front();
+        }
+    }
+
+    pub fn content(&self, events: Vec<String>) -> YamlContent {
+        YamlContent {
+            object: None,
+            events,
+            revisions: self.revisions.iter().cloned().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone as _;
+    use pretty_assertions::assert_eq;
+
+    fn replicas(n: u64) -> Value {
+        serde_yaml::from_str(&format!("spec:\n  replicas: {}", n)).unwrap()
+    }
+
+    #[test]
+    fn resource_versionが変わったときだけ追加する() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let mut revisions = YamlRevisions::default();
+
+        revisions.record(Some("1"), &replicas(1), now);
+        revisions.record(Some("1"), &replicas(1), now);
+        revisions.record(None, &replicas(3), now);
+        revisions.record(Some("2"), &replicas(2), now);
+
+        let actual: Vec<(String, Value)> = revisions
+            .content(vec![])
+            .revisions
+            .into_iter()
+            .map(|r| (r.resource_version, r.object))
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![("1".into(), replicas(1)), ("2".into(), replicas(2))]
+        );
+    }
+
+    #[test]
+    fn 上限を超えたら古いリビジョンから削除する() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let mut revisions = YamlRevisions::default();
+
+        for i in 0..=MAX_REVISIONS {
+            revisions.record(Some(&i.to_string()), &Value::Null, now);
+        }
+
+        let content = revisions.content(vec![]);
+
+        assert_eq!(content.revisions.len(), MAX_REVISIONS);
+        assert_eq!(content.revisions[0].resource_version, "1");
+    }
+}
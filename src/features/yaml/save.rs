@@ -0,0 +1,188 @@
+use std::{
+    fs::OpenOptions,
+    io::Write as _,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result};
+use serde_yaml::Value;
+
+use super::neat::neat;
+
+/// 保存するときの形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SaveFormat {
+    /// 取得したまま（managedFieldsを除く）
+    #[default]
+    Raw,
+    /// ステータスやサーバーが付与したフィールドを除いた再適用できる形式
+    Clean,
+    /// Listタブで選択しているリソースをすべてまとめたマルチドキュメント
+    Bundle,
+}
+
+impl SaveFormat {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Raw => Self::Clean,
+            Self::Clean => Self::Bundle,
+            Self::Bundle => Self::Raw,
+        }
+    }
+}
+
+impl std::fmt::Display for SaveFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Raw => write!(f, "raw"),
+            Self::Clean => write!(f, "clean"),
+            Self::Bundle => write!(f, "bundle"),
+        }
+    }
+}
+
+/// Listタブのリソースをまとめて保存するときのファイル名
+pub const BUNDLE_FILE_NAME: &str = "list-bundle.yaml";
+
+/// `<kind>-<namespace>-<name>.yaml`（クラスタスコープのリソースはnamespaceを省く）
+pub fn default_file_name(object: &Value) -> String {
+    let metadata = &object["metadata"];
+
+    [
+        object["kind"].as_str().map(str::to_lowercase),
+        metadata["namespace"].as_str().map(ToString::to_string),
+        metadata["name"].as_str().map(ToString::to_string),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("-")
+        + ".yaml"
+}
+
+/// 1つのリソースを保存する形式に変換する
+pub fn to_document(object: &Value, clean: bool) -> Result<String> {
+    let mut object = object.clone();
+
+    if clean {
+        neat(&mut object);
+    }
+
+    Ok(serde_yaml::to_string(&object)?)
+}
+
+/// 複数のリソースを`---`で区切ったマルチドキュメントにする
+pub fn to_bundle(objects: &[Value], clean: bool) -> Result<String> {
+    Ok(objects
+        .iter()
+        .map(|object| to_document(object, clean))
+        .collect::<Result<Vec<_>>>()?
+        .join("---\n"))
+}
+
+/// ファイルに書き込んで書き込んだパスを返す
+///
+/// 上書きを確認していないときは、既にあるファイルを書き換えずにエラーにする
+pub fn write(path: impl AsRef<Path>, content: &str, overwrite: bool) -> Result<PathBuf> {
+    let path = path.as_ref();
+
+    let mut options = OpenOptions::new();
+
+    if overwrite {
+        options.write(true).create(true).truncate(true);
+    } else {
+        options.write(true).create_new(true);
+    }
+
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    Ok(path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn yaml(s: &str) -> Value {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    #[rstest]
+    #[case(
+        "{kind: Deployment, metadata: {name: app, namespace: default}}",
+        "deployment-default-app.yaml"
+    )]
+    #[case(
+        "{kind: ClusterRole, metadata: {name: admin}}",
+        "clusterrole-admin.yaml"
+    )]
+    fn デフォルトのファイル名はkindとnamespaceと名前をつなげる(
+        #[case] object: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(default_file_name(&yaml(object)), expected);
+    }
+
+    #[test]
+    fn バンドルはドキュメントを区切って出力する() {
+        let objects = [
+            yaml(indoc! {"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: a
+                  resourceVersion: '1'
+            "}),
+            yaml(indoc! {"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: b
+                  resourceVersion: '2'
+            "}),
+        ];
+
+        assert_eq!(
+            to_bundle(&objects, true).unwrap(),
+            indoc! {"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: a
+                ---
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: b
+            "}
+        );
+    }
+
+    #[test]
+    fn 上書きを確認していないときは既にあるファイルを書き換えない() {
+        let dir = std::env::temp_dir().join(format!("kubetui-save-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("manifest.yaml");
+        std::fs::write(&path, "original").unwrap();
+
+        let not_overwritten = write(&path, "saved", false).is_err();
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        write(&path, "saved", true).unwrap();
+        let overwritten = std::fs::read_to_string(&path).unwrap();
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            (not_overwritten, content.as_str(), overwritten.as_str()),
+            (true, "original", "saved")
+        );
+    }
+}
@@ -2,4 +2,6 @@ mod popups;
 mod tab;
 mod widget;
 
+pub use popups::json_path::{JsonPathOpener, JsonPathPopup};
+pub use popups::save::{update_save_popup, SaveOpener, SavePopup};
 pub use tab::*;
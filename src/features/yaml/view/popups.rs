@@ -1,3 +1,5 @@
+pub(super) mod json_path;
 pub(super) mod kind;
 pub(super) mod name;
 pub(super) mod not_found;
+pub(super) mod save;
@@ -0,0 +1,143 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossterm::event::KeyCode;
+
+use crate::{
+    features::{
+        component_id::YAML_JSON_PATH_POPUP_ID,
+        yaml::diff::{update_yaml_widget, YamlDiff},
+    },
+    message::UserEvent,
+    ui::{
+        event::EventResult,
+        widget::{
+            config::WidgetConfig, input::InputFormBuilder, Item, LiteralItem, SelectedItem, Widget,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+const TITLE_HINT: &str = " (e.g. {.spec.containers[*].image}, empty: back to yaml)";
+
+pub struct JsonPathPopup {
+    pub popup: Widget<'static>,
+    pub opener: JsonPathOpener,
+}
+
+impl JsonPathPopup {
+    pub fn new() -> Self {
+        let opener = JsonPathOpener::default();
+
+        Self {
+            popup: popup(opener.target.clone()),
+            opener,
+        }
+    }
+}
+
+/// 入力した式を適用するビュー
+#[derive(Debug)]
+struct Target {
+    diff: Rc<RefCell<YamlDiff>>,
+    /// yamlを表示するウィジェット
+    id: &'static str,
+    /// 入力を確定したあとに開き直すポップアップ
+    return_to: Option<&'static str>,
+}
+
+/// JSONPathの入力フォームを開く
+#[derive(Debug, Clone, Default)]
+pub struct JsonPathOpener {
+    target: Rc<RefCell<Option<Target>>>,
+}
+
+impl JsonPathOpener {
+    pub fn open(
+        &self,
+        diff: Rc<RefCell<YamlDiff>>,
+        id: &'static str,
+        return_to: Option<&'static str>,
+    ) -> impl Fn(&mut Window) -> EventResult {
+        let target = self.target.clone();
+
+        move |w: &mut Window| {
+            let expr = diff
+                .borrow()
+                .output()
+                .json_path()
+                .unwrap_or_default()
+                .to_string();
+
+            *target.borrow_mut() = Some(Target {
+                diff: diff.clone(),
+                id,
+                return_to,
+            });
+
+            let widget = w.find_widget_mut(YAML_JSON_PATH_POPUP_ID);
+
+            widget.update_widget_item(Item::Single(LiteralItem::from(expr)));
+
+            *widget.widget_config_mut().append_title_mut() = Some(TITLE_HINT.into());
+
+            w.open_popup(YAML_JSON_PATH_POPUP_ID);
+
+            EventResult::Nop
+        }
+    }
+}
+
+fn popup(target: Rc<RefCell<Option<Target>>>) -> Widget<'static> {
+    InputFormBuilder::default()
+        .id(YAML_JSON_PATH_POPUP_ID)
+        .widget_config(
+            WidgetConfig::builder()
+                .title("JSONPath")
+                .append_title(TITLE_HINT)
+                .build(),
+        )
+        .actions(UserEvent::from(KeyCode::Enter), apply(target))
+        .build()
+        .into()
+}
+
+/// 入力した式を開いたビューに適用する（不正な式のときはタイトルにエラーを表示して入力を続ける）
+fn apply(target: Rc<RefCell<Option<Target>>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let target = target.borrow();
+
+        let Some(Target {
+            diff,
+            id,
+            return_to,
+        }) = target.as_ref()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let widget = w.find_widget_mut(YAML_JSON_PATH_POPUP_ID);
+
+        let expr = match widget.widget_item() {
+            Some(SelectedItem::Literal { item, .. }) => item,
+            _ => String::new(),
+        };
+
+        let mut diff = diff.borrow_mut();
+
+        if let Err(err) = diff.output_mut().set_json_path(&expr) {
+            *widget.widget_config_mut().append_title_mut() = Some(format!(" : {}", err).into());
+
+            return EventResult::Nop;
+        }
+
+        update_yaml_widget(w, id, &diff);
+
+        match return_to {
+            Some(id) => w.open_popup(*id),
+            None => w.close_popup(),
+        }
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,221 @@
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
+
+use anyhow::{anyhow, Result};
+use crossbeam::channel::Sender;
+use crossterm::event::KeyCode;
+use serde_yaml::Value;
+
+use crate::{
+    features::{
+        component_id::YAML_SAVE_POPUP_ID,
+        yaml::{
+            diff::YamlDiff,
+            message::YamlRequest,
+            save::{default_file_name, to_document, write, SaveFormat, BUNDLE_FILE_NAME},
+        },
+    },
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{
+            config::WidgetConfig, input::InputFormBuilder, Item, LiteralItem, SelectedItem, Widget,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub struct SavePopup {
+    pub popup: Widget<'static>,
+    pub opener: SaveOpener,
+}
+
+impl SavePopup {
+    pub fn new(tx: &Sender<Message>) -> Self {
+        let opener = SaveOpener::default();
+
+        Self {
+            popup: popup(tx.clone(), opener.state.clone()),
+            opener,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SaveState {
+    format: SaveFormat,
+    /// 保存ポップアップを開いたときに表示していたリソース
+    object: Option<Value>,
+    /// 上書きを確認したファイル
+    overwrite: Option<PathBuf>,
+}
+
+impl SaveState {
+    fn default_file_name(&self) -> String {
+        match (&self.format, &self.object) {
+            (SaveFormat::Bundle, _) | (_, None) => BUNDLE_FILE_NAME.to_string(),
+            (_, Some(object)) => default_file_name(object),
+        }
+    }
+
+    fn title(&self) -> String {
+        format!(" [{}] (Tab: format, Enter: save)", self.format)
+    }
+}
+
+/// ファイル名を入力するポップアップを開く
+#[derive(Debug, Clone, Default)]
+pub struct SaveOpener {
+    state: Rc<RefCell<SaveState>>,
+}
+
+impl SaveOpener {
+    pub fn open(&self, diff: Rc<RefCell<YamlDiff>>) -> impl Fn(&mut Window) -> EventResult {
+        let state = self.state.clone();
+
+        move |w: &mut Window| {
+            let mut state = state.borrow_mut();
+
+            state.object = diff.borrow().object().cloned();
+            state.overwrite = None;
+
+            state.format = match state.object {
+                Some(_) => SaveFormat::Raw,
+                None => SaveFormat::Bundle,
+            };
+
+            let widget = w.find_widget_mut(YAML_SAVE_POPUP_ID);
+
+            widget.update_widget_item(Item::Single(LiteralItem::from(state.default_file_name())));
+
+            *widget.widget_config_mut().append_title_mut() = Some(state.title().into());
+
+            w.open_popup(YAML_SAVE_POPUP_ID);
+
+            EventResult::Nop
+        }
+    }
+}
+
+fn popup(tx: Sender<Message>, state: Rc<RefCell<SaveState>>) -> Widget<'static> {
+    InputFormBuilder::default()
+        .id(YAML_SAVE_POPUP_ID)
+        .widget_config(WidgetConfig::builder().title("Save").build())
+        .actions(UserEvent::from(KeyCode::Tab), next_format(state.clone()))
+        .actions(UserEvent::from(KeyCode::Enter), save(tx, state))
+        .build()
+        .into()
+}
+
+fn input(w: &mut Window) -> String {
+    match w.find_widget_mut(YAML_SAVE_POPUP_ID).widget_item() {
+        Some(SelectedItem::Literal { item, .. }) => item,
+        _ => String::new(),
+    }
+}
+
+/// 形式を切り替える（ファイル名を変えていなければ形式に合わせたファイル名にする）
+fn next_format(state: Rc<RefCell<SaveState>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut state = state.borrow_mut();
+
+        let file_name = input(w);
+
+        let is_default = file_name.is_empty() || file_name == state.default_file_name();
+
+        state.format = state.format.next();
+        state.overwrite = None;
+
+        let widget = w.find_widget_mut(YAML_SAVE_POPUP_ID);
+
+        if is_default {
+            widget.update_widget_item(Item::Single(LiteralItem::from(state.default_file_name())));
+        }
+
+        *widget.widget_config_mut().append_title_mut() = Some(state.title().into());
+
+        EventResult::Nop
+    }
+}
+
+fn save(tx: Sender<Message>, state: Rc<RefCell<SaveState>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let mut state = state.borrow_mut();
+
+        let file_name = input(w);
+
+        let path = PathBuf::from(file_name.trim());
+
+        if path.as_os_str().is_empty() {
+            update_save_popup(w, Err(anyhow!("File name is empty")));
+            return EventResult::Nop;
+        }
+
+        // 既にあるファイルは、もう一度Enterを押したときだけ上書きする
+        let overwrite = state
+            .overwrite
+            .take()
+            .is_some_and(|confirmed| confirmed == path);
+
+        if path.exists() && !overwrite {
+            *w.find_widget_mut(YAML_SAVE_POPUP_ID)
+                .widget_config_mut()
+                .append_title_mut() = Some(
+                format!(
+                    " : {} already exists. Overwrite? (Enter: overwrite)",
+                    path.display()
+                )
+                .into(),
+            );
+
+            state.overwrite = Some(path);
+
+            return EventResult::Nop;
+        }
+
+        let object = state.object.as_ref();
+
+        let result = match state.format {
+            SaveFormat::Bundle => {
+                // 取得に時間がかかるため、結果はワーカーからの応答で表示する
+                tx.send(YamlRequest::SaveBundle { path, overwrite }.into())
+                    .expect("Failed to send YamlRequest::SaveBundle");
+
+                *w.find_widget_mut(YAML_SAVE_POPUP_ID)
+                    .widget_config_mut()
+                    .append_title_mut() = Some(" : saving...".into());
+
+                return EventResult::Nop;
+            }
+            SaveFormat::Raw => save_object(object, false, path, overwrite),
+            SaveFormat::Clean => save_object(object, true, path, overwrite),
+        };
+
+        update_save_popup(w, result);
+
+        EventResult::Nop
+    }
+}
+
+fn save_object(
+    object: Option<&Value>,
+    clean: bool,
+    path: PathBuf,
+    overwrite: bool,
+) -> Result<PathBuf> {
+    let object = object.ok_or_else(|| anyhow!("No resource is shown"))?;
+
+    write(path, &to_document(object, clean)?, overwrite)
+}
+
+/// 保存した結果をタイトルに表示する
+pub fn update_save_popup(w: &mut Window, result: Result<PathBuf>) {
+    let title = match result {
+        Ok(path) => format!(" : saved to {} (Esc: close)", path.display()),
+        Err(err) => format!(" : {:#}", err),
+    };
+
+    *w.find_widget_mut(YAML_SAVE_POPUP_ID)
+        .widget_config_mut()
+        .append_title_mut() = Some(title.into());
+}
@@ -5,7 +5,7 @@ use ratatui::prelude::Constraint;
 
 use crate::{
     clipboard::Clipboard,
-    features::component_id::YAML_TAB_ID,
+    features::{component_id::YAML_TAB_ID, edit::view::EditOpener, yaml::diff::YamlDiff},
     message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
@@ -15,7 +15,10 @@ use crate::{
 };
 
 use super::{
-    popups::{kind::kind_popup, name::name_popup, not_found::not_found_popup},
+    popups::{
+        json_path::JsonPathOpener, kind::kind_popup, name::name_popup, not_found::not_found_popup,
+        save::SaveOpener,
+    },
     widget::yaml_widget,
 };
 
@@ -31,8 +34,12 @@ impl YamlTab {
         title: &'static str,
         tx: &Sender<Message>,
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        diff: Rc<RefCell<YamlDiff>>,
+        json_path: &JsonPathOpener,
+        save: &SaveOpener,
+        edit: &EditOpener,
     ) -> Self {
-        let yaml_widget = yaml_widget(tx, clipboard);
+        let yaml_widget = yaml_widget(tx, clipboard, diff, json_path, save, edit);
 
         let layout = NestedWidgetLayout::default().nested_widget_layout([NestedLayoutElement(
             Constraint::Percentage(100),
@@ -3,11 +3,17 @@ use std::{cell::RefCell, rc::Rc};
 use crossbeam::channel::Sender;
 use ratatui::widgets::Block;
 
+use super::popups::{json_path::JsonPathOpener, save::SaveOpener};
+
 use crate::{
     clipboard::Clipboard,
     features::{
         component_id::{YAML_KIND_POPUP_ID, YAML_WIDGET_ID},
-        yaml::message::YamlRequest,
+        edit::view::EditOpener,
+        yaml::{
+            diff::{newer_revision, older_revision, toggle_json_mode, toggle_neat_mode, YamlDiff},
+            message::YamlRequest,
+        },
     },
     message::Message,
     ui::{
@@ -20,14 +26,25 @@ use crate::{
 pub fn yaml_widget(
     tx: &Sender<Message>,
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    diff: Rc<RefCell<YamlDiff>>,
+    json_path: &JsonPathOpener,
+    save: &SaveOpener,
+    edit: &EditOpener,
 ) -> Widget<'static> {
     let tx = tx.clone();
 
     let builder = Text::builder()
         .id(YAML_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Yaml").build())
-        .block_injection(block_injection())
+        .block_injection(block_injection(diff.clone()))
         .action('f', open_kind_popup(tx))
+        .action('[', older_revision(diff.clone(), YAML_WIDGET_ID))
+        .action(']', newer_revision(diff.clone(), YAML_WIDGET_ID))
+        .action('C', toggle_neat_mode(diff.clone(), YAML_WIDGET_ID))
+        .action('J', toggle_json_mode(diff.clone(), YAML_WIDGET_ID))
+        .action('P', json_path.open(diff.clone(), YAML_WIDGET_ID, None))
+        .action('S', save.open(diff.clone()))
+        .action('e', edit.open(diff))
         .wrap();
 
     if let Some(cb) = clipboard {
@@ -48,13 +65,24 @@ fn open_kind_popup(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
     }
 }
 
-fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
-    |text: &Text, is_active: bool, is_mouse_over: bool| {
+fn block_injection(diff: Rc<RefCell<YamlDiff>>) -> impl Fn(&Text, bool, bool) -> Block<'static> {
+    move |text: &Text, is_active: bool, is_mouse_over: bool| {
         let (index, size) = text.state();
 
         let mut config = text.widget_config().clone();
 
-        *config.append_title_mut() = Some(format!(" [{}/{}]", index, size).into());
+        let diff = diff.borrow();
+
+        *config.append_title_mut() = Some(
+            format!(
+                " [{}/{}]{}{}",
+                index,
+                size,
+                diff.output().title(),
+                diff.title().unwrap_or_default()
+            )
+            .into(),
+        );
 
         config.render_block(text.can_activate() && is_active, is_mouse_over)
     }
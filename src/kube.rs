@@ -1,6 +1,8 @@
+pub mod api_status;
 pub mod apis;
 mod client;
 pub mod context;
+pub mod reflector;
 pub mod table;
 
 pub use client::*;
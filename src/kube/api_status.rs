@@ -0,0 +1,133 @@
+use std::{
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use once_cell::sync::Lazy;
+
+static API_STATUS: Lazy<Mutex<ApiStatus>> = Lazy::new(Default::default);
+
+/// APIサーバーとの接続状態
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// まだリクエストしていない
+    #[default]
+    Unknown,
+    Connected,
+    Disconnected,
+}
+
+/// 直近のリクエストから求めたAPIサーバーの状態
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ApiStatus {
+    pub state: ConnectionState,
+    /// 最後に応答を受け取ったリクエストのレイテンシ
+    pub latency: Option<Duration>,
+    /// 続けて接続に失敗した回数
+    pub failures: u32,
+}
+
+impl ApiStatus {
+    /// 現在の状態のコピーを返す
+    pub fn snapshot() -> Self {
+        *lock()
+    }
+
+    /// contextを切り替えたときに状態を初期化する
+    pub fn reset() {
+        *lock() = Self::default();
+    }
+
+    /// リクエストの結果を記録する
+    pub(super) fn observe(latency: Duration, result: Result<(), &kube::Error>) {
+        lock().record(Some(latency), result);
+    }
+
+    /// レイテンシが分からないリクエスト（watch）のエラーを記録する
+    pub(super) fn observe_error(err: &kube::Error) {
+        lock().record(None, Err(err));
+    }
+
+    fn record(&mut self, latency: Option<Duration>, result: Result<(), &kube::Error>) {
+        match result {
+            // サーバーが応答を返したエラーは接続できているものとみなす
+            Ok(_) | Err(kube::Error::Api(_) | kube::Error::SerdeError(_)) => {
+                self.state = ConnectionState::Connected;
+                self.latency = latency.or(self.latency);
+                self.failures = 0;
+            }
+            Err(kube::Error::HyperError(_) | kube::Error::Service(_) | kube::Error::Auth(_)) => {
+                self.state = ConnectionState::Disconnected;
+                self.failures = self.failures.saturating_add(1);
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+fn lock() -> MutexGuard<'static, ApiStatus> {
+    API_STATUS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kube::error::ErrorResponse;
+    use pretty_assertions::assert_eq;
+
+    fn api_error() -> kube::Error {
+        kube::Error::Api(ErrorResponse {
+            status: "Failure".into(),
+            message: "pods \"app\" not found".into(),
+            reason: "NotFound".into(),
+            code: 404,
+        })
+    }
+
+    #[test]
+    fn 接続に失敗した回数を数えて応答があればリセットする() {
+        let mut status = ApiStatus::default();
+
+        let error = kube::Error::Service("connection refused".into());
+
+        status.record(Some(Duration::from_millis(10)), Err(&error));
+        status.record(Some(Duration::from_millis(10)), Err(&error));
+
+        assert_eq!(
+            status,
+            ApiStatus {
+                state: ConnectionState::Disconnected,
+                latency: None,
+                failures: 2,
+            }
+        );
+
+        status.record(Some(Duration::from_millis(30)), Err(&api_error()));
+
+        assert_eq!(
+            status,
+            ApiStatus {
+                state: ConnectionState::Connected,
+                latency: Some(Duration::from_millis(30)),
+                failures: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn レイテンシが分からないエラーは直前のレイテンシを残す() {
+        let mut status = ApiStatus::default();
+
+        status.record(Some(Duration::from_millis(30)), Ok(()));
+        status.record(None, Err(&api_error()));
+
+        assert_eq!(
+            status,
+            ApiStatus {
+                state: ConnectionState::Connected,
+                latency: Some(Duration::from_millis(30)),
+                failures: 0,
+            }
+        );
+    }
+}
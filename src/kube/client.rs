@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use anyhow::Result;
 use async_trait::async_trait;
 use http::header::{HeaderValue, ACCEPT};
@@ -9,6 +11,8 @@ use serde::de::DeserializeOwned;
 
 use crate::logger;
 
+use super::api_status::ApiStatus;
+
 const TABLE_REQUEST_HEADER: &str = "application/json;as=Table;v=v1;g=meta.k8s.io,application/json;as=Table;v=v1beta1;g=meta.k8s.io,application/json";
 
 fn remove_slash(path: &str) -> &str {
@@ -64,8 +68,12 @@ impl KubeClient {
 
         logger!(debug, "HTTP request {:?}", request);
 
+        let start = Instant::now();
+
         let ret = self.client.request(request).await;
 
+        ApiStatus::observe(start.elapsed(), ret.as_ref().map(|_| ()));
+
         ret.map_err(Into::into)
     }
 }
@@ -99,8 +107,12 @@ impl KubeClientRequest for KubeClient {
 
         logger!(debug, "HTTP request {:?}", request);
 
+        let start = Instant::now();
+
         let ret = self.client.request_text(request).await;
 
+        ApiStatus::observe(start.elapsed(), ret.as_ref().map(|_| ()));
+
         ret.map_err(Into::into)
     }
 }
@@ -0,0 +1,229 @@
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use anyhow::anyhow;
+use futures::StreamExt as _;
+use k8s_openapi::NamespaceResourceScope;
+use kube::{
+    runtime::{
+        reflector::{self, Store},
+        watcher::{self, watcher, Event},
+        WatchStreamExt as _,
+    },
+    Api, Resource,
+};
+use serde::de::DeserializeOwned;
+use tokio::{sync::Notify, task::AbortHandle};
+
+use crate::logger;
+
+use super::{api_status::ApiStatus, KubeClient};
+
+/// 複数のストアで共有する変更通知
+#[derive(Debug, Default, Clone)]
+pub struct ReflectorSignal {
+    changed: Arc<Notify>,
+    forbidden: Arc<AtomicBool>,
+    /// 初回の一覧取得が完了したストアの数
+    ready: Arc<AtomicUsize>,
+    /// 起動したストアの数
+    total: Arc<AtomicUsize>,
+    /// 権限エラー以外のwatchのエラー（まだ送信していない最新のもの）
+    error: Arc<Mutex<Option<anyhow::Error>>>,
+}
+
+impl ReflectorSignal {
+    /// いずれかのストアが更新されるまで待つ
+    pub async fn changed(&self) {
+        self.changed.notified().await
+    }
+
+    /// watchの権限がないときtrue
+    pub fn is_forbidden(&self) -> bool {
+        self.forbidden.load(Ordering::Relaxed)
+    }
+
+    /// すべてのストアで初回の一覧取得が完了したときtrue
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Relaxed) >= self.total.load(Ordering::Relaxed)
+    }
+
+    /// watchのエラーを取り出す（同じエラーは一度だけ返す）
+    pub fn take_error(&self) -> Option<anyhow::Error> {
+        self.error.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+
+    fn set_error(&self, err: anyhow::Error) {
+        *self.error.lock().unwrap_or_else(|e| e.into_inner()) = Some(err);
+    }
+}
+
+/// 名前空間ごとにwatchしたリソースを保持するローカルストア
+///
+/// ドロップ時にwatchを停止する
+pub struct NamespacedStore<K>
+where
+    K: Resource + 'static,
+    K::DynamicType: Eq + std::hash::Hash + Clone,
+{
+    stores: Vec<(String, Store<K>)>,
+    handles: Vec<AbortHandle>,
+}
+
+impl<K> NamespacedStore<K>
+where
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope>
+        + Clone
+        + DeserializeOwned
+        + Debug
+        + Send
+        + Sync
+        + 'static,
+{
+    pub fn spawn(client: &KubeClient, namespaces: &[String], signal: &ReflectorSignal) -> Self {
+        Self::spawn_with_modifier(client, namespaces, signal, |_| {})
+    }
+
+    /// ストアに保存する前にオブジェクトを加工する
+    /// managedFieldsは常に取り除く
+    pub fn spawn_with_modifier(
+        client: &KubeClient,
+        namespaces: &[String],
+        signal: &ReflectorSignal,
+        modifier: fn(&mut K),
+    ) -> Self {
+        let mut stores = Vec::new();
+        let mut handles = Vec::new();
+
+        for ns in namespaces {
+            let api: Api<K> = Api::namespaced(client.to_client(), ns);
+
+            let (reader, writer) = reflector::store();
+
+            let stream = reflector::reflector(
+                writer,
+                watcher(api, watcher::Config::default())
+                    .modify(move |obj: &mut K| {
+                        obj.meta_mut().managed_fields = None;
+                        modifier(obj);
+                    })
+                    .default_backoff(),
+            );
+
+            signal.total.fetch_add(1, Ordering::Relaxed);
+
+            let signal = signal.clone();
+            let namespace = ns.to_string();
+
+            let handle = tokio::spawn(async move {
+                let mut stream = stream.boxed();
+                let mut is_ready = false;
+
+                while let Some(event) = stream.next().await {
+                    match event {
+                        Ok(event) => {
+                            if !is_ready && matches!(event, Event::Restarted(_)) {
+                                is_ready = true;
+                                signal.ready.fetch_add(1, Ordering::Relaxed);
+                            }
+
+                            signal.changed.notify_one();
+                        }
+                        Err(err) if is_forbidden(&err) => {
+                            logger!(
+                                warn,
+                                "watch is forbidden. kind={} namespace={} error={}",
+                                K::kind(&()),
+                                namespace,
+                                err
+                            );
+
+                            signal.forbidden.store(true, Ordering::Relaxed);
+                            signal.changed.notify_one();
+                            break;
+                        }
+                        Err(err) => {
+                            logger!(
+                                error,
+                                "watch error. kind={} namespace={} error={}",
+                                K::kind(&()),
+                                namespace,
+                                err
+                            );
+
+                            observe_api_status(&err);
+
+                            signal.set_error(anyhow!(
+                                "Failed to watch {} in {}: {}",
+                                K::kind(&()),
+                                namespace,
+                                err
+                            ));
+                            signal.changed.notify_one();
+                        }
+                    }
+                }
+            })
+            .abort_handle();
+
+            stores.push((ns.to_string(), reader));
+            handles.push(handle);
+        }
+
+        Self { stores, handles }
+    }
+
+    /// 名前空間ごとのオブジェクトを名前順で返す
+    pub fn state(&self) -> Vec<(&str, Vec<Arc<K>>)> {
+        self.stores
+            .iter()
+            .map(|(ns, store)| {
+                let mut objects = store.state();
+                objects.sort_by(|a, b| a.meta().name.cmp(&b.meta().name));
+                (ns.as_str(), objects)
+            })
+            .collect()
+    }
+}
+
+impl<K> Drop for NamespacedStore<K>
+where
+    K: Resource + 'static,
+    K::DynamicType: Eq + std::hash::Hash + Clone,
+{
+    fn drop(&mut self) {
+        for handle in &self.handles {
+            handle.abort();
+        }
+    }
+}
+
+/// watchのエラーをAPIサーバーの接続状態に反映する
+fn observe_api_status(err: &watcher::Error) {
+    use watcher::Error::*;
+
+    match err {
+        InitialListFailed(err) | WatchStartFailed(err) | WatchFailed(err) => {
+            ApiStatus::observe_error(err)
+        }
+        WatchError(res) => ApiStatus::observe_error(&kube::Error::Api(res.clone())),
+        _ => {}
+    }
+}
+
+fn is_forbidden(err: &watcher::Error) -> bool {
+    use watcher::Error::*;
+
+    match err {
+        InitialListFailed(kube::Error::Api(res)) | WatchStartFailed(kube::Error::Api(res)) => {
+            res.code == 403
+        }
+        WatchError(res) => res.code == 403,
+        _ => false,
+    }
+}
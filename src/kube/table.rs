@@ -7,7 +7,7 @@ use crate::kube::{
     KubeClient, KubeClientRequest as _,
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct KubeTableRow {
     pub namespace: String,
     pub name: String,
@@ -15,7 +15,7 @@ pub struct KubeTableRow {
     pub row: Vec<String>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct KubeTable {
     pub header: Vec<String>,
     pub rows: Vec<KubeTableRow>,
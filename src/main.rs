@@ -2,49 +2,59 @@ mod ansi;
 mod app;
 mod clipboard;
 mod cmd;
+mod config;
 mod features;
 mod kube;
 mod logging;
 mod message;
+mod session;
 mod ui;
 mod workers;
 
 use std::panic;
 
-use anyhow::Result;
-use crossterm::{
-    cursor::Show,
-    event::{DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+use crate::{
+    app::App,
+    cmd::Command,
+    config::Config,
+    features::{
+        alert::AlertRules,
+        api_resources::kube::ListColumns,
+        preset::{self, Presets},
+    },
+    logging::Logger,
+    session::Session,
+    ui::theme::Theme,
+    workers::interval::IntervalConfig,
 };
+use anyhow::Result;
 
-use crate::{app::App, cmd::Command, logging::Logger};
-
+#[macro_export]
 macro_rules! enable_raw_mode {
     () => {
-        enable_raw_mode().expect("failed to enable raw mode");
-        execute!(
+        crossterm::terminal::enable_raw_mode().expect("failed to enable raw mode");
+        crossterm::execute!(
             std::io::stdout(),
-            EnterAlternateScreen,
-            EnableMouseCapture,
-            EnableFocusChange
+            crossterm::terminal::EnterAlternateScreen,
+            crossterm::event::EnableMouseCapture,
+            crossterm::event::EnableFocusChange
         )
         .expect("failed to enable raw mode");
     };
 }
 
+#[macro_export]
 macro_rules! disable_raw_mode {
     () => {
-        execute!(
+        crossterm::execute!(
             std::io::stdout(),
-            LeaveAlternateScreen,
-            DisableMouseCapture,
-            DisableFocusChange,
-            Show
+            crossterm::terminal::LeaveAlternateScreen,
+            crossterm::event::DisableMouseCapture,
+            crossterm::event::DisableFocusChange,
+            crossterm::cursor::Show
         )
         .expect("failed to restore terminal");
-        disable_raw_mode().expect("failed to disable raw mode");
+        crossterm::terminal::disable_raw_mode().expect("failed to disable raw mode");
     };
 }
 
@@ -76,11 +86,34 @@ fn main() -> Result<()> {
         Logger::init()?;
     }
 
+    let config = Config::load(command.config.as_deref())?;
+
+    IntervalConfig::init(command.interval_config(config.interval_config()));
+    Theme::init(config.theme.theme(Theme::is_no_color()));
+    AlertRules::init(config.alert.rules()?);
+    ListColumns::init(config.list.columns()?);
+
+    let presets = Presets::load(preset::default_path())?;
+
+    // --no-restoreのときは保存したセッションを読み込まず、上書きもしない
+    let session_path = session::default_path().filter(|_| !command.no_restore);
+
+    let session = session_path
+        .as_deref()
+        .map(Session::load)
+        .unwrap_or_default();
+
     enable_raw_mode!();
 
-    let result = App::run(command);
+    let result = App::run(command, config, presets, session);
 
     disable_raw_mode!();
 
-    result
+    let session = result?;
+
+    if let Some(path) = session_path {
+        session.save(&path)?;
+    }
+
+    Ok(())
 }
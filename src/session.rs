@@ -0,0 +1,170 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{cmd::SplitDirection, config::InitialTab, logger, workers::kube::SavedKubeState};
+
+/// 終了したときの状態
+///
+/// 次に起動したときに復元する。コマンドライン引数で指定した値が優先され、設定ファイルの値より優先される
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    /// 最後に表示していたcontext
+    pub context: Option<String>,
+    /// contextごとのnamespaceとAPIリソース
+    pub contexts: BTreeMap<String, SavedKubeState>,
+    /// Podタブのログのクエリ
+    pub log_query: Option<String>,
+    pub tab: Option<InitialTab>,
+    pub split_direction: Option<SplitDirection>,
+}
+
+/// kubeのスレッドとレンダリングのスレッドがそれぞれ終了するときに書き込む
+pub type SharedSession = Arc<Mutex<Session>>;
+
+/// $XDG_STATE_HOME/kubetui/session.yaml
+/// XDG_STATE_HOMEが未設定のときは $HOME/.local/state/kubetui/session.yaml
+pub fn default_path() -> Option<PathBuf> {
+    let state_dir = env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state")))?;
+
+    Some(state_dir.join("kubetui").join("session.yaml"))
+}
+
+impl Session {
+    /// ファイルがないときや壊れているときは復元せずに起動する
+    pub fn load(path: &Path) -> Self {
+        match Self::read(path) {
+            Ok(session) => session,
+            Err(err) => {
+                logger!(warn, "{:#}", err);
+                Self::default()
+            }
+        }
+    }
+
+    fn read(path: &Path) -> Result<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read session file {}", path.display()))
+            }
+        };
+
+        Self::from_yaml(&content)
+            .with_context(|| format!("invalid session file {}", path.display()))
+    }
+
+    fn from_yaml(content: &str) -> Result<Self> {
+        if content.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+
+        std::fs::write(path, serde_yaml::to_string(self)?)
+            .with_context(|| format!("failed to write session file {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn セッションを読み込む() {
+        let content = indoc! { r#"
+            context: kind-dev
+            contexts:
+              kind-dev:
+                target_namespaces: [app, monitoring]
+                target_api_resources:
+                  - !Api
+                    name: pods
+                    kind: Pod
+                    version: v1
+                    scope: Namespaced
+            log_query: deployment/web
+            tab: list
+            split_direction: horizontal
+        "# };
+
+        let actual = Session::from_yaml(content).unwrap();
+
+        assert_eq!(actual.context.as_deref(), Some("kind-dev"));
+        assert_eq!(
+            actual.contexts["kind-dev"].target_namespaces,
+            vec!["app".to_string(), "monitoring".to_string()]
+        );
+        assert_eq!(actual.contexts["kind-dev"].target_api_resources.len(), 1);
+        assert_eq!(actual.log_query.as_deref(), Some("deployment/web"));
+        assert_eq!(actual.tab, Some(InitialTab::List));
+        assert_eq!(actual.split_direction, Some(SplitDirection::Horizontal));
+    }
+
+    #[test]
+    fn 保存したセッションを読み込める() {
+        let path = std::env::temp_dir()
+            .join(format!("kubetui-session-{}", std::process::id()))
+            .join("session.yaml");
+
+        let session = Session {
+            context: Some("kind-dev".into()),
+            contexts: BTreeMap::from([(
+                "kind-dev".into(),
+                SavedKubeState {
+                    target_namespaces: vec!["app".into()],
+                    target_api_resources: vec![],
+                },
+            )]),
+            log_query: Some("pod/web".into()),
+            tab: Some(InitialTab::Workload),
+            split_direction: Some(SplitDirection::Vertical),
+        };
+
+        session.save(&path).unwrap();
+
+        let actual = Session::load(&path);
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        assert_eq!(actual, session);
+    }
+
+    #[test]
+    fn 壊れたセッションのときは復元しない() {
+        let path = std::env::temp_dir()
+            .join(format!("kubetui-broken-session-{}", std::process::id()))
+            .join("session.yaml");
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "contexts: [").unwrap();
+
+        let actual = Session::load(&path);
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        assert_eq!(actual, Session::default());
+    }
+}
@@ -1,7 +1,9 @@
 mod callback;
 pub mod event;
+pub mod highlight;
 pub mod popup;
 pub mod tab;
+pub mod theme;
 pub mod widget;
 mod window;
 
@@ -9,4 +11,4 @@ pub mod util;
 
 pub use tab::Tab;
 pub use util::key_event_to_code;
-pub use window::{Header, Window, WindowAction};
+pub use window::{Header, StatusBarCallback, Toast, Window, WindowAction};
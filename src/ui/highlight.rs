@@ -0,0 +1,297 @@
+use crate::ui::theme::{paint, Theme, YamlColors};
+
+/// yamlの行にシンタックスハイライトのエスケープシーケンスを付与する
+///
+/// 色はTextウィジェットのansiパーサでスタイルに変換されるため、検索や折り返しはそのまま使える
+/// すでにエスケープシーケンスを含む行は装飾済みとみなして変更しない
+pub fn highlight_yaml<S: AsRef<str>>(lines: &[S]) -> Vec<String> {
+    let mut highlighter = YamlHighlighter::new(Theme::global().yaml);
+
+    lines
+        .iter()
+        .map(|line| highlighter.line(line.as_ref()))
+        .collect()
+}
+
+struct YamlHighlighter {
+    colors: YamlColors,
+    /// ブロックスカラーを持つノードのインデント（この幅より深い行をブロックスカラーとして扱う）
+    block_indent: Option<usize>,
+}
+
+impl YamlHighlighter {
+    fn new(colors: YamlColors) -> Self {
+        Self {
+            colors,
+            block_indent: None,
+        }
+    }
+
+    fn line(&mut self, line: &str) -> String {
+        if line.contains('\x1b') {
+            self.block_indent = None;
+            return line.to_string();
+        }
+
+        let body = line.trim_start_matches(' ');
+        let indent = line.len() - body.len();
+
+        if let Some(block_indent) = self.block_indent {
+            if body.is_empty() {
+                return line.to_string();
+            }
+
+            if block_indent < indent {
+                return format!(
+                    "{}{}",
+                    &line[..indent],
+                    paint(self.colors.block_scalar, body)
+                );
+            }
+
+            self.block_indent = None;
+        }
+
+        let mut ret = line[..indent].to_string();
+        let mut rest = body;
+        let mut column = indent;
+        let mut node_column = indent;
+
+        // シーケンスの要素
+        while rest == "-" || rest.starts_with("- ") {
+            let len = rest.len().min(2);
+
+            ret += &rest[..len];
+            node_column = column;
+            column += len;
+            rest = &rest[len..];
+        }
+
+        if rest.starts_with('#') || rest == "---" || rest == "..." {
+            ret += &paint(self.colors.comment, rest);
+            return ret;
+        }
+
+        if let Some(end) = key_end(rest) {
+            ret += &paint(self.colors.key, &rest[..end]);
+            ret.push(':');
+
+            node_column = column;
+            rest = &rest[end + 1..];
+
+            let value = rest.trim_start();
+            ret += &rest[..rest.len() - value.len()];
+            rest = value;
+        }
+
+        let (value, comment) = split_comment(rest);
+
+        ret += &self.value(value, node_column);
+
+        if let Some(comment) = comment {
+            ret += &paint(self.colors.comment, comment);
+        }
+
+        ret
+    }
+
+    fn value(&mut self, value: &str, node_column: usize) -> String {
+        let color = match value {
+            "" => return String::new(),
+            _ if is_block_indicator(value) => {
+                self.block_indent = Some(node_column);
+                self.colors.block_scalar
+            }
+            _ if value.starts_with(['"', '\'']) => self.colors.string,
+            "true" | "True" | "TRUE" | "false" | "False" | "FALSE" | "null" | "Null" | "NULL"
+            | "~" => self.colors.boolean,
+            _ if is_number(value) => self.colors.number,
+            // アンカー、エイリアス、タグ、フローコレクションは装飾しない
+            _ if value.starts_with(['&', '*', '!', '{', '[']) => return value.to_string(),
+            _ => self.colors.string,
+        };
+
+        paint(color, value)
+    }
+}
+
+/// マッピングのキーの終わり（`:`の位置）
+fn key_end(s: &str) -> Option<usize> {
+    if s.starts_with(['{', '[']) {
+        return None;
+    }
+
+    let start = match s.chars().next() {
+        Some(quote @ ('"' | '\'')) => s[1..].find(quote)? + 2,
+        _ => 0,
+    };
+
+    let bytes = s.as_bytes();
+
+    (start..bytes.len())
+        .take_while(|&i| !(bytes[i] == b'#' && 0 < i && bytes[i - 1] == b' '))
+        .find(|&i| bytes[i] == b':' && (i + 1 == bytes.len() || bytes[i + 1] == b' '))
+}
+
+/// 値と行末のコメントに分ける
+fn split_comment(s: &str) -> (&str, Option<&str>) {
+    let start = match s.chars().next() {
+        Some(quote @ ('"' | '\'')) => s[1..].find(quote).map_or(s.len(), |i| i + 2),
+        _ => 0,
+    };
+
+    match s[start..].find(" #") {
+        Some(i) => {
+            let (value, comment) = s.split_at(start + i);
+            (value, Some(comment))
+        }
+        None => (s, None),
+    }
+}
+
+fn is_block_indicator(s: &str) -> bool {
+    s.starts_with(['|', '>'])
+        && s[1..]
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '+' || c == '-')
+}
+
+fn is_number(s: &str) -> bool {
+    let unsigned = s.trim_start_matches(['+', '-']);
+
+    if matches!(unsigned, ".inf" | ".Inf" | ".INF") || matches!(s, ".nan" | ".NaN" | ".NAN") {
+        return true;
+    }
+
+    if let Some(digits) = s.strip_prefix("0x") {
+        return !digits.is_empty() && digits.chars().all(|c| c.is_ascii_hexdigit());
+    }
+
+    if let Some(digits) = s.strip_prefix("0o") {
+        return !digits.is_empty() && digits.chars().all(|c| c.is_digit(8));
+    }
+
+    unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && unsigned.chars().any(|c| c.is_ascii_digit())
+        && s.parse::<f64>().is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Color;
+    use rstest::rstest;
+
+    const COLORS: YamlColors = YamlColors {
+        key: Color::Blue,
+        string: Color::Green,
+        number: Color::Magenta,
+        boolean: Color::Yellow,
+        comment: Color::DarkGray,
+        block_scalar: Color::Cyan,
+    };
+
+    fn highlight(yaml: &str) -> Vec<String> {
+        let mut highlighter = YamlHighlighter::new(COLORS);
+
+        yaml.lines().map(|line| highlighter.line(line)).collect()
+    }
+
+    #[test]
+    fn キーと値の種類ごとに色を付ける() {
+        let actual = highlight(indoc! {r#"
+            # comment
+            metadata:
+              name: app
+              labels:
+                "app.kubernetes.io/name": 'web'
+            spec:
+              replicas: 3
+              paused: false
+              ports:
+              - containerPort: 8080 # http
+              - 0.5
+        "#});
+
+        let key = |s: &str| paint(COLORS.key, s);
+
+        assert_eq!(
+            actual,
+            vec![
+                paint(COLORS.comment, "# comment"),
+                format!("{}:", key("metadata")),
+                format!("  {}: {}", key("name"), paint(COLORS.string, "app")),
+                format!("  {}:", key("labels")),
+                format!(
+                    "    {}: {}",
+                    key("\"app.kubernetes.io/name\""),
+                    paint(COLORS.string, "'web'")
+                ),
+                format!("{}:", key("spec")),
+                format!("  {}: {}", key("replicas"), paint(COLORS.number, "3")),
+                format!("  {}: {}", key("paused"), paint(COLORS.boolean, "false")),
+                format!("  {}:", key("ports")),
+                format!(
+                    "  - {}: {}{}",
+                    key("containerPort"),
+                    paint(COLORS.number, "8080"),
+                    paint(COLORS.comment, " # http")
+                ),
+                format!("  - {}", paint(COLORS.number, "0.5")),
+            ]
+        );
+    }
+
+    #[test]
+    fn インデントが戻るまでブロックスカラーとして扱う() {
+        let actual = highlight(indoc! {"
+            data:
+              script: |-
+                echo key: value
+
+                exit 0
+              next: 1
+        "});
+
+        assert_eq!(
+            actual,
+            vec![
+                format!("{}:", paint(COLORS.key, "data")),
+                format!(
+                    "  {}: {}",
+                    paint(COLORS.key, "script"),
+                    paint(COLORS.block_scalar, "|-")
+                ),
+                format!("    {}", paint(COLORS.block_scalar, "echo key: value")),
+                String::new(),
+                format!("    {}", paint(COLORS.block_scalar, "exit 0")),
+                format!(
+                    "  {}: {}",
+                    paint(COLORS.key, "next"),
+                    paint(COLORS.number, "1")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn エスケープシーケンスを含む行は変更しない() {
+        let line = paint(Color::Red, "key: value");
+
+        assert_eq!(highlight(&line), vec![line]);
+    }
+
+    #[rstest]
+    #[case("10", true)]
+    #[case("-1.5e3", true)]
+    #[case("0x1F", true)]
+    #[case(".inf", true)]
+    #[case("1.2.3", false)]
+    #[case("12:30", false)]
+    #[case("inf", false)]
+    fn 数値を判定する(#[case] value: &str, #[case] expected: bool) {
+        assert_eq!(is_number(value), expected);
+    }
+}
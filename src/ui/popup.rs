@@ -33,6 +33,8 @@ struct PopupChunkSize {
     width: f32,
     /// content height percentage (0.0 ~ 100.0)
     height: f32,
+    /// content height in rows (takes precedence over height)
+    fixed_height: Option<u16>,
 }
 
 impl Default for PopupChunkSize {
@@ -40,6 +42,7 @@ impl Default for PopupChunkSize {
         Self {
             width: 85.0,
             height: 85.0,
+            fixed_height: None,
         }
     }
 }
@@ -48,8 +51,12 @@ impl PopupChunkSize {
     fn chunk(&self, parent_chunk: Rect) -> Rect {
         let horizontal_margin =
             (parent_chunk.width as f32 * ((100.0 - self.width) / 2.0 / 100.0)).round() as u16;
-        let vertical_margin =
-            (parent_chunk.height as f32 * ((100.0 - self.height) / 2.0 / 100.0)).round() as u16;
+        let vertical_margin = match self.fixed_height {
+            Some(height) => parent_chunk.height.saturating_sub(height) / 2,
+            None => {
+                (parent_chunk.height as f32 * ((100.0 - self.height) / 2.0 / 100.0)).round() as u16
+            }
+        };
 
         parent_chunk.inner(&Margin {
             vertical: vertical_margin,
@@ -73,6 +80,18 @@ impl<'a> Popup<'a> {
         }
     }
 
+    /// 入力フォームのように高さが決まっているポップアップ
+    pub fn with_height(widget: Widget<'a>, height: u16) -> Self {
+        Self {
+            widget,
+            chunk: Default::default(),
+            chunk_size: PopupChunkSize {
+                fixed_height: Some(height),
+                ..Default::default()
+            },
+        }
+    }
+
     pub fn chunk(&self) -> Rect {
         self.chunk
     }
@@ -82,6 +82,8 @@ pub struct Tab<'a> {
     active_widget_index: usize,
     activatable_widget_indices: Vec<usize>,
     mouse_over_widget_index: Option<usize>,
+    chunk: Rect,
+    min_widget_height: Option<u16>,
 }
 
 #[allow(dead_code)]
@@ -94,24 +96,46 @@ impl<'a> Tab<'a> {
     ) -> Self {
         let widgets: Vec<_> = widgets.into();
 
-        let activatable_widget_indices = widgets
-            .iter()
-            .enumerate()
-            .filter(|(_, w)| w.can_activate())
-            .map(|(i, _)| i)
-            .collect();
-
         Self {
             id: id.into(),
             title: title.into(),
             nested_widget_layout: layout,
+            activatable_widget_indices: activatable_widget_indices(&widgets),
             widgets,
-            activatable_widget_indices,
             active_widget_index: 0,
             mouse_over_widget_index: None,
+            chunk: Rect::default(),
+            min_widget_height: None,
         }
     }
 
+    /// 縦に並べたウィジェットがこの高さを下回るときは、アクティブなウィジェットだけを表示する
+    ///
+    /// 表示するウィジェットはTab / BackTabで切り替える
+    pub fn min_widget_height(mut self, height: u16) -> Self {
+        self.min_widget_height = Some(height);
+        self
+    }
+
+    /// ウィジェットとレイアウトを入れ替える（アクティブなウィジェットは位置で引き継ぐ）
+    pub fn update_widgets(
+        &mut self,
+        widgets: impl Into<Vec<Widget<'a>>>,
+        layout: NestedWidgetLayout,
+    ) {
+        self.widgets = widgets.into();
+        self.nested_widget_layout = layout;
+        self.activatable_widget_indices = activatable_widget_indices(&self.widgets);
+
+        if self.widgets.len() <= self.active_widget_index {
+            self.active_widget_index = 0;
+        }
+
+        self.clear_mouse_over();
+
+        self.update_chunk(self.chunk);
+    }
+
     pub fn id(&self) -> &str {
         &self.id
     }
@@ -137,6 +161,8 @@ impl<'a> Tab<'a> {
 
         self.active_widget_index =
             (self.active_widget_index + 1) % self.activatable_widget_indices.len();
+
+        self.update_collapsed_chunk();
     }
 
     pub fn activate_prev_widget(&mut self) {
@@ -146,6 +172,8 @@ impl<'a> Tab<'a> {
 
         self.active_widget_index =
             (self.active_widget_index + activatable_widget_len - 1) % activatable_widget_len;
+
+        self.update_collapsed_chunk();
     }
 
     pub fn active_widget_id(&self) -> &str {
@@ -161,8 +189,41 @@ impl<'a> Tab<'a> {
     }
 
     pub fn update_chunk(&mut self, chunk: Rect) {
-        self.nested_widget_layout
-            .update_chunk(chunk, &mut self.widgets);
+        self.chunk = chunk;
+
+        if self.is_collapsed() {
+            self.update_collapsed_chunk();
+        } else {
+            self.nested_widget_layout
+                .update_chunk(chunk, &mut self.widgets);
+        }
+    }
+
+    /// ウィジェットを並べると最小の高さを下回るか
+    fn is_collapsed(&self) -> bool {
+        self.min_widget_height.is_some_and(|height| {
+            1 < self.widgets.len()
+                && (self.chunk.height as usize) < height as usize * self.widgets.len()
+        })
+    }
+
+    /// アクティブなウィジェットにタブ全体を割り当て、ほかのウィジェットは隠す
+    fn update_collapsed_chunk(&mut self) {
+        if !self.is_collapsed() {
+            return;
+        }
+
+        let active_widget_index = self.active_widget_index;
+
+        let chunk = self.chunk;
+
+        self.widgets.iter_mut().enumerate().for_each(|(i, w)| {
+            if i == active_widget_index {
+                w.update_chunk(chunk);
+            } else {
+                w.update_chunk(Rect::default());
+            }
+        });
     }
 
     pub fn activate_widget_by_id(&mut self, id: &str) {
@@ -170,6 +231,8 @@ impl<'a> Tab<'a> {
             self.clear_mouse_over();
 
             self.active_widget_index = index;
+
+            self.update_collapsed_chunk();
         }
     }
 
@@ -216,9 +279,24 @@ impl<'a> Tab<'a> {
     }
 }
 
+fn activatable_widget_indices(widgets: &[Widget]) -> Vec<usize> {
+    widgets
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| w.can_activate())
+        .map(|(i, _)| i)
+        .collect()
+}
+
 impl<'a> Tab<'a> {
     pub fn render(&mut self, f: &mut Frame) {
+        let is_collapsed = self.is_collapsed();
+
         self.widgets.iter_mut().enumerate().for_each(|(i, w)| {
+            if is_collapsed && i != self.active_widget_index {
+                return;
+            }
+
             w.render(
                 f,
                 i == self.active_widget_index,
@@ -227,3 +305,72 @@ impl<'a> Tab<'a> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn tab(height: u16) -> Tab<'static> {
+        let widgets: Vec<Widget> = ["a", "b", "c"]
+            .into_iter()
+            .map(|id| Table::builder().id(id).build().into())
+            .collect();
+
+        let layout = NestedWidgetLayout::default().nested_widget_layout(
+            (0..3)
+                .map(|i| {
+                    NestedLayoutElement(Constraint::Ratio(1, 3), LayoutElement::WidgetIndex(i))
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let mut tab = Tab::new("tab", "Tab", widgets, layout).min_widget_height(10);
+
+        tab.update_chunk(Rect::new(0, 0, 80, height));
+
+        tab
+    }
+
+    fn chunks(tab: &Tab) -> Vec<Rect> {
+        tab.as_ref_widgets().iter().map(|w| w.chunk()).collect()
+    }
+
+    #[test]
+    fn 最小の高さに収まるときはすべてのウィジェットを並べる() {
+        let tab = tab(30);
+
+        assert_eq!(
+            chunks(&tab),
+            vec![
+                Rect::new(0, 0, 80, 10),
+                Rect::new(0, 10, 80, 10),
+                Rect::new(0, 20, 80, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn 最小の高さに収まらないときはアクティブなウィジェットだけを表示する() {
+        let mut tab = tab(29);
+
+        assert_eq!(
+            chunks(&tab),
+            vec![Rect::new(0, 0, 80, 29), Rect::default(), Rect::default()]
+        );
+
+        tab.activate_next_widget();
+
+        assert_eq!(
+            chunks(&tab),
+            vec![Rect::default(), Rect::new(0, 0, 80, 29), Rect::default()]
+        );
+
+        tab.activate_widget_by_id("c");
+
+        assert_eq!(
+            chunks(&tab),
+            vec![Rect::default(), Rect::default(), Rect::new(0, 0, 80, 29)]
+        );
+    }
+}
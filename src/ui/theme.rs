@@ -0,0 +1,368 @@
+use std::fmt::Display;
+
+use once_cell::sync::OnceCell;
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+
+static THEME: OnceCell<Theme> = OnceCell::new();
+
+/// 画面全体で使う色とスタイル
+///
+/// 色は用途ごとに指定し、表示するテキストにはANSIエスケープシーケンスとして埋め込む
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub error: Color,
+    pub warning: Color,
+    pub success: Color,
+    pub info: Color,
+    /// 補足情報やフォーカスしていないタイトル
+    pub muted: Color,
+    /// 選択中のタブや行
+    pub selected: Style,
+    /// フォーカスしているウィジェットの枠線
+    pub border_active: Style,
+    pub border_inactive: Style,
+    pub border_mouse_over: Style,
+    pub tab_mouse_over: Style,
+    /// ログのプレフィックスに順番に割り当てる色
+    pub log_prefix: Vec<LogPrefixColor>,
+    /// ConfigMapやSecretのキーなどに順番に割り当てる色
+    pub palette: Vec<Color>,
+    /// yamlのシンタックスハイライト
+    pub yaml: YamlColors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YamlColors {
+    pub key: Color,
+    pub string: Color,
+    pub number: Color,
+    /// true、falseとnull
+    pub boolean: Color,
+    pub comment: Color,
+    /// | や > に続く複数行の文字列
+    pub block_scalar: Color,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogPrefixColor {
+    pub pod: Color,
+    pub container: Color,
+}
+
+impl LogPrefixColor {
+    const fn new(pod: Color, container: Color) -> Self {
+        Self { pod, container }
+    }
+}
+
+/// 組み込みのテーマ
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemePreset {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemePreset {
+    pub fn theme(&self) -> Theme {
+        match self {
+            Self::Dark => Theme::dark(),
+            Self::Light => Theme::light(),
+            Self::HighContrast => Theme::high_contrast(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// 起動時に1度だけ設定する
+    pub fn init(self) {
+        let _ = THEME.set(self);
+    }
+
+    pub fn global() -> &'static Self {
+        THEME.get_or_init(Self::default)
+    }
+
+    /// 暗い背景の端末向け
+    pub fn dark() -> Self {
+        Self {
+            error: Color::Red,
+            warning: Color::Yellow,
+            success: Color::LightGreen,
+            info: Color::LightCyan,
+            muted: Color::DarkGray,
+            selected: Style::default().add_modifier(Modifier::REVERSED),
+            border_active: Style::default(),
+            border_inactive: Style::default().fg(Color::DarkGray),
+            border_mouse_over: Style::default().fg(Color::Gray),
+            tab_mouse_over: Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::REVERSED),
+            log_prefix: vec![
+                LogPrefixColor::new(Color::LightGreen, Color::Green),
+                LogPrefixColor::new(Color::LightYellow, Color::Yellow),
+                LogPrefixColor::new(Color::LightBlue, Color::Blue),
+                LogPrefixColor::new(Color::LightMagenta, Color::Magenta),
+                LogPrefixColor::new(Color::LightCyan, Color::Cyan),
+                LogPrefixColor::new(Color::White, Color::Gray),
+            ],
+            palette: vec![
+                Color::Green,
+                Color::Yellow,
+                Color::Blue,
+                Color::Magenta,
+                Color::Cyan,
+                Color::Gray,
+            ],
+            yaml: YamlColors {
+                key: Color::LightBlue,
+                string: Color::Green,
+                number: Color::Magenta,
+                boolean: Color::Yellow,
+                comment: Color::DarkGray,
+                block_scalar: Color::Cyan,
+            },
+        }
+    }
+
+    /// 明るい背景の端末向け
+    ///
+    /// 明るい背景で読みにくい黄色や白は256色の濃い色で置き換える
+    pub fn light() -> Self {
+        Self {
+            error: Color::Red,
+            warning: Color::Indexed(130),
+            success: Color::Green,
+            info: Color::Blue,
+            muted: Color::DarkGray,
+            selected: Style::default().add_modifier(Modifier::REVERSED),
+            border_active: Style::default(),
+            border_inactive: Style::default().fg(Color::Gray),
+            border_mouse_over: Style::default().fg(Color::DarkGray),
+            tab_mouse_over: Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::REVERSED),
+            log_prefix: vec![
+                LogPrefixColor::new(Color::Green, Color::Indexed(22)),
+                LogPrefixColor::new(Color::Indexed(130), Color::Indexed(94)),
+                LogPrefixColor::new(Color::Blue, Color::Indexed(18)),
+                LogPrefixColor::new(Color::Magenta, Color::Indexed(90)),
+                LogPrefixColor::new(Color::Indexed(30), Color::Indexed(23)),
+                LogPrefixColor::new(Color::Black, Color::DarkGray),
+            ],
+            palette: vec![
+                Color::Green,
+                Color::Indexed(130),
+                Color::Blue,
+                Color::Magenta,
+                Color::Indexed(30),
+                Color::DarkGray,
+            ],
+            yaml: YamlColors {
+                key: Color::Blue,
+                string: Color::Green,
+                number: Color::Magenta,
+                boolean: Color::Indexed(130),
+                comment: Color::DarkGray,
+                block_scalar: Color::Indexed(30),
+            },
+        }
+    }
+
+    /// 明るい色と太字で区別しやすくしたテーマ
+    pub fn high_contrast() -> Self {
+        Self {
+            error: Color::LightRed,
+            warning: Color::LightYellow,
+            success: Color::LightGreen,
+            info: Color::LightCyan,
+            muted: Color::Gray,
+            selected: Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+            border_active: Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            border_inactive: Style::default().fg(Color::Gray),
+            border_mouse_over: Style::default().fg(Color::White),
+            tab_mouse_over: Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::REVERSED),
+            log_prefix: vec![
+                LogPrefixColor::new(Color::LightGreen, Color::LightGreen),
+                LogPrefixColor::new(Color::LightYellow, Color::LightYellow),
+                LogPrefixColor::new(Color::LightBlue, Color::LightBlue),
+                LogPrefixColor::new(Color::LightMagenta, Color::LightMagenta),
+                LogPrefixColor::new(Color::LightCyan, Color::LightCyan),
+                LogPrefixColor::new(Color::White, Color::White),
+            ],
+            palette: vec![
+                Color::LightGreen,
+                Color::LightYellow,
+                Color::LightBlue,
+                Color::LightMagenta,
+                Color::LightCyan,
+                Color::White,
+            ],
+            yaml: YamlColors {
+                key: Color::LightBlue,
+                string: Color::LightGreen,
+                number: Color::LightMagenta,
+                boolean: Color::LightYellow,
+                comment: Color::Gray,
+                block_scalar: Color::LightCyan,
+            },
+        }
+    }
+
+    /// NO_COLORが設定されているときのテーマ
+    ///
+    /// 色は使わず、反転や太字などの装飾だけで区別する
+    pub fn no_color() -> Self {
+        Self {
+            error: Color::Reset,
+            warning: Color::Reset,
+            success: Color::Reset,
+            info: Color::Reset,
+            muted: Color::Reset,
+            selected: Style::default().add_modifier(Modifier::REVERSED),
+            border_active: Style::default().add_modifier(Modifier::BOLD),
+            border_inactive: Style::default(),
+            border_mouse_over: Style::default(),
+            tab_mouse_over: Style::default().add_modifier(Modifier::UNDERLINED),
+            log_prefix: vec![LogPrefixColor::new(Color::Reset, Color::Reset)],
+            palette: vec![Color::Reset],
+            yaml: YamlColors {
+                key: Color::Reset,
+                string: Color::Reset,
+                number: Color::Reset,
+                boolean: Color::Reset,
+                comment: Color::Reset,
+                block_scalar: Color::Reset,
+            },
+        }
+    }
+
+    /// NO_COLOR（https://no-color.org/）が空でない値で設定されているか
+    pub fn is_no_color() -> bool {
+        std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+    }
+
+    /// ログプレフィックスの色（indexは要素数で割った余りを使う）
+    pub fn log_prefix_color(&self, index: usize) -> LogPrefixColor {
+        self.log_prefix
+            .get(index % self.log_prefix.len().max(1))
+            .copied()
+            .unwrap_or(LogPrefixColor::new(Color::Reset, Color::Reset))
+    }
+}
+
+/// 前景色をANSIエスケープシーケンスで付与する
+///
+/// Color::Resetのときは装飾しない
+pub fn paint(color: Color, s: impl Display) -> String {
+    match sgr_foreground(color) {
+        Some(code) => format!("\x1b[{}m{}\x1b[39m", code, s),
+        None => s.to_string(),
+    }
+}
+
+/// ratatuiの色をSGRの前景色のパラメータに変換する
+///
+/// GrayとWhiteはui::widget::ansi_colorの変換（37 => White、97 => Gray）に合わせる
+fn sgr_foreground(color: Color) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::White => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::Gray => 97,
+        Color::Indexed(n) => return Some(format!("38;5;{}", n)),
+        Color::Rgb(r, g, b) => return Some(format!("38;2;{};{};{}", r, g, b)),
+    };
+
+    Some(code.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::ui::widget::ansi_color::generate_style_from_ansi_color;
+
+    #[rstest]
+    #[case(Color::Red, "\x1b[31mtext\x1b[39m")]
+    #[case(Color::DarkGray, "\x1b[90mtext\x1b[39m")]
+    #[case(Color::Indexed(130), "\x1b[38;5;130mtext\x1b[39m")]
+    #[case(Color::Rgb(1, 2, 3), "\x1b[38;2;1;2;3mtext\x1b[39m")]
+    #[case(Color::Reset, "text")]
+    fn 色に応じたエスケープシーケンスを付与する(
+        #[case] color: Color,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(paint(color, "text"), expected);
+    }
+
+    #[rstest]
+    #[case(Theme::dark())]
+    #[case(Theme::light())]
+    #[case(Theme::high_contrast())]
+    fn パレットの色はansiパーサで元の色に戻る(#[case] theme: Theme) {
+        let colors = theme
+            .palette
+            .iter()
+            .chain(theme.log_prefix.iter().flat_map(|c| [&c.pod, &c.container]))
+            .chain([&theme.error, &theme.warning, &theme.muted])
+            .chain([
+                &theme.yaml.key,
+                &theme.yaml.string,
+                &theme.yaml.number,
+                &theme.yaml.boolean,
+                &theme.yaml.comment,
+                &theme.yaml.block_scalar,
+            ]);
+
+        for color in colors {
+            let params: Vec<u8> = sgr_foreground(*color)
+                .unwrap()
+                .split(';')
+                .map(|n| n.parse().unwrap())
+                .collect();
+
+            assert_eq!(
+                generate_style_from_ansi_color(params),
+                Style::default().fg(*color)
+            );
+        }
+    }
+
+    #[test]
+    fn no_colorのときは装飾しない() {
+        let theme = Theme::no_color();
+
+        assert_eq!(paint(theme.error, "error"), "error");
+        assert_eq!(paint(theme.log_prefix_color(3).pod, "pod"), "pod");
+    }
+}
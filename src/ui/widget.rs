@@ -1,4 +1,4 @@
-mod ansi_color;
+pub(super) mod ansi_color;
 mod spans;
 mod styled_graphemes;
 mod wrap;
@@ -9,14 +9,18 @@ pub mod input;
 pub mod list;
 pub mod table;
 pub mod text;
+pub mod tree;
 
 pub use complex::*;
 pub use list::*;
 pub use table::*;
 pub use text::*;
+pub use tree::*;
 
 use self::{config::WidgetConfig, input::InputForm, styled_graphemes::StyledGraphemes};
 
+use crate::kube::table::KubeTableRow;
+
 use super::event::EventResult;
 
 use crossterm::event::{KeyEvent, MouseEvent};
@@ -86,11 +90,60 @@ impl From<Vec<String>> for TableItem {
     }
 }
 
+/// namespaceとnameをメタデータに含める
+impl From<KubeTableRow> for TableItem {
+    fn from(
+        KubeTableRow {
+            namespace,
+            name,
+            metadata,
+            row,
+        }: KubeTableRow,
+    ) -> Self {
+        let mut item_metadata = BTreeMap::from([
+            ("namespace".to_string(), namespace),
+            ("name".to_string(), name),
+        ]);
+
+        if let Some(metadata) = metadata {
+            item_metadata.extend(metadata);
+        }
+
+        Self::new(row, Some(item_metadata))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeItem {
+    pub item: LiteralItem,
+    pub children: Vec<TreeItem>,
+}
+
+impl TreeItem {
+    pub fn new(item: impl Into<LiteralItem>, children: impl Into<Vec<TreeItem>>) -> Self {
+        Self {
+            item: item.into(),
+            children: children.into(),
+        }
+    }
+
+    /// 開閉状態を保持するための識別子
+    /// metadataにkeyがあればそれを使い、なければ表示文字列を使う
+    pub fn key(&self) -> &str {
+        self.item
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("key"))
+            .unwrap_or(&self.item.item)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Item {
     Single(LiteralItem),
     Array(Vec<LiteralItem>),
     Table(Vec<TableItem>),
+    Tree(Vec<TreeItem>),
 }
 
 impl Item {
@@ -118,6 +171,14 @@ impl Item {
         }
     }
 
+    pub fn tree(self) -> Vec<TreeItem> {
+        if let Self::Tree(v) = self {
+            v
+        } else {
+            panic!("called tree() on {:?}", self)
+        }
+    }
+
     pub fn as_array(&self) -> &[LiteralItem] {
         if let Self::Array(v) = self {
             v
@@ -210,6 +271,7 @@ pub enum Widget<'a> {
     SingleSelect(SingleSelect<'a>),
     MultipleSelect(MultipleSelect<'a>),
     Input(InputForm),
+    Tree(Tree<'a>),
 }
 
 #[allow(dead_code)]
@@ -255,6 +317,14 @@ impl<'a> Widget<'a> {
         }
     }
 
+    pub fn as_tree(&self) -> &Tree<'a> {
+        if let Self::Tree(w) = self {
+            w
+        } else {
+            panic!("called as_tree() on {:?}", self)
+        }
+    }
+
     // as_mut_*
     pub fn as_mut_list(&mut self) -> &mut List<'a> {
         if let Self::List(w) = self {
@@ -295,4 +365,12 @@ impl<'a> Widget<'a> {
             panic!("called as_mut_multiple_select() on {:?}", self)
         }
     }
+
+    pub fn as_mut_tree(&mut self) -> &mut Tree<'a> {
+        if let Self::Tree(w) = self {
+            w
+        } else {
+            panic!("called as_mut_tree() on {:?}", self)
+        }
+    }
 }
@@ -1,11 +1,13 @@
 use std::{borrow::Cow, fmt::Display};
 
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, BorderType, Borders},
 };
 
+use crate::ui::theme::Theme;
+
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct WidgetConfigBuilder(WidgetConfig);
 
@@ -114,7 +116,7 @@ impl WidgetConfig {
                 title.insert(0, " ".into());
 
                 title.iter_mut().for_each(|span| {
-                    span.style = span.style.fg(Color::DarkGray);
+                    span.style = span.style.fg(Theme::global().muted);
                 });
             }
         } else {
@@ -127,19 +129,17 @@ impl WidgetConfig {
     /// Render Block
     ///
     /// Active:   ─ + Title ───  (BOLD)
-    /// Inactive: ─── Title ───  (DarkGray: title is Raw)
+    /// Inactive: ─── Title ───  (muted: title is Raw)
     pub fn render_block(&self, is_active: bool, is_mouse_over: bool) -> Block<'static> {
         let block = if self.can_activate {
+            let theme = Theme::global();
+
             if is_active {
-                self.block.clone()
+                self.block.clone().border_style(theme.border_active)
             } else if is_mouse_over {
-                self.block
-                    .clone()
-                    .border_style(Style::default().fg(Color::Gray))
+                self.block.clone().border_style(theme.border_mouse_over)
             } else {
-                self.block
-                    .clone()
-                    .border_style(Style::default().fg(Color::DarkGray))
+                self.block.clone().border_style(theme.border_inactive)
             }
         } else {
             self.block.clone()
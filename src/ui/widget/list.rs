@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use ratatui::{
     layout::Rect,
-    style::{Modifier, Style},
+    style::Style,
     widgets::{self, Block, ListState, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
 };
@@ -18,6 +18,7 @@ use crate::{
     ui::{
         event::{Callback, EventResult},
         key_event_to_code,
+        theme::Theme,
         util::{MousePosition, RectContainsPoint},
         Window,
     },
@@ -176,7 +177,7 @@ impl<'a> List<'a> {
         widgets::List::new(self.items.widget_items().to_vec())
             .block(block)
             .style(Style::default())
-            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_style(Theme::global().selected)
     }
 
     fn showable_height(&self) -> usize {
@@ -5,23 +5,25 @@ use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKin
 use derivative::*;
 use ratatui::{
     layout::{Constraint, Rect},
-    style::{Modifier, Style, Stylize},
+    style::{Style, Stylize},
     widgets::{
         Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Table as TuiTable, TableState,
     },
     Frame,
 };
-use std::rc::Rc;
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use filter_form::FilterForm;
 use item::InnerItem;
 
 use crate::{
+    clipboard::Clipboard,
     define_callback, logger,
     message::UserEvent,
     ui::{
         event::{Callback, EventResult},
         key_event_to_code,
+        theme::Theme,
         util::{MousePosition, RectContainsPoint},
         Window,
     },
@@ -59,6 +61,8 @@ pub struct TableBuilder {
     block_injection: Option<RenderBlockInjection>,
     #[derivative(Debug = "ignore")]
     highlight_injection: Option<RenderHighlightInjection>,
+    #[derivative(Debug = "ignore")]
+    clipboard: Option<Rc<RefCell<Clipboard>>>,
 }
 
 #[allow(dead_code)]
@@ -129,6 +133,12 @@ impl TableBuilder {
         self
     }
 
+    /// 'Y'で選択している行をクリップボードにコピーする
+    pub fn clipboard(mut self, clipboard: Rc<RefCell<Clipboard>>) -> Self {
+        self.clipboard = Some(clipboard);
+        self
+    }
+
     pub fn build(self) -> Table<'static> {
         let mut table = Table {
             id: self.id,
@@ -139,6 +149,7 @@ impl TableBuilder {
             show_status: self.show_status,
             block_injection: self.block_injection,
             highlight_injection: self.highlight_injection,
+            clipboard: self.clipboard,
             filtered_key: self.filtered_key.clone(),
             ..Default::default()
         };
@@ -221,6 +232,8 @@ pub struct Table<'a> {
     block_injection: Option<RenderBlockInjection>,
     #[derivative(Debug = "ignore")]
     highlight_injection: Option<RenderHighlightInjection>,
+    #[derivative(Debug = "ignore")]
+    clipboard: Option<Rc<RefCell<Clipboard>>>,
 }
 
 impl<'a> Table<'a> {
@@ -236,6 +249,26 @@ impl<'a> Table<'a> {
         &self.state
     }
 
+    /// フィルター前のアイテム
+    pub fn original_items(&self) -> &[TableItem] {
+        self.items.original_items()
+    }
+
+    pub fn metadata_filter(&self) -> &BTreeMap<String, String> {
+        self.items.metadata_filter()
+    }
+
+    /// メタデータの値がすべて一致する行だけを表示する
+    pub fn update_metadata_filter(&mut self, filter: BTreeMap<String, String>) {
+        let old_len = self.items.len();
+
+        self.items.update_metadata_filter(filter);
+
+        self.adjust_selected(old_len, self.items.len());
+
+        self.update_row_bounds();
+    }
+
     pub fn equal_header(&self, header: &[String]) -> bool {
         self.items.header().original() == header
     }
@@ -251,6 +284,7 @@ impl<'a> Table<'a> {
             .header(header)
             .items(rows)
             .filtered_key(self.filtered_key.clone())
+            .metadata_filter(self.items.metadata_filter().clone())
             .max_width(self.max_width())
             .build();
 
@@ -537,6 +571,10 @@ impl WidgetTrait for Table<'_> {
                     self.mode.filter_input();
                 }
 
+                KeyCode::Char('Y') if self.clipboard.is_some() => {
+                    self.copy_selected_row();
+                }
+
                 KeyCode::Char('q') | KeyCode::Esc if self.mode.is_filter_confirm() => {
                     self.filter_cancel();
                 }
@@ -615,6 +653,26 @@ impl WidgetTrait for Table<'_> {
 }
 
 impl<'a> Table<'a> {
+    /// 選択している行の列をタブ区切りでコピーする
+    fn copy_selected_row(&self) {
+        let Some(item) = self
+            .state
+            .selected()
+            .and_then(|index| self.items().get(index))
+        else {
+            return;
+        };
+
+        let contents = item.item.join("\t");
+
+        if let Some(clipboard) = &self.clipboard {
+            logger!(info, "Clipboard saved '{}'", contents);
+            if let Err(e) = clipboard.borrow_mut().set_contents(contents) {
+                logger!(error, "Clipboard Error '{}'", e);
+            }
+        }
+    }
+
     fn on_select_callback(&self) -> Option<Callback> {
         self.on_select.clone().and_then(|cb| {
             self.selected_item()
@@ -640,20 +698,20 @@ impl<'a> Table<'a> {
         if let Some(highlight_injection) = &self.highlight_injection {
             highlight_injection(self.selected_item().as_deref())
         } else if let Some(item) = self.selected_item() {
-            let mut style = Style::default().add_modifier(Modifier::REVERSED);
+            let mut style = Theme::global().selected;
 
             if let Some(item) = item.item.first() {
                 let sg = styled_graphemes::styled_graphemes(item);
 
                 if let Some(first) = sg.first() {
                     if let Some(fg) = first.style().fg {
-                        style = Style::default().fg(fg).add_modifier(Modifier::REVERSED);
+                        style = style.fg(fg);
                     }
                 }
             }
             style
         } else {
-            Style::default().add_modifier(Modifier::REVERSED)
+            Theme::global().selected
         }
     }
 }
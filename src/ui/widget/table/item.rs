@@ -3,7 +3,7 @@ use ratatui::{
     style::{Color, Style},
     widgets::{Cell, Row},
 };
-use std::ops::Deref;
+use std::{collections::BTreeMap, ops::Deref};
 
 use crate::{
     logger,
@@ -23,6 +23,7 @@ pub struct InnerItemBuilder {
     items: Vec<TableItem>,
     max_width: usize,
     filtered_key: String,
+    metadata_filter: BTreeMap<String, String>,
 }
 
 impl InnerItemBuilder {
@@ -46,15 +47,22 @@ impl InnerItemBuilder {
         self
     }
 
+    pub fn metadata_filter(mut self, filter: BTreeMap<String, String>) -> Self {
+        self.metadata_filter = filter;
+        self
+    }
+
     pub fn build(self) -> InnerItem<'static> {
         let mut inner_item = InnerItem {
             header: Header::new(self.header),
             original_items: self.items.clone(),
             filtered_items: self.items,
             filtered_key: self.filtered_key,
+            metadata_filter: self.metadata_filter,
             ..Default::default()
         };
 
+        inner_item.inner_filter_items();
         inner_item.update_max_width(self.max_width);
 
         inner_item
@@ -79,6 +87,8 @@ pub struct InnerItem<'a> {
     max_width: usize,
     filtered_key: String,
     filtered_word: String,
+    /// メタデータの値がすべて一致する行だけを表示する
+    metadata_filter: BTreeMap<String, String>,
 }
 
 impl<'a> InnerItem<'a> {
@@ -102,6 +112,15 @@ impl<'a> InnerItem<'a> {
         &self.filtered_items
     }
 
+    /// フィルター前のアイテム
+    pub fn original_items(&self) -> &[TableItem] {
+        &self.original_items
+    }
+
+    pub fn metadata_filter(&self) -> &BTreeMap<String, String> {
+        &self.metadata_filter
+    }
+
     pub fn rendered_items(&self) -> &[InnerRow] {
         &self.rendered_items
     }
@@ -134,32 +153,45 @@ impl<'a> InnerItem<'a> {
         self.inner_filter_items();
         self.inner_update_rendered_items();
     }
+
+    pub fn update_metadata_filter(&mut self, filter: BTreeMap<String, String>) {
+        self.metadata_filter = filter;
+        self.inner_filter_items();
+        self.inner_update_rendered_items();
+    }
 }
 
 impl<'a> InnerItem<'a> {
     fn inner_filter_items(&mut self) {
-        self.filtered_items = if self.filtered_word.is_empty() {
-            self.original_items.clone()
-        } else {
-            self.original_items
-                .iter()
-                .filter_map(|item| {
-                    let choice = item.item[self.filtered_index()]
-                        .styled_graphemes_symbols()
-                        .concat();
-
-                    if self
-                        .filtered_word
-                        .split(' ')
-                        .any(|pattern| choice.contains(pattern))
-                    {
-                        Some(item.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect()
+        self.filtered_items = self
+            .original_items
+            .iter()
+            .filter(|item| self.matches_metadata_filter(item) && self.matches_filtered_word(item))
+            .cloned()
+            .collect();
+    }
+
+    fn matches_filtered_word(&self, item: &TableItem) -> bool {
+        if self.filtered_word.is_empty() {
+            return true;
         }
+
+        let choice = item.item[self.filtered_index()]
+            .styled_graphemes_symbols()
+            .concat();
+
+        self.filtered_word
+            .split(' ')
+            .any(|pattern| choice.contains(pattern))
+    }
+
+    fn matches_metadata_filter(&self, item: &TableItem) -> bool {
+        self.metadata_filter.iter().all(|(key, value)| {
+            item.metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get(key))
+                .is_some_and(|v| v == value)
+        })
     }
 
     fn inner_update_rendered_items(&mut self) {
@@ -386,4 +418,62 @@ mod tests {
             assert_eq!(actual, 0);
         }
     }
+
+    mod metadata_filter {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn item(name: &str, kind: &str) -> TableItem {
+            TableItem {
+                metadata: Some(BTreeMap::from([("kind".to_string(), kind.to_string())])),
+                item: vec![name.to_string()],
+            }
+        }
+
+        #[test]
+        fn メタデータが一致する行だけを表示する() {
+            let mut inner_item = InnerItem::builder()
+                .header(vec!["NAME".to_string()])
+                .items(vec![
+                    item("a", "Pod"),
+                    item("b", "Deployment"),
+                    TableItem {
+                        metadata: None,
+                        item: vec!["c".to_string()],
+                    },
+                ])
+                .filtered_key("NAME")
+                .build();
+
+            inner_item
+                .update_metadata_filter(BTreeMap::from([("kind".to_string(), "Pod".to_string())]));
+
+            assert_eq!(inner_item.items(), vec![item("a", "Pod")]);
+            assert_eq!(inner_item.original_items().len(), 3);
+
+            inner_item.update_metadata_filter(BTreeMap::new());
+
+            assert_eq!(inner_item.items().len(), 3);
+        }
+
+        #[test]
+        fn 文字列のフィルターと組み合わせる() {
+            let mut inner_item = InnerItem::builder()
+                .header(vec!["NAME".to_string()])
+                .items(vec![
+                    item("app", "Pod"),
+                    item("db", "Pod"),
+                    item("app", "Job"),
+                ])
+                .filtered_key("NAME")
+                .metadata_filter(BTreeMap::from([("kind".to_string(), "Pod".to_string())]))
+                .build();
+
+            assert_eq!(inner_item.items().len(), 2);
+
+            inner_item.update_filter("app");
+
+            assert_eq!(inner_item.items(), vec![item("app", "Pod")]);
+        }
+    }
 }
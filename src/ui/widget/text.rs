@@ -437,6 +437,10 @@ impl Text {
         (self.scroll.y, self.scroll_y_last_index())
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.item.is_empty()
+    }
+
     fn match_action(&self, ev: UserEvent) -> Option<&Callback> {
         self.actions
             .iter()
@@ -0,0 +1,486 @@
+use std::{collections::HashSet, rc::Rc};
+
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
+use derivative::*;
+use ratatui::{layout::Rect, Frame};
+
+use super::{
+    config::WidgetConfig, Item, List, LiteralItem, RenderTrait, SelectedItem, TreeItem, WidgetTrait,
+};
+
+use crate::{
+    define_callback,
+    ui::{
+        event::{Callback, EventResult},
+        key_event_to_code, Window,
+    },
+};
+
+define_callback!(pub OnSelectCallback, Fn(&mut Window, &LiteralItem) -> EventResult);
+
+const KEY_SEPARATOR: &str = "\n";
+
+/// 表示中の1行に対応するノード情報
+#[derive(Debug, Default, Clone)]
+struct TreeRow {
+    /// ルートからのパスで表したノードの識別子
+    key: String,
+    /// 親ノードの行インデックス
+    parent: Option<usize>,
+    has_children: bool,
+    item: LiteralItem,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug, Default)]
+pub struct TreeBuilder {
+    id: String,
+    widget_config: WidgetConfig,
+    items: Vec<TreeItem>,
+    #[derivative(Debug = "ignore")]
+    on_select: Option<OnSelectCallback>,
+}
+
+#[allow(dead_code)]
+impl TreeBuilder {
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn widget_config(mut self, widget_config: &WidgetConfig) -> Self {
+        self.widget_config = widget_config.clone();
+        self
+    }
+
+    pub fn items(mut self, items: impl Into<Vec<TreeItem>>) -> Self {
+        self.items = items.into();
+        self
+    }
+
+    /// Enterキーで呼ばれるコールバック
+    /// 未設定のときはEnterキーでノードの開閉を行う
+    pub fn on_select<F>(mut self, cb: F) -> Self
+    where
+        F: Into<OnSelectCallback>,
+    {
+        self.on_select = Some(cb.into());
+        self
+    }
+
+    pub fn build(self) -> Tree<'static> {
+        let mut tree = Tree {
+            list: List::builder()
+                .id(self.id)
+                .widget_config(&self.widget_config)
+                .build(),
+            on_select: self.on_select,
+            ..Default::default()
+        };
+
+        tree.update_widget_item(Item::Tree(self.items));
+        tree
+    }
+}
+
+/// 折りたたみ可能なツリー表示のウィジェット
+///
+/// 描画と選択はListに委譲し、開閉状態に応じて平坦化した行を渡す
+#[derive(Derivative)]
+#[derivative(Debug, Default)]
+pub struct Tree<'a> {
+    items: Vec<TreeItem>,
+    collapsed: HashSet<String>,
+    rows: Vec<TreeRow>,
+    list: List<'a>,
+    #[derivative(Debug = "ignore")]
+    on_select: Option<OnSelectCallback>,
+}
+
+#[allow(dead_code)]
+impl Tree<'_> {
+    pub fn builder() -> TreeBuilder {
+        TreeBuilder::default()
+    }
+
+    pub fn items(&self) -> &[TreeItem] {
+        &self.items
+    }
+
+    /// 選択中のノードを展開する
+    pub fn expand(&mut self) {
+        let Some(key) = self.selected_row().map(|row| row.key.clone()) else {
+            return;
+        };
+
+        if self.collapsed.remove(&key) {
+            self.refresh();
+        }
+    }
+
+    /// 選択中のノードを折りたたむ
+    /// 子を持たないか既に折りたたまれているときは親ノードを選択する
+    pub fn collapse(&mut self) {
+        let Some(row) = self.selected_row().cloned() else {
+            return;
+        };
+
+        if row.has_children && !self.collapsed.contains(&row.key) {
+            self.collapsed.insert(row.key);
+            self.refresh();
+        } else if let Some(parent) = row.parent {
+            self.list.select_index(parent);
+        }
+    }
+
+    /// 選択中のノードの開閉を切り替える
+    pub fn toggle(&mut self) {
+        let Some(row) = self.selected_row().cloned() else {
+            return;
+        };
+
+        if !row.has_children {
+            return;
+        }
+
+        if !self.collapsed.remove(&row.key) {
+            self.collapsed.insert(row.key);
+        }
+
+        self.refresh();
+    }
+
+    fn selected_row(&self) -> Option<&TreeRow> {
+        self.list
+            .state()
+            .selected()
+            .and_then(|index| self.rows.get(index))
+    }
+
+    /// 開閉状態をもとに表示行を作り直し、選択中のノードを維持する
+    fn refresh(&mut self) {
+        let selected_key = self.selected_row().map(|row| row.key.clone());
+
+        let mut rows = Vec::new();
+        let mut lines = Vec::new();
+
+        flatten(
+            &self.items,
+            &self.collapsed,
+            &FlattenContext::default(),
+            &mut rows,
+            &mut lines,
+        );
+
+        self.rows = rows;
+        self.list.update_widget_item(Item::Array(lines));
+
+        if let Some(index) =
+            selected_key.and_then(|key| self.rows.iter().position(|row| row.key == key))
+        {
+            self.list.select_index(index);
+        }
+    }
+
+    fn on_select_callback(&self) -> Option<Callback> {
+        self.on_select.clone().and_then(|cb| {
+            self.selected_row()
+                .map(|row| Rc::new(row.item.clone()))
+                .map(|item| Callback::new(move |w| cb(w, &item)))
+        })
+    }
+}
+
+#[derive(Default)]
+struct FlattenContext<'a> {
+    parent_key: Option<&'a str>,
+    parent: Option<usize>,
+    /// 罫線のインデント
+    indent: String,
+    depth: usize,
+}
+
+fn flatten(
+    items: &[TreeItem],
+    collapsed: &HashSet<String>,
+    ctx: &FlattenContext,
+    rows: &mut Vec<TreeRow>,
+    lines: &mut Vec<LiteralItem>,
+) {
+    for (i, item) in items.iter().enumerate() {
+        let is_last = i + 1 == items.len();
+
+        let key = match ctx.parent_key {
+            Some(parent_key) => format!("{}{}{}", parent_key, KEY_SEPARATOR, item.key()),
+            None => item.key().to_string(),
+        };
+
+        let has_children = !item.children.is_empty();
+        let is_collapsed = collapsed.contains(&key);
+
+        let branch = match (ctx.depth, is_last) {
+            (0, _) => "",
+            (_, true) => "└─ ",
+            (_, false) => "├─ ",
+        };
+
+        let marker = match (has_children, is_collapsed) {
+            (false, _) => "  ",
+            (true, true) => "▸ ",
+            (true, false) => "▾ ",
+        };
+
+        lines.push(LiteralItem::new(
+            format!("{}{}{}{}", ctx.indent, branch, marker, item.item.item),
+            item.item.metadata.clone(),
+        ));
+
+        let index = rows.len();
+
+        rows.push(TreeRow {
+            key: key.clone(),
+            parent: ctx.parent,
+            has_children,
+            item: item.item.clone(),
+        });
+
+        if has_children && !is_collapsed {
+            let indent = match (ctx.depth, is_last) {
+                (0, _) => String::new(),
+                (_, true) => format!("{}   ", ctx.indent),
+                (_, false) => format!("{}│  ", ctx.indent),
+            };
+
+            let child_ctx = FlattenContext {
+                parent_key: Some(&key),
+                parent: Some(index),
+                indent,
+                depth: ctx.depth + 1,
+            };
+
+            flatten(&item.children, collapsed, &child_ctx, rows, lines);
+        }
+    }
+}
+
+impl WidgetTrait for Tree<'_> {
+    fn id(&self) -> &str {
+        self.list.id()
+    }
+
+    fn widget_config(&self) -> &WidgetConfig {
+        self.list.widget_config()
+    }
+
+    fn widget_config_mut(&mut self) -> &mut WidgetConfig {
+        self.list.widget_config_mut()
+    }
+
+    fn can_activate(&self) -> bool {
+        true
+    }
+
+    fn widget_item(&self) -> Option<SelectedItem> {
+        self.selected_row().map(|row| row.item.clone().into())
+    }
+
+    fn chunk(&self) -> Rect {
+        self.list.chunk()
+    }
+
+    fn select_index(&mut self, index: usize) {
+        self.list.select_index(index)
+    }
+
+    fn select_next(&mut self, index: usize) {
+        self.list.select_next(index)
+    }
+
+    fn select_prev(&mut self, index: usize) {
+        self.list.select_prev(index)
+    }
+
+    fn select_first(&mut self) {
+        self.list.select_first()
+    }
+
+    fn select_last(&mut self) {
+        self.list.select_last()
+    }
+
+    fn append_widget_item(&mut self, _: Item) {
+        unimplemented!()
+    }
+
+    fn update_widget_item(&mut self, item: Item) {
+        self.items = item.tree();
+        self.refresh();
+    }
+
+    fn on_mouse_event(&mut self, ev: MouseEvent) -> EventResult {
+        self.list.on_mouse_event(ev)
+    }
+
+    fn on_key_event(&mut self, ev: KeyEvent) -> EventResult {
+        match key_event_to_code(ev) {
+            KeyCode::Enter => {
+                if let Some(cb) = self.on_select_callback() {
+                    return EventResult::Callback(cb);
+                }
+
+                self.toggle();
+            }
+
+            KeyCode::Char(' ') => {
+                self.toggle();
+            }
+
+            KeyCode::Right => {
+                self.expand();
+            }
+
+            KeyCode::Left => {
+                self.collapse();
+            }
+
+            _ => {
+                return self.list.on_key_event(ev);
+            }
+        }
+
+        EventResult::Nop
+    }
+
+    fn update_chunk(&mut self, chunk: Rect) {
+        self.list.update_chunk(chunk)
+    }
+
+    fn clear(&mut self) {
+        self.items = Default::default();
+        self.collapsed = Default::default();
+        self.rows = Default::default();
+        self.list.clear();
+    }
+}
+
+impl RenderTrait for Tree<'_> {
+    fn render(&mut self, f: &mut Frame, is_active: bool, is_mouse_over: bool) {
+        self.list.render(f, is_active, is_mouse_over)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn items() -> Vec<TreeItem> {
+        vec![TreeItem::new(
+            "Deployment/app",
+            vec![
+                TreeItem::new(
+                    "ReplicaSet/app-1",
+                    vec![
+                        TreeItem::new("Pod/app-1-a", vec![]),
+                        TreeItem::new("Pod/app-1-b", vec![]),
+                    ],
+                ),
+                TreeItem::new(
+                    "ReplicaSet/app-2",
+                    vec![TreeItem::new("Pod/app-2-a", vec![])],
+                ),
+            ],
+        )]
+    }
+
+    fn lines(tree: &Tree) -> Vec<String> {
+        tree.list.items().iter().map(|i| i.item.clone()).collect()
+    }
+
+    #[test]
+    fn 罫線付きで平坦化する() {
+        let mut tree = Tree::default();
+        tree.update_widget_item(Item::Tree(items()));
+
+        assert_eq!(
+            lines(&tree),
+            vec![
+                "▾ Deployment/app",
+                "├─ ▾ ReplicaSet/app-1",
+                "│  ├─   Pod/app-1-a",
+                "│  └─   Pod/app-1-b",
+                "└─ ▾ ReplicaSet/app-2",
+                "   └─   Pod/app-2-a",
+            ]
+        );
+    }
+
+    #[test]
+    fn 折りたたんだノードの子は表示しない() {
+        let mut tree = Tree::default();
+        tree.update_widget_item(Item::Tree(items()));
+
+        tree.select_index(1);
+        tree.toggle();
+
+        assert_eq!(
+            lines(&tree),
+            vec![
+                "▾ Deployment/app",
+                "├─ ▸ ReplicaSet/app-1",
+                "└─ ▾ ReplicaSet/app-2",
+                "   └─   Pod/app-2-a",
+            ]
+        );
+
+        tree.expand();
+
+        assert_eq!(lines(&tree).len(), 6);
+    }
+
+    #[test]
+    fn 子を持たないノードで折りたたむと親ノードを選択する() {
+        let mut tree = Tree::default();
+        tree.update_widget_item(Item::Tree(items()));
+
+        tree.select_index(3);
+        tree.collapse();
+
+        assert_eq!(tree.list.state().selected(), Some(1));
+    }
+
+    #[test]
+    fn 更新後も開閉状態と選択中のノードを維持する() {
+        let mut tree = Tree::default();
+        tree.update_widget_item(Item::Tree(items()));
+
+        tree.select_index(1);
+        tree.toggle();
+        tree.select_index(2);
+
+        let mut updated = items();
+        updated[0].children.insert(
+            0,
+            TreeItem::new(
+                "ReplicaSet/app-0",
+                vec![TreeItem::new("Pod/app-0-a", vec![])],
+            ),
+        );
+
+        tree.update_widget_item(Item::Tree(updated));
+
+        assert_eq!(
+            lines(&tree),
+            vec![
+                "▾ Deployment/app",
+                "├─ ▾ ReplicaSet/app-0",
+                "│  └─   Pod/app-0-a",
+                "├─ ▸ ReplicaSet/app-1",
+                "└─ ▾ ReplicaSet/app-2",
+                "   └─   Pod/app-2-a",
+            ]
+        );
+
+        assert_eq!(tree.list.state().selected(), Some(4));
+    }
+}
@@ -4,9 +4,9 @@ use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKin
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::Style,
     text::{Line, Span},
-    widgets::{Block, Paragraph, Tabs},
+    widgets::{Block, Borders, Clear, Paragraph, Tabs},
     Frame,
 };
 
@@ -17,12 +17,15 @@ use crate::{define_callback, logger, message::UserEvent, workers::kube::message:
 use super::{
     event::{Callback, EventResult},
     popup::Popup,
+    theme::Theme,
     util::{key_event_to_code, MousePosition, RectContainsPoint},
     widget::{Widget, WidgetTrait},
     Tab,
 };
 
 define_callback!(pub HeaderCallback, Fn() -> Paragraph<'static>);
+define_callback!(pub ToastCallback, Fn() -> Vec<Line<'static>>);
+define_callback!(pub StatusBarCallback, Fn() -> Line<'static>);
 
 #[derive(Default)]
 pub struct Window<'a> {
@@ -35,6 +38,8 @@ pub struct Window<'a> {
     popups: Vec<Popup<'a>>,
     open_popup_id: Option<String>,
     header: Option<Header<'a>>,
+    toast: Option<Toast>,
+    status_bar: Option<StatusBarCallback>,
     layout_index: WindowLayoutIndex,
     last_known_size: Rect,
 }
@@ -44,6 +49,7 @@ struct WindowLayoutIndex {
     tab: usize,
     header: usize,
     contents: usize,
+    status_bar: usize,
 }
 
 pub enum HeaderContent<'a> {
@@ -91,12 +97,36 @@ impl<'a> Header<'a> {
     }
 }
 
+/// コンテンツの右上に重ねて表示する通知
+///
+/// コールバックが空の行を返す間は表示しない
+pub struct Toast {
+    title: String,
+    border_style: Style,
+    content: ToastCallback,
+}
+
+impl Toast {
+    pub fn new<F>(title: impl Into<String>, border_style: Style, content: F) -> Self
+    where
+        F: Into<ToastCallback>,
+    {
+        Self {
+            title: title.into(),
+            border_style,
+            content: content.into(),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct WindowBuilder<'a> {
     tabs: Vec<Tab<'a>>,
     callbacks: Vec<(UserEvent, Callback)>,
     popups: Vec<Popup<'a>>,
     header: Option<Header<'a>>,
+    toast: Option<Toast>,
+    status_bar: Option<StatusBarCallback>,
 }
 
 impl<'a> WindowBuilder<'a> {
@@ -124,35 +154,34 @@ impl<'a> WindowBuilder<'a> {
         self
     }
 
+    pub fn toast(mut self, toast: Toast) -> Self {
+        self.toast = Some(toast);
+        self
+    }
+
+    /// ウィンドウの最下行に表示する
+    pub fn status_bar(mut self, status_bar: impl Into<StatusBarCallback>) -> Self {
+        self.status_bar = Some(status_bar.into());
+        self
+    }
+
     pub fn build(self) -> Window<'a> {
-        let (layout_index, constraints) = if let Some(header) = &self.header {
-            (
-                WindowLayoutIndex {
-                    tab: 0,
-                    header: 2,
-                    contents: 3,
-                },
-                vec![
-                    Constraint::Length(1),
-                    Constraint::Length(1),
-                    Constraint::Length(header.height),
-                    Constraint::Min(1),
-                ],
-            )
-        } else {
-            (
-                WindowLayoutIndex {
-                    tab: 0,
-                    header: 0,
-                    contents: 2,
-                },
-                vec![
-                    Constraint::Length(1),
-                    Constraint::Length(1),
-                    Constraint::Min(1),
-                ],
-            )
-        };
+        let mut layout_index = WindowLayoutIndex::default();
+
+        let mut constraints = vec![Constraint::Length(1), Constraint::Length(1)];
+
+        if let Some(header) = &self.header {
+            layout_index.header = constraints.len();
+            constraints.push(Constraint::Length(header.height));
+        }
+
+        layout_index.contents = constraints.len();
+        constraints.push(Constraint::Min(1));
+
+        if self.status_bar.is_some() {
+            layout_index.status_bar = constraints.len();
+            constraints.push(Constraint::Length(1));
+        }
 
         let layout = Layout::default()
             .direction(Direction::Vertical)
@@ -164,6 +193,8 @@ impl<'a> WindowBuilder<'a> {
             callbacks: self.callbacks,
             popups: self.popups,
             header: self.header,
+            toast: self.toast,
+            status_bar: self.status_bar,
             layout_index,
             ..Default::default()
         }
@@ -206,9 +237,7 @@ impl<'a> Window<'a> {
                 {
                     Line::from(Span::styled(
                         Self::tab_title_format(tab_index, tab.title()),
-                        Style::default()
-                            .fg(Color::DarkGray)
-                            .add_modifier(Modifier::REVERSED),
+                        Theme::global().tab_mouse_over,
                     ))
                 } else {
                     Line::from(Self::tab_title_format(tab_index, tab.title()))
@@ -219,7 +248,7 @@ impl<'a> Window<'a> {
         Tabs::new(titles)
             .block(Self::tab_block())
             .select(self.active_tab_index)
-            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_style(Theme::global().selected)
     }
 
     pub fn match_callback(&self, ev: UserEvent) -> Option<Callback> {
@@ -271,6 +300,10 @@ impl<'a> Window<'a> {
         &mut self.tabs[self.active_tab_index]
     }
 
+    pub fn find_tab_mut(&mut self, id: &str) -> Option<&mut Tab<'a>> {
+        self.tabs.iter_mut().find(|tab| tab.id() == id)
+    }
+
     pub fn activate_tab_by_index(&mut self, index: usize) {
         if self.tabs.get(index).is_some() {
             self.active_tab_index = index;
@@ -384,6 +417,10 @@ impl<'a> Window<'a> {
 
         self.render_contents(f);
 
+        self.render_status_bar(f);
+
+        self.render_toast(f);
+
         self.render_popup(f);
     }
 
@@ -405,6 +442,54 @@ impl<'a> Window<'a> {
         self.active_tab_mut().render(f);
     }
 
+    fn render_status_bar(&self, f: &mut Frame) {
+        if let Some(status_bar) = &self.status_bar {
+            f.render_widget(
+                Paragraph::new((status_bar)()),
+                self.chunks()[self.layout_index.status_bar],
+            );
+        }
+    }
+
+    fn render_toast(&self, f: &mut Frame) {
+        let Some(toast) = &self.toast else {
+            return;
+        };
+
+        let lines = (toast.content)();
+
+        if lines.is_empty() {
+            return;
+        }
+
+        let contents = self.chunks()[self.layout_index.contents];
+
+        let content_width = lines
+            .iter()
+            .map(Line::width)
+            .max()
+            .unwrap_or_default()
+            .max(toast.title.width());
+
+        let width = (content_width as u16 + 2).min(contents.width.saturating_sub(2));
+        let height = (lines.len() as u16 + 2).min(contents.height.saturating_sub(1));
+
+        let chunk = Rect::new(
+            contents.right().saturating_sub(width + 1),
+            contents.top() + 1,
+            width,
+            height,
+        );
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(toast.border_style)
+            .title(toast.title.as_str());
+
+        f.render_widget(Clear, chunk);
+        f.render_widget(Paragraph::new(lines).block(block), chunk);
+    }
+
     fn render_popup(&mut self, f: &mut Frame) {
         if let Some(id) = &self.open_popup_id {
             if let Some(popup) = self.popups.iter_mut().find(|p| p.id() == id) {
@@ -424,6 +509,8 @@ pub enum WindowAction {
     CloseWindow,
     Continue,
     UpdateContents(Kube),
+    /// ウィジェットに紐づかないエラー
+    Error(anyhow::Error),
 }
 
 // Event
@@ -1,3 +1,4 @@
+pub mod interval;
 pub mod kube;
 mod render;
 mod tick;
@@ -0,0 +1,233 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use once_cell::sync::OnceCell;
+use tokio::sync::Notify;
+
+static INTERVAL_CONFIG: OnceCell<IntervalConfig> = OnceCell::new();
+
+static IS_FOCUSED: AtomicBool = AtomicBool::new(true);
+static FOCUS_GAINED: Notify = Notify::const_new();
+
+/// 失敗が続いたときに間隔を倍にする回数の上限
+const MAX_BACKOFF_EXPONENT: u32 = 10;
+
+/// ワーカーごとのポーリング間隔
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalConfig {
+    pub pod: Duration,
+    pub event: Duration,
+    pub network: Duration,
+    pub config: Duration,
+    pub workload: Duration,
+    /// API Resourcesで選択したリソースの一覧
+    pub api: Duration,
+    /// API Resourcesの一覧（discovery）
+    pub api_discovery: Duration,
+    /// 詳細表示（YAML、describeなど）
+    pub description: Duration,
+    /// ログの再取得
+    pub log: Duration,
+    /// 取得したログの描画への反映
+    pub log_collect: Duration,
+    /// 画面の再描画
+    pub tick: Duration,
+    /// バックオフで広げる間隔の上限
+    pub max_backoff: Duration,
+    /// 端末がフォーカスを失っている間に間隔を何倍にするか
+    pub unfocused_multiplier: u32,
+}
+
+impl Default for IntervalConfig {
+    fn default() -> Self {
+        Self {
+            pod: Duration::from_secs(1),
+            event: Duration::from_secs(1),
+            network: Duration::from_secs(1),
+            config: Duration::from_secs(1),
+            workload: Duration::from_secs(1),
+            api: Duration::from_secs(1),
+            api_discovery: Duration::from_secs(10),
+            description: Duration::from_secs(3),
+            log: Duration::from_secs(3),
+            log_collect: Duration::from_millis(200),
+            tick: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(60),
+            unfocused_multiplier: 5,
+        }
+    }
+}
+
+impl IntervalConfig {
+    /// 起動時に1度だけ設定する
+    pub fn init(self) {
+        let _ = INTERVAL_CONFIG.set(self);
+    }
+
+    pub fn global() -> &'static Self {
+        INTERVAL_CONFIG.get_or_init(Self::default)
+    }
+
+    /// 失敗回数とフォーカスの状態を考慮した間隔
+    fn period(&self, base: Duration, failures: u32, is_focused: bool) -> Duration {
+        let mut period = base;
+
+        if 0 < failures {
+            let backoff = base.saturating_mul(1 << failures.min(MAX_BACKOFF_EXPONENT));
+            period = backoff.min(self.max_backoff).max(base);
+        }
+
+        if !is_focused {
+            period = period.saturating_mul(self.unfocused_multiplier.max(1));
+        }
+
+        period
+    }
+}
+
+/// 端末のフォーカス状態を更新する
+pub fn set_focused(is_focused: bool) {
+    IS_FOCUSED.store(is_focused, Ordering::Relaxed);
+
+    if is_focused {
+        FOCUS_GAINED.notify_waiters();
+    }
+}
+
+pub fn is_focused() -> bool {
+    IS_FOCUSED.load(Ordering::Relaxed)
+}
+
+/// フォーカスを考慮した間隔（同期処理用）
+pub fn current_period(base: Duration) -> Duration {
+    IntervalConfig::global().period(base, 0, is_focused())
+}
+
+/// 失敗時のバックオフとフォーカスを失っている間の減速を行うインターバル
+///
+/// 初回のtickはすぐに返る
+#[derive(Debug)]
+pub struct AdaptiveInterval {
+    base: Duration,
+    failures: u32,
+    is_first: bool,
+}
+
+impl AdaptiveInterval {
+    pub fn new(base: Duration) -> Self {
+        Self {
+            base,
+            failures: 0,
+            is_first: true,
+        }
+    }
+
+    pub async fn tick(&mut self) {
+        if self.is_first {
+            self.is_first = false;
+            return;
+        }
+
+        let period = self.period();
+
+        if is_focused() {
+            tokio::time::sleep(period).await;
+        } else {
+            // フォーカスが戻ったらすぐに再開する
+            tokio::select! {
+                _ = tokio::time::sleep(period) => {}
+                _ = FOCUS_GAINED.notified() => {}
+            }
+        }
+    }
+
+    pub fn period(&self) -> Duration {
+        IntervalConfig::global().period(self.base, self.failures, is_focused())
+    }
+
+    /// リクエストの結果を記録する
+    /// 失敗が続くと間隔を広げ、429（Too Many Requests）のときは上限まで広げる
+    pub fn record<T>(&mut self, result: &anyhow::Result<T>) {
+        match result {
+            Ok(_) => self.failures = 0,
+            Err(err) if is_too_many_requests(err) => self.failures = MAX_BACKOFF_EXPONENT,
+            Err(_) => self.failures = (self.failures + 1).min(MAX_BACKOFF_EXPONENT),
+        }
+    }
+}
+
+fn is_too_many_requests(err: &anyhow::Error) -> bool {
+    err.chain().any(|e| {
+        matches!(
+            e.downcast_ref::<kube::Error>(),
+            Some(kube::Error::Api(res)) if res.code == 429
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use kube::error::ErrorResponse;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::success(0, true, Duration::from_secs(1))]
+    #[case::failure_once(1, true, Duration::from_secs(2))]
+    #[case::failure_three_times(3, true, Duration::from_secs(8))]
+    #[case::capped(MAX_BACKOFF_EXPONENT, true, Duration::from_secs(60))]
+    #[case::unfocused(0, false, Duration::from_secs(5))]
+    #[case::unfocused_and_failure(1, false, Duration::from_secs(10))]
+    fn 失敗回数とフォーカスに応じて間隔を広げる(
+        #[case] failures: u32,
+        #[case] is_focused: bool,
+        #[case] expected: Duration,
+    ) {
+        let config = IntervalConfig::default();
+
+        let actual = config.period(Duration::from_secs(1), failures, is_focused);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 上限より長い間隔はバックオフしても短くしない() {
+        let config = IntervalConfig {
+            max_backoff: Duration::from_secs(5),
+            ..Default::default()
+        };
+
+        let actual = config.period(Duration::from_secs(10), 3, true);
+
+        assert_eq!(actual, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn _429のときは上限までバックオフする() {
+        let mut interval = AdaptiveInterval::new(Duration::from_secs(1));
+
+        let err: anyhow::Result<()> = Err(kube::Error::Api(ErrorResponse {
+            status: "Failure".into(),
+            message: "Too Many Requests".into(),
+            reason: "TooManyRequests".into(),
+            code: 429,
+        })
+        .into());
+
+        interval.record(&err);
+
+        assert_eq!(interval.failures, MAX_BACKOFF_EXPONENT);
+
+        interval.record(&Ok(()));
+
+        assert_eq!(interval.failures, 0);
+
+        interval.record::<()>(&Err(anyhow!("error")));
+
+        assert_eq!(interval.failures, 1);
+    }
+}
@@ -2,11 +2,14 @@ pub mod color;
 mod config;
 mod controller;
 pub mod message;
+mod reflector;
 mod store;
 mod worker;
 
 pub use config::KubeWorkerConfig;
 pub use controller::*;
+pub use reflector::*;
+pub use store::SavedKubeState;
 pub use worker::*;
 
 use std::sync::{
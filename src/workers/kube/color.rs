@@ -1,59 +1,38 @@
-pub mod fg {
+use ratatui::style::Color;
 
-    #[allow(dead_code)]
-    #[derive(Clone, Copy)]
-    pub enum Color {
-        Reset = 39,
+use crate::ui::theme::Theme;
 
-        Red = 31,
-        Green = 32,
-        Yellow = 33,
-        Blue = 34,
-        Magenta = 35,
-        Cyan = 36,
-        Gray = 37,
-
-        DarkGray = 90,
-        LightRed = 91,
-        LightGreen = 92,
-        LightYellow = 93,
-        LightBlue = 94,
-        LightMagenta = 95,
-        LightCyan = 96,
-        White = 97,
-    }
-
-    impl Color {
-        pub fn wrap(&self, s: impl Into<String>) -> String {
-            format!("\x1b[{}m{}\x1b[39m", *self as u8, s.into())
-        }
-    }
+/// テーマのパレットの色を先頭から順番に返す
+pub struct Palette {
+    colors: &'static [Color],
+    index: usize,
 }
 
-const COLOR: [u8; 6] = [
-    fg::Color::Green as u8,
-    fg::Color::Yellow as u8,
-    fg::Color::Blue as u8,
-    fg::Color::Magenta as u8,
-    fg::Color::Cyan as u8,
-    fg::Color::Gray as u8,
-];
-
-pub struct Color {
-    index: usize,
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl Color {
+impl Palette {
     pub fn new() -> Self {
-        Self { index: 0 }
+        Self::with_colors(&Theme::global().palette)
     }
 
-    pub fn next_color(&mut self) -> u8 {
-        if COLOR.len() <= self.index {
+    fn with_colors(colors: &'static [Color]) -> Self {
+        Self { colors, index: 0 }
+    }
+
+    pub fn next_color(&mut self) -> Color {
+        if self.colors.is_empty() {
+            return Color::Reset;
+        }
+
+        if self.colors.len() <= self.index {
             self.index = 0;
         }
         self.index += 1;
-        COLOR[self.index - 1]
+        self.colors[self.index - 1]
     }
 }
 
@@ -61,39 +40,41 @@ impl Color {
 mod tests {
     use super::*;
 
+    const COLORS: [Color; 3] = [Color::Green, Color::Yellow, Color::Blue];
+
     #[test]
     fn color_default() {
-        let mut color = Color::new();
-        assert_eq!(color.next_color(), 32)
+        let mut palette = Palette::with_colors(&COLORS);
+        assert_eq!(palette.next_color(), Color::Green)
     }
 
     #[test]
     fn color_next_1() {
-        let mut color = Color::new();
-        color.next_color();
-        assert_eq!(color.next_color(), 33)
+        let mut palette = Palette::with_colors(&COLORS);
+        palette.next_color();
+        assert_eq!(palette.next_color(), Color::Yellow)
     }
 
     #[test]
     fn color_next_last() {
-        let mut color = Color::new();
-        color.next_color();
-        color.next_color();
-        color.next_color();
-        color.next_color();
-        color.next_color();
-        assert_eq!(color.next_color(), 37)
+        let mut palette = Palette::with_colors(&COLORS);
+        palette.next_color();
+        palette.next_color();
+        assert_eq!(palette.next_color(), Color::Blue)
     }
 
     #[test]
     fn color_next_loop() {
-        let mut color = Color::new();
-        color.next_color();
-        color.next_color();
-        color.next_color();
-        color.next_color();
-        color.next_color();
-        color.next_color();
-        assert_eq!(color.next_color(), 32)
+        let mut palette = Palette::with_colors(&COLORS);
+        palette.next_color();
+        palette.next_color();
+        palette.next_color();
+        assert_eq!(palette.next_color(), Color::Green)
+    }
+
+    #[test]
+    fn color_empty() {
+        let mut palette = Palette::with_colors(&[]);
+        assert_eq!(palette.next_color(), Color::Reset)
     }
 }
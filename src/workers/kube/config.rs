@@ -1,8 +1,10 @@
-use std::{ops::Deref, path::PathBuf};
+use std::{collections::BTreeMap, ops::Deref, path::PathBuf};
 
 use anyhow::{anyhow, Result};
 use kube::config::{Kubeconfig, KubeconfigError};
 
+use crate::session::SharedSession;
+
 use super::TargetNamespaces;
 
 #[derive(Debug, Default, Clone)]
@@ -11,6 +13,10 @@ pub struct KubeWorkerConfig {
     pub target_namespaces: Option<TargetNamespaces>,
     pub context: Option<String>,
     pub all_namespaces: bool,
+    /// 設定ファイルで指定したコンテキストごとのnamespace
+    pub context_namespaces: BTreeMap<String, TargetNamespaces>,
+    /// 起動時に復元し、終了時にcontextごとの状態を書き込む
+    pub session: SharedSession,
 }
 
 pub struct Context(String);
@@ -28,6 +28,11 @@ use crate::{
             message::ConfigMessage,
         },
         context::message::{ContextMessage, ContextRequest, ContextResponse},
+        describe::{kube::DescribeWorker, message::DescribeMessage},
+        edit::{
+            kube::{replace, target_api},
+            message::{EditMessage, EditRequest, EditResponse},
+        },
         event::kube::EventPoller,
         get::{kube::yaml::GetYamlWorker, message::GetMessage},
         namespace::message::{NamespaceMessage, NamespaceRequest, NamespaceResponse},
@@ -36,22 +41,28 @@ use crate::{
             message::NetworkMessage,
         },
         pod::{
-            kube::{LogWorker, PodPoller},
-            message::LogMessage,
+            kube::{LogWorker, PodDetailWorker, PodPoller},
+            message::{LogMessage, PodDetailMessage},
+        },
+        workload::{
+            kube::{OwnerTreeWorker, WorkloadDescriptionWorker, WorkloadPoller},
+            message::{WorkloadMessage, WorkloadRequest},
         },
         yaml::{
-            kube::{FetchResourceList, YamlWorker},
+            kube::{save_bundle, FetchResourceList, YamlWorker},
             message::{YamlMessage, YamlRequest, YamlResponse},
         },
     },
-    kube::KubeClient,
+    kube::{api_status::ApiStatus, KubeClient},
+    logger,
     message::Message,
+    session::SharedSession,
     workers::kube::message::Kube,
 };
 
 use super::{
     config::{read_kubeconfig, Context, KubeWorkerConfig},
-    store::{KubeState, KubeStore},
+    store::{KubeState, KubeStore, SessionBaseline},
     worker::Worker,
     AbortWorker as _,
 };
@@ -91,6 +102,8 @@ pub struct KubeController {
     kubeconfig: Kubeconfig,
     context: String,
     store: KubeStore,
+    session: SharedSession,
+    baseline: SessionBaseline,
 }
 
 impl KubeController {
@@ -105,14 +118,49 @@ impl KubeController {
             target_namespaces,
             context,
             all_namespaces,
+            context_namespaces,
+            session,
         } = config;
 
         let kubeconfig = read_kubeconfig(kubeconfig)?;
 
-        let context = Context::try_from(&kubeconfig, context)?;
+        let (saved_context, saved_states) = {
+            let session = session.lock().expect("Failed to lock session");
+            (session.context.clone(), session.contexts.clone())
+        };
+
+        // 最後に表示していたcontextがkubeconfigから消えていたときはcurrent-contextを使う
+        let saved_context = saved_context.filter(|saved| {
+            let exists = kubeconfig.contexts.iter().any(|ctx| &ctx.name == saved);
+
+            if !exists {
+                logger!(warn, "context {} in session is not found", saved);
+            }
+
+            exists
+        });
+
+        let baseline_context = Context::try_from(&kubeconfig, saved_context.clone())?;
+
+        let context = Context::try_from(&kubeconfig, context.or(saved_context))?;
 
         let mut store = KubeStore::try_from_kubeconfig(kubeconfig.clone()).await?;
 
+        for (context, namespaces) in context_namespaces {
+            if namespaces.is_empty() {
+                continue;
+            }
+
+            match store.get_mut(&context) {
+                Ok(state) => state.target_namespaces = namespaces,
+                Err(_) => logger!(warn, "context {} in config file is not found", context),
+            }
+        }
+
+        store.restore(saved_states);
+
+        let baseline_states = store.saved_states();
+
         let KubeState {
             client: state_client,
             target_namespaces: state_of_target_namespaces,
@@ -129,6 +177,13 @@ impl KubeController {
             *state_of_target_namespaces = target_namespaces;
         }
 
+        let baseline = SessionBaseline {
+            context: baseline_context.to_string(),
+            startup_context: context.to_string(),
+            states: baseline_states,
+            startup_states: store.saved_states(),
+        };
+
         Ok(Self {
             tx,
             rx,
@@ -136,6 +191,8 @@ impl KubeController {
             kubeconfig,
             context: context.to_string(),
             store,
+            session,
+            baseline,
         })
     }
 
@@ -147,6 +204,8 @@ impl KubeController {
             kubeconfig,
             mut context,
             mut store,
+            session,
+            baseline,
         } = self;
 
         while !is_terminated.load(Ordering::Relaxed) {
@@ -193,6 +252,7 @@ impl KubeController {
             let config_handle = ConfigPoller::new(poller_base.clone()).spawn();
             let network_handle = NetworkPoller::new(poller_base.clone()).spawn();
             let event_handle = EventPoller::new(poller_base.clone()).spawn();
+            let workload_handle = WorkloadPoller::new(poller_base.clone()).spawn();
             let api_handle = ApiPoller::new(
                 poller_base.clone(),
                 shared_target_api_resources.clone(),
@@ -206,6 +266,7 @@ impl KubeController {
                 config_handle,
                 network_handle,
                 event_handle,
+                workload_handle,
                 api_handle,
             ];
 
@@ -232,8 +293,22 @@ impl KubeController {
                             );
 
                             context = ctx;
+
+                            ApiStatus::reset();
+                        }
+                        WorkerResult::Terminated => {
+                            let target_namespaces = shared_target_namespaces.read().await;
+                            let target_api_resources = shared_target_api_resources.read().await;
+
+                            store.insert(
+                                context.to_string(),
+                                KubeState::new(
+                                    client.clone(),
+                                    target_namespaces.to_vec(),
+                                    target_api_resources.to_vec(),
+                                ),
+                            );
                         }
-                        WorkerResult::Terminated => {}
                     },
                     Err(e) => {
                         Self::abort(&handles);
@@ -243,6 +318,13 @@ impl KubeController {
             }
         }
 
+        let mut session = session.lock().expect("Failed to lock session");
+
+        let (context, contexts) = baseline.resolve(context, store.saved_states());
+
+        session.context = Some(context);
+        session.contexts = contexts;
+
         Ok(())
     }
 
@@ -286,10 +368,14 @@ impl Worker for EventController {
 
     async fn run(&self) -> Self::Output {
         let mut log_handler: Option<AbortHandle> = None;
+        let mut pod_detail_handler: Option<AbortHandle> = None;
         let mut config_handler: Option<AbortHandle> = None;
         let mut network_handler: Option<AbortHandle> = None;
         let mut yaml_handler: Option<AbortHandle> = None;
         let mut get_handler: Option<AbortHandle> = None;
+        let mut describe_handler: Option<AbortHandle> = None;
+        let mut workload_handler: Option<AbortHandle> = None;
+        let mut owner_tree_handler: Option<AbortHandle> = None;
 
         let EventController {
             base: poll_worker,
@@ -333,6 +419,11 @@ impl Worker for EventController {
                                 log_handler = None;
                             }
 
+                            if let Some(handler) = pod_detail_handler {
+                                handler.abort();
+                                pod_detail_handler = None;
+                            }
+
                             if let Some(handler) = config_handler {
                                 handler.abort();
                                 config_handler = None;
@@ -353,6 +444,21 @@ impl Worker for EventController {
                                 get_handler = None;
                             }
 
+                            if let Some(handler) = describe_handler {
+                                handler.abort();
+                                describe_handler = None;
+                            }
+
+                            if let Some(handler) = workload_handler {
+                                handler.abort();
+                                workload_handler = None;
+                            }
+
+                            if let Some(handler) = owner_tree_handler {
+                                handler.abort();
+                                owner_tree_handler = None;
+                            }
+
                             tx.send(NamespaceResponse::Set(req).into())
                                 .expect("Failed to send NamespaceResponse:Set");
                         }
@@ -368,6 +474,24 @@ impl Worker for EventController {
                         task::yield_now().await;
                     }
 
+                    Kube::PodDetail(PodDetailMessage::Request(req)) => {
+                        if let Some(handler) = pod_detail_handler {
+                            handler.abort();
+                        }
+
+                        pod_detail_handler = Some(
+                            PodDetailWorker::new(
+                                is_terminated.clone(),
+                                tx,
+                                kube_client.clone(),
+                                req,
+                            )
+                            .spawn(),
+                        );
+
+                        task::yield_now().await;
+                    }
+
                     Kube::Config(ConfigMessage::Request(req)) => {
                         if let Some(handler) = config_handler {
                             handler.abort();
@@ -399,6 +523,36 @@ impl Worker for EventController {
                                     shared_target_api_resources.write().await;
                                 *target_api_resources = req.clone();
                             }
+                            SetByKeys(keys) => {
+                                let api_resources = shared_api_resources.read().await;
+
+                                let (found, missing): (Vec<_>, Vec<_>) = keys
+                                    .into_iter()
+                                    .map(|key| (api_resources.find_by_key(&key).cloned(), key))
+                                    .partition(|(api, _)| api.is_some());
+
+                                let found: Vec<_> =
+                                    found.into_iter().filter_map(|(api, _)| api).collect();
+
+                                {
+                                    let mut target_api_resources =
+                                        shared_target_api_resources.write().await;
+                                    *target_api_resources = found.clone();
+                                }
+
+                                // 選択ポップアップに項目がないと選択状態を反映できない
+                                tx.send(ApiResponse::Get(Ok(api_resources.to_vec())).into())
+                                    .expect("Failed to send ApiResponse::Get");
+
+                                tx.send(
+                                    ApiResponse::SetByKeys {
+                                        api_resources: found,
+                                        missing: missing.into_iter().map(|(_, key)| key).collect(),
+                                    }
+                                    .into(),
+                                )
+                                .expect("Failed to send ApiResponse::SetByKeys");
+                            }
                         }
                     }
 
@@ -411,6 +565,10 @@ impl Worker for EventController {
                                 h.abort();
                             }
 
+                            if let Some(h) = pod_detail_handler {
+                                h.abort();
+                            }
+
                             if let Some(h) = config_handler {
                                 h.abort();
                             }
@@ -427,6 +585,18 @@ impl Worker for EventController {
                                 h.abort();
                             }
 
+                            if let Some(h) = describe_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = workload_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = owner_tree_handler {
+                                h.abort();
+                            }
+
                             return WorkerResult::ChangedContext(req);
                         }
                     },
@@ -473,17 +643,93 @@ impl Worker for EventController {
                                 );
                                 task::yield_now().await;
                             }
+                            SaveBundle { path, overwrite } => {
+                                // すべてのリソースを取得するまで時間がかかるため、ロックを持たずに別タスクで保存する
+                                let target_api_resources =
+                                    shared_target_api_resources.read().await.to_vec();
+                                let target_namespaces =
+                                    shared_target_namespaces.read().await.to_vec();
+
+                                let kube_client = kube_client.clone();
+                                let tx = tx.clone();
+
+                                task::spawn(async move {
+                                    let saved = save_bundle(
+                                        &kube_client,
+                                        &target_api_resources,
+                                        &target_namespaces,
+                                        path,
+                                        overwrite,
+                                    )
+                                    .await;
+
+                                    tx.send(YamlResponse::Saved(saved).into())
+                                        .expect("Failed to send YamlResponse::Saved");
+                                });
+                            }
                         }
                     }
 
+                    Kube::Edit(EditMessage::Request(req)) => {
+                        let (object, dry_run) = match req {
+                            EditRequest::DryRun(object) => (object, true),
+                            EditRequest::Apply(object) => (object, false),
+                        };
+
+                        // APIの解決だけロックを持って行い、サーバーの応答は別タスクで待つ
+                        let target =
+                            target_api(kube_client, &*shared_api_resources.read().await, &object);
+
+                        let tx = tx.clone();
+
+                        task::spawn(async move {
+                            let res = match target {
+                                Ok((api, name)) => replace(&api, &name, &object, dry_run).await,
+                                Err(err) => Err(err.into()),
+                            };
+
+                            let res = if dry_run {
+                                EditResponse::DryRun(res)
+                            } else {
+                                EditResponse::Applied(res)
+                            };
+
+                            tx.send(res.into()).expect("Failed to send EditResponse");
+                        });
+                    }
+
                     Kube::Get(GetMessage::Request(req)) => {
                         if let Some(handler) = get_handler {
                             handler.abort();
                         }
 
                         get_handler = Some(
-                            GetYamlWorker::new(is_terminated.clone(), tx, kube_client.clone(), req)
-                                .spawn(),
+                            GetYamlWorker::new(
+                                is_terminated.clone(),
+                                tx,
+                                kube_client.clone(),
+                                shared_api_resources.clone(),
+                                req,
+                            )
+                            .spawn(),
+                        );
+                        task::yield_now().await;
+                    }
+
+                    Kube::Describe(DescribeMessage::Request(req)) => {
+                        if let Some(handler) = describe_handler {
+                            handler.abort();
+                        }
+
+                        describe_handler = Some(
+                            DescribeWorker::new(
+                                is_terminated.clone(),
+                                tx,
+                                kube_client.clone(),
+                                shared_api_resources.clone(),
+                                req,
+                            )
+                            .spawn(),
                         );
                         task::yield_now().await;
                     }
@@ -505,6 +751,45 @@ impl Worker for EventController {
 
                         task::yield_now().await;
                     }
+
+                    Kube::Workload(WorkloadMessage::Request(req)) => {
+                        match req {
+                            WorkloadRequest::Description(req) => {
+                                if let Some(handler) = workload_handler {
+                                    handler.abort();
+                                }
+
+                                workload_handler = Some(
+                                    WorkloadDescriptionWorker::new(
+                                        is_terminated.clone(),
+                                        tx,
+                                        kube_client.clone(),
+                                        req,
+                                    )
+                                    .spawn(),
+                                );
+                            }
+                            WorkloadRequest::OwnerTree(req) => {
+                                if let Some(handler) = owner_tree_handler {
+                                    handler.abort();
+                                }
+
+                                owner_tree_handler = Some(
+                                    OwnerTreeWorker::new(
+                                        is_terminated.clone(),
+                                        tx,
+                                        kube_client.clone(),
+                                        shared_api_resources.clone(),
+                                        shared_target_api_resources.clone(),
+                                        req,
+                                    )
+                                    .spawn(),
+                                );
+                            }
+                        }
+
+                        task::yield_now().await;
+                    }
                     _ => unreachable!(),
                 },
                 Ok(_) => unreachable!(),
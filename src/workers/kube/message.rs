@@ -2,10 +2,17 @@ use anyhow::Result;
 
 use crate::{
     features::{
-        api_resources::message::ApiMessage, config::message::ConfigMessage,
-        context::message::ContextMessage, get::message::GetMessage,
-        namespace::message::NamespaceMessage, network::message::NetworkMessage,
-        pod::message::LogMessage, yaml::message::YamlMessage,
+        api_resources::message::ApiMessage,
+        config::message::ConfigMessage,
+        context::message::ContextMessage,
+        describe::message::DescribeMessage,
+        edit::message::EditMessage,
+        get::message::GetMessage,
+        namespace::message::NamespaceMessage,
+        network::message::NetworkMessage,
+        pod::message::{LogMessage, PodDetailMessage},
+        workload::message::WorkloadMessage,
+        yaml::message::YamlMessage,
     },
     kube::table::KubeTable,
     message::Message,
@@ -22,14 +29,18 @@ pub enum Kube {
         context: String,
         namespaces: TargetNamespaces,
     },
-    Event(Result<Vec<String>>),
+    Event(Result<KubeTable>),
     Namespace(NamespaceMessage),
     Pod(Result<KubeTable>),
     Log(LogMessage),
+    PodDetail(PodDetailMessage),
     Config(ConfigMessage),
     Network(NetworkMessage),
     Yaml(YamlMessage),
     Get(GetMessage),
+    Workload(WorkloadMessage),
+    Edit(EditMessage),
+    Describe(DescribeMessage),
 }
 
 impl From<Kube> for Message {
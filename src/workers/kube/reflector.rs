@@ -0,0 +1,110 @@
+use std::{sync::atomic::Ordering, time::Duration};
+
+use crate::{kube::reflector::ReflectorSignal, logger, workers::interval::AdaptiveInterval};
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::{PollerBase, WorkerResult};
+
+/// 連続した変更通知をまとめるための待ち時間
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// watchで保持したローカルストアから一覧を作り、内容が変わったときだけ送信する
+///
+/// watchの権限がないときは従来どおり一覧APIのポーリングに切り替える
+#[async_trait]
+pub trait ReflectorPoller: Send + Sync {
+    type Stores: Send + Sync;
+    type Output: PartialEq + Clone + Send;
+
+    fn base(&self) -> &PollerBase;
+
+    /// 経過時間の表示を更新する間隔
+    /// ポーリングに切り替えたときの間隔を兼ねる
+    fn interval(&self) -> Duration;
+
+    fn spawn_stores(&self, namespaces: &[String], signal: &ReflectorSignal) -> Self::Stores;
+
+    fn render(&self, stores: &Self::Stores, namespaces: &[String]) -> Result<Self::Output>;
+
+    async fn poll(&self, namespaces: &[String]) -> Result<Self::Output>;
+
+    fn send(&self, output: Result<Self::Output>);
+
+    async fn run_reflector(&self) -> WorkerResult {
+        let PollerBase {
+            is_terminated,
+            shared_target_namespaces,
+            ..
+        } = self.base();
+
+        let mut interval = AdaptiveInterval::new(self.interval());
+
+        while !is_terminated.load(Ordering::Relaxed) {
+            let namespaces = shared_target_namespaces.read().await.to_vec();
+
+            let signal = ReflectorSignal::default();
+            let stores = self.spawn_stores(&namespaces, &signal);
+
+            let mut last_output: Option<Self::Output> = None;
+            let mut is_polling = false;
+
+            loop {
+                tokio::select! {
+                    _ = signal.changed(), if !is_polling => {
+                        tokio::time::sleep(DEBOUNCE).await;
+                    }
+                    _ = interval.tick() => {}
+                }
+
+                if is_terminated.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                if *shared_target_namespaces.read().await != namespaces {
+                    break;
+                }
+
+                if signal.is_forbidden() {
+                    if !is_polling {
+                        is_polling = true;
+                        logger!(warn, "fallback to polling. namespaces={:?}", namespaces);
+                    }
+
+                    let result = self.poll(&namespaces).await;
+                    interval.record(&result);
+
+                    self.send(result);
+                    continue;
+                }
+
+                // 初回の一覧取得やwatchに失敗したときは、ポーリングと同じくエラーを送る
+                if let Some(err) = signal.take_error() {
+                    let result = Err(err);
+                    interval.record(&result);
+
+                    self.send(result);
+
+                    // エラーを表示したあとは、内容が同じでも復旧したときに送り直す
+                    last_output = None;
+                }
+
+                if !signal.is_ready() {
+                    continue;
+                }
+
+                match self.render(&stores, &namespaces) {
+                    Ok(output) => {
+                        if last_output.as_ref() != Some(&output) {
+                            last_output = Some(output.clone());
+                            self.send(Ok(output));
+                        }
+                    }
+                    Err(err) => self.send(Err(err)),
+                }
+            }
+        }
+
+        WorkerResult::Terminated
+    }
+}
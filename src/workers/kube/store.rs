@@ -6,8 +6,9 @@ use kube::{
     config::{KubeConfigOptions, Kubeconfig},
     Client, Config,
 };
+use serde::{Deserialize, Serialize};
 
-use crate::kube::KubeClient;
+use crate::{kube::KubeClient, logger};
 
 use super::controller::{TargetApiResources, TargetNamespaces};
 
@@ -34,6 +35,54 @@ impl KubeState {
     }
 }
 
+/// セッションに保存するcontextの状態（クライアントは除く）
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedKubeState {
+    pub target_namespaces: TargetNamespaces,
+    pub target_api_resources: TargetApiResources,
+}
+
+/// コマンドライン引数で上書きする前と起動したときの状態
+///
+/// 起動してから変わっていない値は上書きする前の値に戻して、`-c`や`-n`で一時的に指定した値を保存しない
+#[derive(Debug, Clone)]
+pub struct SessionBaseline {
+    pub context: Context,
+    pub startup_context: Context,
+    pub states: BTreeMap<Context, SavedKubeState>,
+    pub startup_states: BTreeMap<Context, SavedKubeState>,
+}
+
+impl SessionBaseline {
+    /// 終了したときの状態から保存する状態を求める
+    pub fn resolve(
+        &self,
+        context: Context,
+        states: BTreeMap<Context, SavedKubeState>,
+    ) -> (Context, BTreeMap<Context, SavedKubeState>) {
+        let context = if context == self.startup_context {
+            self.context.to_string()
+        } else {
+            context
+        };
+
+        let states = states
+            .into_iter()
+            .map(|(context, state)| {
+                match (self.startup_states.get(&context), self.states.get(&context)) {
+                    (Some(startup), Some(baseline)) if startup == &state => {
+                        (context, baseline.clone())
+                    }
+                    _ => (context, state),
+                }
+            })
+            .collect();
+
+        (context, states)
+    }
+}
+
 #[derive(Debug)]
 #[cfg_attr(test, derive(PartialEq))]
 pub struct KubeStore {
@@ -132,6 +181,37 @@ impl KubeStore {
     pub fn insert(&mut self, context: Context, state: KubeState) {
         self.inner.insert(context, state);
     }
+
+    /// 保存した状態を復元する（kubeconfigにないcontextは読み飛ばす）
+    pub fn restore(&mut self, states: BTreeMap<Context, SavedKubeState>) {
+        for (context, saved) in states {
+            let Some(state) = self.inner.get_mut(&context) else {
+                logger!(warn, "context {} in session is not found", context);
+                continue;
+            };
+
+            if !saved.target_namespaces.is_empty() {
+                state.target_namespaces = saved.target_namespaces;
+            }
+
+            state.target_api_resources = saved.target_api_resources;
+        }
+    }
+
+    pub fn saved_states(&self) -> BTreeMap<Context, SavedKubeState> {
+        self.inner
+            .iter()
+            .map(|(context, state)| {
+                (
+                    context.to_string(),
+                    SavedKubeState {
+                        target_namespaces: state.target_namespaces.to_vec(),
+                        target_api_resources: state.target_api_resources.to_vec(),
+                    },
+                )
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -235,4 +315,104 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[tokio::test]
+    async fn kubeconfigにないcontextは復元しない() {
+        let kubeconfig = Kubeconfig::from_yaml(CONFIG).unwrap();
+
+        let mut store = KubeStore::try_from_kubeconfig(kubeconfig).await.unwrap();
+
+        store.restore(BTreeMap::from([
+            (
+                "cluster-1".to_string(),
+                SavedKubeState {
+                    target_namespaces: vec!["app".to_string(), "monitoring".to_string()],
+                    target_api_resources: Default::default(),
+                },
+            ),
+            (
+                "removed".to_string(),
+                SavedKubeState {
+                    target_namespaces: vec!["app".to_string()],
+                    target_api_resources: Default::default(),
+                },
+            ),
+        ]));
+
+        let actual = store.saved_states();
+
+        assert_eq!(
+            actual.keys().collect::<Vec<_>>(),
+            vec!["cluster-1", "cluster-2", "cluster-3"]
+        );
+        assert_eq!(
+            actual["cluster-1"].target_namespaces,
+            vec!["app".to_string(), "monitoring".to_string()]
+        );
+        assert_eq!(
+            actual["cluster-2"].target_namespaces,
+            vec!["ns-2".to_string()]
+        );
+    }
+
+    #[test]
+    fn 起動してから変わっていない値はコマンドライン引数で上書きする前の値を保存する() {
+        let state = |namespaces: &[&str]| SavedKubeState {
+            target_namespaces: namespaces.iter().map(ToString::to_string).collect(),
+            target_api_resources: Default::default(),
+        };
+
+        let baseline = SessionBaseline {
+            context: "cluster-1".into(),
+            startup_context: "cluster-2".into(),
+            states: BTreeMap::from([
+                ("cluster-1".into(), state(&["app"])),
+                ("cluster-2".into(), state(&["default"])),
+            ]),
+            startup_states: BTreeMap::from([
+                ("cluster-1".into(), state(&["app"])),
+                ("cluster-2".into(), state(&["tmp"])),
+            ]),
+        };
+
+        // -c cluster-2 -n tmp で起動して何も変えずに終了した
+        let actual = baseline.resolve(
+            "cluster-2".into(),
+            BTreeMap::from([
+                ("cluster-1".into(), state(&["app"])),
+                ("cluster-2".into(), state(&["tmp"])),
+            ]),
+        );
+
+        assert_eq!(
+            actual,
+            (
+                "cluster-1".to_string(),
+                BTreeMap::from([
+                    ("cluster-1".into(), state(&["app"])),
+                    ("cluster-2".into(), state(&["default"])),
+                ])
+            )
+        );
+
+        // 起動してから切り替えた値は保存する
+        let actual = baseline.resolve(
+            "cluster-1".into(),
+            BTreeMap::from([
+                ("cluster-1".into(), state(&["app", "monitoring"])),
+                ("cluster-2".into(), state(&["prod"])),
+            ]),
+        );
+
+        assert_eq!(
+            actual,
+            (
+                "cluster-1".to_string(),
+                BTreeMap::from([
+                    ("cluster-1".into(), state(&["app", "monitoring"])),
+                    ("cluster-2".into(), state(&["prod"])),
+                ])
+            )
+        );
+    }
 }
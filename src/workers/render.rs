@@ -12,20 +12,33 @@ use std::{
 };
 
 use anyhow::Result;
+use chrono::Utc;
 use crossbeam::channel::{Receiver, Sender};
 use ratatui::{backend::CrosstermBackend, layout::Direction, Terminal, TerminalOptions, Viewport};
 
 use crate::{
+    clipboard::Clipboard,
+    config::Config,
+    features::{
+        alert::{AlertManager, AlertRules},
+        api_resources::view::ListTables,
+        edit::{view::open_editor, EditSession},
+        pod::timeline::PodTimeline,
+        preset::Presets,
+        status::{view::update_error_history_popup, ErrorHistory},
+        yaml::diff::YamlDiff,
+    },
     kube::context::{Context, Namespace},
     logger,
     message::Message,
     panic_set_hook,
+    session::SharedSession,
     ui::WindowAction,
 };
 
 use self::{
     action::{update_contents, window_action},
-    window::WindowInit,
+    window::{save_session, SharedState, WindowInit},
 };
 
 pub struct Render {
@@ -33,6 +46,9 @@ pub struct Render {
     rx: Receiver<Message>,
     is_terminated: Arc<AtomicBool>,
     direction: Direction,
+    config: Config,
+    presets: Presets,
+    session: SharedSession,
 }
 
 impl Render {
@@ -41,9 +57,15 @@ impl Render {
         rx: Receiver<Message>,
         is_terminated: Arc<AtomicBool>,
         direction: Direction,
+        config: Config,
+        presets: Presets,
+        session: SharedSession,
     ) -> Self {
         Self {
             direction,
+            config,
+            presets,
+            session,
             tx,
             rx,
             is_terminated,
@@ -71,16 +93,33 @@ impl Render {
     }
 
     fn render(&self) -> Result<()> {
-        let namespace = Rc::new(RefCell::new(Namespace::new()));
-        let context = Rc::new(RefCell::new(Context::new()));
+        let clipboard = Some(Rc::new(RefCell::new(Clipboard::new())));
+
+        let state = SharedState {
+            context: Rc::new(RefCell::new(Context::new())),
+            namespaces: Rc::new(RefCell::new(Namespace::new())),
+            alerts: Rc::new(RefCell::new(AlertManager::new(
+                AlertRules::global().clone(),
+            ))),
+            errors: Rc::new(RefCell::new(ErrorHistory::default())),
+            pod_timeline: Rc::new(RefCell::new(PodTimeline::default())),
+            yaml_diff: Rc::new(RefCell::new(YamlDiff::default())),
+            yaml_popup_diff: Rc::new(RefCell::new(YamlDiff::default())),
+            edit: Rc::new(RefCell::new(EditSession::default())),
+            list_tables: ListTables::new(&self.tx, &clipboard),
+            clipboard,
+            presets: Rc::new(RefCell::new(self.presets.clone())),
+        };
+
+        let restored = self.session.lock().expect("Failed to lock session").clone();
 
         let mut window = WindowInit::new(
             self.direction,
             self.tx.clone(),
-            context.clone(),
-            namespace.clone(),
+            state.clone(),
+            self.config.clone(),
         )
-        .build();
+        .build(&restored);
 
         let mut terminal = Terminal::with_options(
             CrosstermBackend::new(io::stdout()),
@@ -104,16 +143,28 @@ impl Render {
                     // break
                 }
                 WindowAction::UpdateContents(ev) => {
-                    update_contents(
-                        &mut window,
-                        ev,
-                        &mut context.borrow_mut(),
-                        &mut namespace.borrow_mut(),
-                    );
+                    update_contents(&mut window, ev, &state);
+                }
+                WindowAction::Error(e) => {
+                    let mut errors = state.errors.borrow_mut();
+
+                    errors.push("kubetui", &e, Utc::now());
+
+                    update_error_history_popup(&mut window, &errors);
                 }
             }
+
+            // エディタから戻ったあとは端末の表示が崩れているため描画し直す
+            if open_editor(&mut window, &state.edit, &self.tx) {
+                terminal.clear()?;
+            }
         }
 
+        save_session(
+            &window,
+            &mut self.session.lock().expect("Failed to lock session"),
+        );
+
         Ok(())
     }
 }
@@ -1,43 +1,67 @@
-use std::collections::BTreeMap;
+use std::{cell::RefCell, collections::BTreeMap};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
 use crossbeam::channel::Receiver;
 
 use crate::{
     features::{
-        api_resources::message::{ApiMessage, ApiResponse},
+        alert::{view::update_alert_popup, Alert, AlertManager},
+        api_resources::{
+            kube::ApiResource,
+            message::{ApiMessage, ApiResponse},
+            view::clear_list,
+        },
         component_id::{
-            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, CONTEXT_POPUP_ID, EVENT_WIDGET_ID,
-            LIST_POPUP_ID, LIST_WIDGET_ID, MULTIPLE_NAMESPACES_POPUP_ID,
-            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID,
-            SINGLE_NAMESPACE_POPUP_ID, YAML_KIND_POPUP_ID, YAML_NAME_POPUP_ID,
-            YAML_NOT_FOUND_POPUP_ID, YAML_POPUP_ID, YAML_WIDGET_ID,
+            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, CONTEXT_POPUP_ID, DESCRIBE_POPUP_ID,
+            EVENT_WIDGET_ID, LIST_POPUP_ID, LIST_WIDGET_ID, MULTIPLE_NAMESPACES_POPUP_ID,
+            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID, OWNER_TREE_POPUP_ID,
+            POD_DETAIL_POPUP_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID, PRESET_POPUP_ID,
+            SINGLE_NAMESPACE_POPUP_ID, WORKLOAD_DESCRIPTION_WIDGET_ID, WORKLOAD_WIDGET_ID,
+            YAML_KIND_POPUP_ID, YAML_NAME_POPUP_ID, YAML_NOT_FOUND_POPUP_ID, YAML_POPUP_ID,
+            YAML_WIDGET_ID,
         },
         config::message::ConfigMessage,
         context::message::{ContextMessage, ContextResponse},
+        describe::message::{DescribeMessage, DescribeResponse},
+        edit::{message::EditMessage, view::update_edit_popup},
         get::message::{GetMessage, GetResponse},
         namespace::message::{NamespaceMessage, NamespaceResponse},
         network::message::{NetworkMessage, NetworkResponse},
-        pod::message::LogMessage,
-        yaml::message::{YamlMessage, YamlResourceListItem, YamlResponse},
-    },
-    kube::{
-        context::{Context, Namespace},
-        table::{KubeTable, KubeTableRow},
+        pod::{
+            message::{LogMessage, PodDetailMessage},
+            timeline::update_pod_widget,
+        },
+        status::{view::update_error_history_popup, ErrorHistory},
+        workload::message::{OwnerTreeNode, WorkloadMessage, WorkloadResponse},
+        yaml::{
+            diff::{update_yaml_widget, YamlDiff},
+            message::{YamlMessage, YamlResourceListItem, YamlResponse},
+            revision::YamlContent,
+            view::update_save_popup,
+        },
     },
+    kube::table::KubeTable,
     message::Message,
     ui::{
         event::{Callback, EventResult},
+        highlight::highlight_yaml,
+        theme::{paint, Theme},
         util::chars::convert_tabs_to_spaces,
-        widget::{Item, LiteralItem, TableItem, WidgetTrait},
+        widget::{Item, LiteralItem, TableItem, TreeItem, Widget, WidgetTrait},
         Window, WindowAction,
     },
     workers::kube::message::Kube,
 };
 
+use super::window::SharedState;
+
 macro_rules! error_format {
     ($fmt:literal, $($arg:tt)*) => {
-        format!(concat!("\x1b[31m[kubetui] ", $fmt,"\x1b[39m"), $($arg)*)
+        paint(
+            Theme::global().error,
+            format!(concat!("[kubetui] ", $fmt), $($arg)*),
+        )
     };
 }
 
@@ -87,105 +111,217 @@ pub fn window_action(window: &mut Window, rx: &Receiver<Message>) -> WindowActio
 
         Message::Tick => {}
         Message::Kube(k) => return WindowAction::UpdateContents(k),
-        Message::Error(_) => {}
+        Message::Error(e) => return WindowAction::Error(e),
     }
     WindowAction::Continue
 }
 
-fn update_widget_item_for_table(window: &mut Window, id: &str, table: Result<KubeTable>) {
-    let widget = window.find_widget_mut(id);
-    let w = widget.as_mut_table();
+/// エラーを履歴に残してステータスバーに表示する
+fn record_error(window: &mut Window, errors: &mut ErrorHistory, id: &str, err: &anyhow::Error) {
+    let source = window.find_widget(id).widget_config().title().to_string();
 
-    match table {
-        Ok(table) => {
-            if w.equal_header(table.header()) {
-                w.update_widget_item(Item::Table(
-                    table
-                        .rows
-                        .into_iter()
-                        .map(
-                            |KubeTableRow {
-                                 namespace,
-                                 name,
-                                 metadata,
-                                 row,
-                             }| {
-                                let mut item_metadata = BTreeMap::from([
-                                    ("namespace".to_string(), namespace),
-                                    ("name".to_string(), name),
-                                ]);
-
-                                if let Some(metadata) = metadata {
-                                    item_metadata.extend(metadata);
-                                }
-
-                                TableItem {
-                                    metadata: Some(item_metadata),
-                                    item: row,
-                                }
-                            },
-                        )
-                        .collect(),
-                ));
-            } else {
-                let rows: Vec<TableItem> = table
-                    .rows
-                    .into_iter()
-                    .map(
-                        |KubeTableRow {
-                             namespace,
-                             name,
-                             metadata,
-                             row,
-                         }| {
-                            let mut item_metadata = BTreeMap::from([
-                                ("namespace".to_string(), namespace),
-                                ("name".to_string(), name),
-                            ]);
-
-                            if let Some(metadata) = metadata {
-                                item_metadata.extend(metadata);
-                            }
-
-                            TableItem {
-                                metadata: Some(item_metadata),
-                                item: row,
-                            }
-                        },
-                    )
-                    .collect();
-
-                w.update_header_and_rows(&table.header, &rows);
-            }
-        }
+    errors.push(source, err, Utc::now());
+
+    update_error_history_popup(window, errors);
+}
+
+/// ポーリングに失敗しても表示中の内容は残し、まだ何も表示していないときだけエラーを表示する
+fn has_contents(widget: &Widget) -> bool {
+    match widget {
+        Widget::Text(w) => !w.is_empty(),
+        Widget::Table(w) => !w.original_items().is_empty(),
+        Widget::Tree(w) => !w.items().is_empty(),
+        _ => false,
+    }
+}
+
+fn update_widget_item_for_table(
+    window: &mut Window,
+    errors: &mut ErrorHistory,
+    id: &str,
+    table: Result<KubeTable>,
+) {
+    let table = match table {
+        Ok(table) => table,
         Err(e) => {
-            let rows: Vec<TableItem> = vec![vec![error_format!("{:?}", e)].into()];
-            w.update_header_and_rows(&["ERROR".to_string()], &rows);
+            record_error(window, errors, id, &e);
+
+            let widget = window.find_widget_mut(id);
+
+            if !has_contents(widget) {
+                let rows: Vec<TableItem> = vec![vec![error_format!("{:?}", e)].into()];
+                widget
+                    .as_mut_table()
+                    .update_header_and_rows(&["ERROR".to_string()], &rows);
+            }
+
+            return;
         }
+    };
+
+    let widget = window.find_widget_mut(id);
+    let w = widget.as_mut_table();
+
+    let rows: Vec<TableItem> = table.rows.into_iter().map(TableItem::from).collect();
+
+    if w.equal_header(&table.header) {
+        w.update_widget_item(Item::Table(rows));
+    } else {
+        w.update_header_and_rows(&table.header, &rows);
     }
 }
 
-fn update_widget_item_for_vec(window: &mut Window, id: &str, vec: Result<Vec<String>>) {
-    let widget = window.find_widget_mut(id);
+fn update_widget_item_for_vec(
+    window: &mut Window,
+    errors: &mut ErrorHistory,
+    id: &str,
+    vec: Result<Vec<String>>,
+) {
     match vec {
         Ok(i) => {
-            widget.update_widget_item(Item::Array(i.into_iter().map(LiteralItem::from).collect()));
+            window
+                .find_widget_mut(id)
+                .update_widget_item(Item::Array(i.into_iter().map(LiteralItem::from).collect()));
         }
         Err(e) => {
-            widget.update_widget_item(Item::Array(error_lines!(e)));
+            record_error(window, errors, id, &e);
+
+            let widget = window.find_widget_mut(id);
+
+            if !has_contents(widget) {
+                widget.update_widget_item(Item::Array(error_lines!(e)));
+            }
         }
     }
 }
 
-pub fn update_contents(
+/// ListタブとYAMLタブのAPIリソースの選択ポップアップの項目（優先バージョン以外は薄く表示する）
+fn api_resource_item(key: ApiResource) -> LiteralItem {
+    let Ok(json) = serde_json::to_string(&key) else {
+        unreachable!()
+    };
+
+    let metadata = BTreeMap::from([("key".into(), json)]);
+
+    let item = if key.is_api() || key.is_preferred_version() {
+        key.to_string()
+    } else {
+        paint(Theme::global().muted, key)
+    };
+
+    LiteralItem::new(item, Some(metadata))
+}
+
+/// 選択ポップアップの選択状態を指定したAPIリソースだけにする
+fn select_api_resources(window: &mut Window, list: Vec<ApiResource>) {
+    let w = window
+        .find_widget_mut(LIST_POPUP_ID)
+        .as_mut_multiple_select();
+
+    w.unselect_all();
+
+    for key in list {
+        w.select_item(&api_resource_item(key));
+    }
+}
+
+fn update_widget_item_for_yaml(
+    window: &mut Window,
+    errors: &mut ErrorHistory,
+    id: &str,
+    vec: Result<Vec<String>>,
+) {
+    update_widget_item_for_vec(window, errors, id, vec.map(|lines| highlight_yaml(&lines)));
+}
+
+/// 差分表示中は新しいリビジョンと比較し直す
+fn update_yaml_diff(
     window: &mut Window,
-    ev: Kube,
-    context: &mut Context,
-    namespace: &mut Namespace,
+    errors: &mut ErrorHistory,
+    id: &str,
+    diff: &RefCell<YamlDiff>,
+    content: Result<YamlContent>,
 ) {
+    match content {
+        Ok(content) => {
+            let mut diff = diff.borrow_mut();
+
+            diff.update(content);
+
+            update_yaml_widget(window, id, &diff);
+        }
+        Err(e) => update_widget_item_for_vec(window, errors, id, Err(e)),
+    }
+}
+
+fn owner_tree_item(node: OwnerTreeNode) -> TreeItem {
+    let OwnerTreeNode {
+        kind,
+        name,
+        status,
+        color,
+        is_target,
+        children,
+    } = node;
+
+    let mut item = format!("{}/{} ({})", kind, name, status);
+
+    if let Some(color) = color {
+        item = paint(color, item);
+    }
+
+    // 起点のリソースを強調表示する
+    if is_target {
+        item = format!("\x1b[1m{}\x1b[22m", item);
+    }
+
+    TreeItem {
+        item: LiteralItem {
+            metadata: Some(BTreeMap::from([
+                ("key".to_string(), format!("{}/{}", kind, name)),
+                ("kind".to_string(), kind),
+                ("name".to_string(), name),
+            ])),
+            item,
+        },
+        children: children.into_iter().map(owner_tree_item).collect(),
+    }
+}
+
+/// 新しい通知があれば一覧を更新して端末に通知する
+fn raise_alerts(window: &mut Window, alerts: &AlertManager, raised: Vec<Alert>) {
+    if raised.is_empty() {
+        return;
+    }
+
+    update_alert_popup(window, alerts);
+
+    alerts.notify_method().notify(&raised);
+}
+
+pub fn update_contents(window: &mut Window, ev: Kube, state: &SharedState) {
+    let mut context = state.context.borrow_mut();
+    let mut namespace = state.namespaces.borrow_mut();
+    let mut alerts = state.alerts.borrow_mut();
+    let mut errors = state.errors.borrow_mut();
+    let mut pod_timeline = state.pod_timeline.borrow_mut();
+
+    let errors = &mut *errors;
+
     match ev {
         Kube::Pod(pods_table) => {
-            update_widget_item_for_table(window, POD_WIDGET_ID, pods_table);
+            if let Ok(table) = &pods_table {
+                let raised = alerts.observe_pods(table.rows(), Utc::now());
+                raise_alerts(window, &alerts, raised);
+
+                pod_timeline.record(table, Utc::now());
+            }
+
+            // 巻き戻し中は表示中のスナップショットと新しい状態を比較し直す
+            match pods_table {
+                Ok(_) if !pod_timeline.is_live() => update_pod_widget(window, &pod_timeline),
+                _ => update_widget_item_for_table(window, errors, POD_WIDGET_ID, pods_table),
+            }
         }
 
         Kube::Log(LogMessage::Response(res)) => {
@@ -205,25 +341,36 @@ pub fn update_contents(
                 }
                 Err(e) => {
                     widget.append_widget_item(Item::Array(error_lines!(e)));
+
+                    record_error(window, errors, POD_LOG_WIDGET_ID, &e);
                 }
             }
         }
 
+        Kube::PodDetail(PodDetailMessage::Response(res)) => {
+            update_widget_item_for_yaml(window, errors, POD_DETAIL_POPUP_ID, res);
+        }
+
         Kube::Config(ConfigMessage::Response(res)) => {
             use crate::features::config::message::ConfigResponse::*;
 
             match res {
                 Table(list) => {
-                    update_widget_item_for_table(window, CONFIG_WIDGET_ID, list);
+                    update_widget_item_for_table(window, errors, CONFIG_WIDGET_ID, list);
                 }
                 Data(data) => {
-                    update_widget_item_for_vec(window, CONFIG_RAW_DATA_WIDGET_ID, data);
+                    update_widget_item_for_vec(window, errors, CONFIG_RAW_DATA_WIDGET_ID, data);
                 }
             }
         }
 
         Kube::Event(ev) => {
-            update_widget_item_for_vec(window, EVENT_WIDGET_ID, ev);
+            if let Ok(table) = &ev {
+                let raised = alerts.observe_events(table.rows(), Utc::now());
+                raise_alerts(window, &alerts, raised);
+            }
+
+            update_widget_item_for_table(window, errors, EVENT_WIDGET_ID, ev);
         }
 
         Kube::Namespace(NamespaceMessage::Response(res)) => match res {
@@ -241,6 +388,8 @@ pub fn update_contents(
                         ));
                 }
                 Err(err) => {
+                    record_error(window, errors, SINGLE_NAMESPACE_POPUP_ID, &err);
+
                     let err = error_lines!(err);
                     window
                         .find_widget_mut(MULTIPLE_NAMESPACES_POPUP_ID)
@@ -253,12 +402,14 @@ pub fn update_contents(
             },
             NamespaceResponse::Set(res) => {
                 namespace.update(res);
+                alerts.reset();
+                pod_timeline.reset();
             }
         },
 
         Kube::Context(ContextMessage::Response(res)) => match res {
             ContextResponse::Get(res) => {
-                update_widget_item_for_vec(window, CONTEXT_POPUP_ID, Ok(res));
+                update_widget_item_for_vec(window, errors, CONTEXT_POPUP_ID, Ok(res));
             }
         },
 
@@ -268,6 +419,8 @@ pub fn update_contents(
         } => {
             context.update(ctx);
             namespace.update(ns.clone());
+            alerts.reset();
+            pod_timeline.reset();
 
             window
                 .find_widget_mut(MULTIPLE_NAMESPACES_POPUP_ID)
@@ -281,27 +434,7 @@ pub fn update_contents(
         }
 
         Kube::RestoreAPIs(list) => {
-            let w = window
-                .find_widget_mut(LIST_POPUP_ID)
-                .as_mut_multiple_select();
-
-            for key in list {
-                let Ok(json) = serde_json::to_string(&key) else {
-                    unreachable!()
-                };
-
-                let metadata = BTreeMap::from([("key".into(), json)]);
-
-                let item = if key.is_api() || key.is_preferred_version() {
-                    key.to_string()
-                } else {
-                    format!("\x1b[90m{}\x1b[39m", key)
-                };
-
-                let literal_item = LiteralItem::new(item, Some(metadata));
-
-                w.select_item(&literal_item);
-            }
+            select_api_resources(window, list);
         }
 
         Kube::Api(ApiMessage::Response(res)) => {
@@ -311,33 +444,36 @@ pub fn update_contents(
                     let widget = window.find_widget_mut(LIST_POPUP_ID);
                     match list {
                         Ok(i) => {
-                            let items = i
-                                .into_iter()
-                                .map(|key| {
-                                    let Ok(json) = serde_json::to_string(&key) else {
-                                        unreachable!()
-                                    };
-                                    let metadata = BTreeMap::from([("key".into(), json)]);
-
-                                    let item = if key.is_api() || key.is_preferred_version() {
-                                        key.to_string()
-                                    } else {
-                                        format!("\x1b[90m{}\x1b[39m", key)
-                                    };
-
-                                    LiteralItem::new(item, Some(metadata))
-                                })
-                                .collect();
+                            let items = i.into_iter().map(api_resource_item).collect();
 
                             widget.update_widget_item(Item::Array(items));
                         }
                         Err(e) => {
                             widget.update_widget_item(Item::Array(error_lines!(e)));
+
+                            record_error(window, errors, LIST_POPUP_ID, &e);
                         }
                     }
                 }
-                Poll(list) => {
-                    update_widget_item_for_vec(window, LIST_WIDGET_ID, list);
+                Poll(Ok(tables)) => {
+                    state.list_tables.update(window, tables);
+                }
+                Poll(Err(e)) => {
+                    update_widget_item_for_table(window, errors, LIST_WIDGET_ID, Err(e));
+                }
+                SetByKeys {
+                    api_resources,
+                    missing,
+                } => {
+                    select_api_resources(window, api_resources);
+
+                    clear_list(window);
+
+                    if !missing.is_empty() {
+                        let err = anyhow!("API resources not found: {}", missing.join(", "));
+
+                        record_error(window, errors, PRESET_POPUP_ID, &err);
+                    }
                 }
             }
         }
@@ -349,29 +485,14 @@ pub fn update_contents(
                     let widget = window.find_widget_mut(YAML_KIND_POPUP_ID);
                     match res {
                         Ok(vec) => {
-                            let items = vec
-                                .into_iter()
-                                .map(|key| {
-                                    let Ok(json) = serde_json::to_string(&key) else {
-                                        unreachable!()
-                                    };
-
-                                    let metadata = BTreeMap::from([("key".into(), json)]);
-
-                                    let item = if key.is_api() || key.is_preferred_version() {
-                                        key.to_string()
-                                    } else {
-                                        format!("\x1b[90m{}\x1b[39m", key)
-                                    };
-
-                                    LiteralItem::new(item, Some(metadata))
-                                })
-                                .collect();
+                            let items = vec.into_iter().map(api_resource_item).collect();
 
                             widget.update_widget_item(Item::Array(items));
                         }
                         Err(e) => {
                             widget.update_widget_item(Item::Array(error_lines!(e)));
+
+                            record_error(window, errors, YAML_KIND_POPUP_ID, &e);
                         }
                     }
                 }
@@ -419,32 +540,82 @@ pub fn update_contents(
                     Err(e) => {
                         let widget = window.find_widget_mut(YAML_NAME_POPUP_ID);
                         widget.update_widget_item(Item::Array(error_lines!(e)));
+
+                        record_error(window, errors, YAML_NAME_POPUP_ID, &e);
                     }
                 },
                 Yaml(res) => {
-                    update_widget_item_for_vec(window, YAML_WIDGET_ID, res);
+                    update_yaml_diff(window, errors, YAML_WIDGET_ID, &state.yaml_diff, res);
+                }
+                Saved(res) => {
+                    update_save_popup(window, res);
                 }
             }
         }
 
+        Kube::Edit(EditMessage::Response(res)) => {
+            update_edit_popup(window, &state.edit, res);
+        }
+
+        Kube::Describe(DescribeMessage::Response(DescribeResponse { kind, name, lines })) => {
+            let widget = window
+                .find_widget_mut(DESCRIBE_POPUP_ID)
+                .widget_config_mut();
+            *(widget.append_title_mut()) = Some(format!(" : {}/{}", kind, name).into());
+
+            update_widget_item_for_vec(window, errors, DESCRIBE_POPUP_ID, lines);
+        }
+
         Kube::Get(GetMessage::Response(GetResponse { kind, name, yaml })) => {
             let widget = window.find_widget_mut(YAML_POPUP_ID).widget_config_mut();
             *(widget.append_title_mut()) = Some(format!(" : {}/{}", kind, name).into());
 
-            update_widget_item_for_vec(window, YAML_POPUP_ID, yaml);
+            update_yaml_diff(window, errors, YAML_POPUP_ID, &state.yaml_popup_diff, yaml);
         }
 
         Kube::Network(NetworkMessage::Response(ev)) => {
             use NetworkResponse::*;
 
             match ev {
-                List(res) => update_widget_item_for_table(window, NETWORK_WIDGET_ID, res),
+                List(res) => update_widget_item_for_table(window, errors, NETWORK_WIDGET_ID, res),
                 Yaml(res) => {
-                    update_widget_item_for_vec(window, NETWORK_DESCRIPTION_WIDGET_ID, res);
+                    update_widget_item_for_yaml(window, errors, NETWORK_DESCRIPTION_WIDGET_ID, res);
                 }
             }
         }
 
+        Kube::Workload(WorkloadMessage::Response(ev)) => match ev {
+            WorkloadResponse::List(res) => {
+                update_widget_item_for_table(window, errors, WORKLOAD_WIDGET_ID, res)
+            }
+            WorkloadResponse::Description(res) => {
+                update_widget_item_for_yaml(window, errors, WORKLOAD_DESCRIPTION_WIDGET_ID, res);
+            }
+            WorkloadResponse::OwnerTree(res) => match res {
+                Ok(nodes) => {
+                    window
+                        .find_widget_mut(OWNER_TREE_POPUP_ID)
+                        .update_widget_item(Item::Tree(
+                            nodes.into_iter().map(owner_tree_item).collect(),
+                        ));
+                }
+                Err(e) => {
+                    record_error(window, errors, OWNER_TREE_POPUP_ID, &e);
+
+                    let widget = window.find_widget_mut(OWNER_TREE_POPUP_ID);
+
+                    if !has_contents(widget) {
+                        widget.update_widget_item(Item::Tree(
+                            error_lines!(e)
+                                .into_iter()
+                                .map(|item| TreeItem::new(item, vec![]))
+                                .collect(),
+                        ));
+                    }
+                }
+            },
+        },
+
         _ => unreachable!(),
     }
 }
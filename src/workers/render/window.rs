@@ -1,89 +1,122 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crossbeam::channel::Sender;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{layout::Direction, text::Line, widgets::Paragraph};
 
 use crate::{
     clipboard::Clipboard,
+    config::{Action, Config, InitialTab},
     features::{
-        api_resources::view::ListTab,
+        alert::{
+            view::{alert_toast, AlertPopup},
+            AlertManager,
+        },
+        api_resources::view::{ListTab, ListTables},
         component_id::{
-            CONFIG_WIDGET_ID, CONTEXT_POPUP_ID, HELP_POPUP_ID, MULTIPLE_NAMESPACES_POPUP_ID,
-            NETWORK_WIDGET_ID, POD_WIDGET_ID, SINGLE_NAMESPACE_POPUP_ID, YAML_POPUP_ID,
+            ALERT_POPUP_ID, CONTEXT_POPUP_ID, DESCRIBE_POPUP_ID, ERROR_HISTORY_POPUP_ID,
+            HELP_POPUP_ID, MULTIPLE_NAMESPACES_POPUP_ID, OWNER_TREE_POPUP_ID,
+            POD_LOG_QUERY_WIDGET_ID, POD_WIDGET_ID, PRESET_POPUP_ID, SINGLE_NAMESPACE_POPUP_ID,
+            YAML_POPUP_ID,
         },
         config::view::ConfigTab,
         context::{message::ContextRequest, view::ContextPopup},
+        describe::{message::DescribeMessage, view::DescribePopup},
+        edit::{view::EditPopup, EditSession},
         event::view::EventTab,
-        get::{
-            message::{GetRequest, GetYamlKind},
-            view::YamlPopup,
-        },
+        get::{message::GetRequest, view::YamlPopup},
         help::HelpPopup,
         namespace::{
             message::NamespaceRequest,
             view::{MultipleNamespacesPopup, SingleNamespacePopup},
         },
         network::view::NetworkTab,
-        pod::view::PodTab,
-        yaml::view::YamlTab,
+        pod::{timeline::PodTimeline, view::PodTab},
+        preset::{view::PresetPopup, Presets},
+        status::{
+            view::{status_bar, ErrorHistoryPopup},
+            ErrorHistory,
+        },
+        workload::{
+            message::OwnerTreeTarget,
+            view::{OwnerTreePopup, WorkloadTab},
+        },
+        yaml::{
+            diff::YamlDiff,
+            view::{JsonPathPopup, SavePopup, YamlTab},
+        },
     },
     kube::context::{Context, Namespace},
-    message::{Message, UserEvent},
+    message::Message,
+    session::Session,
     ui::{
-        event::{CallbackFn, EventResult},
+        event::{Callback, CallbackFn, EventResult},
         popup::Popup,
-        widget::{SelectedItem, WidgetTrait},
+        widget::{Item, SelectedItem, WidgetTrait},
         Header, Tab, Window, WindowAction,
     },
 };
 
+/// ウィンドウのコールバックと受信したコンテンツの反映で共有する状態
+#[derive(Clone)]
+pub struct SharedState {
+    pub context: Rc<RefCell<Context>>,
+    pub namespaces: Rc<RefCell<Namespace>>,
+    pub alerts: Rc<RefCell<AlertManager>>,
+    pub errors: Rc<RefCell<ErrorHistory>>,
+    pub pod_timeline: Rc<RefCell<PodTimeline>>,
+    pub yaml_diff: Rc<RefCell<YamlDiff>>,
+    pub yaml_popup_diff: Rc<RefCell<YamlDiff>>,
+    pub edit: Rc<RefCell<EditSession>>,
+    pub list_tables: ListTables,
+    pub presets: Rc<RefCell<Presets>>,
+    pub clipboard: Option<Rc<RefCell<Clipboard>>>,
+}
+
 pub struct WindowInit {
     split_mode: Direction,
     tx: Sender<Message>,
-    context: Rc<RefCell<Context>>,
-    namespaces: Rc<RefCell<Namespace>>,
+    state: SharedState,
+    config: Config,
 }
 
 impl WindowInit {
     pub fn new(
         split_mode: Direction,
         tx: Sender<Message>,
-        context: Rc<RefCell<Context>>,
-        namespaces: Rc<RefCell<Namespace>>,
+        state: SharedState,
+        config: Config,
     ) -> Self {
         Self {
             split_mode,
             tx,
-            context,
-            namespaces,
+            state,
+            config,
         }
     }
 
-    pub fn build(self) -> Window<'static> {
+    pub fn build(self, session: &Session) -> Window<'static> {
         let (tabs, popups) = self.tabs_popups();
 
         let builder = Window::builder().tabs(tabs).popup(popups);
 
         // Configure Action
+        let keybindings = &self.config.keybindings;
+
         let tx = self.tx.clone();
-        let builder = builder.action(
-            UserEvent::Key(KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT)),
-            move |w: &mut Window| {
-                tx.send(NamespaceRequest::Get.into())
-                    .expect("Failed to send NamespaceRequest::Get");
-                w.open_popup(MULTIPLE_NAMESPACES_POPUP_ID);
-                EventResult::Nop
-            },
-        );
+        let open_multiple_namespaces = move |w: &mut Window| {
+            tx.send(NamespaceRequest::Get.into())
+                .expect("Failed to send NamespaceRequest::Get");
+            w.open_popup(MULTIPLE_NAMESPACES_POPUP_ID);
+            EventResult::Nop
+        };
 
         let tx = self.tx.clone();
-        let builder = builder.action('n', move |w: &mut Window| {
+        let open_single_namespace = move |w: &mut Window| {
             tx.send(NamespaceRequest::Get.into())
                 .expect("Failed to send NamespaceRequest::Get");
             w.open_popup(SINGLE_NAMESPACE_POPUP_ID);
             EventResult::Nop
-        });
+        };
 
         let fn_close = |w: &mut Window| {
             if w.opening_popup() {
@@ -95,27 +128,66 @@ impl WindowInit {
         };
 
         let tx = self.tx.clone();
-        let builder = builder.action('c', move |w: &mut Window| {
+        let open_context = move |w: &mut Window| {
             tx.send(ContextRequest::Get.into())
                 .expect("Failed to send ContextRequest::Get");
             w.open_popup(CONTEXT_POPUP_ID);
             EventResult::Nop
-        });
+        };
 
         let open_help = move |w: &mut Window| {
             w.open_popup(HELP_POPUP_ID);
             EventResult::Nop
         };
 
+        let open_alerts = move |w: &mut Window| {
+            w.open_popup(ALERT_POPUP_ID);
+            EventResult::Nop
+        };
+
+        let open_errors = move |w: &mut Window| {
+            w.open_popup(ERROR_HISTORY_POPUP_ID);
+            EventResult::Nop
+        };
+
+        let open_presets = move |w: &mut Window| {
+            w.open_popup(PRESET_POPUP_ID);
+            EventResult::Nop
+        };
+
         let open_yaml = open_yaml(self.tx.clone());
 
-        let builder = builder.action('h', open_help).action('?', open_help);
-        let builder = builder.action('y', open_yaml);
+        let open_describe = open_describe(self.tx.clone());
+
+        let open_owner_tree = open_owner_tree(self.tx.clone());
+
+        let actions: [(Action, Callback); 11] = [
+            (Action::MultipleNamespaces, open_multiple_namespaces.into()),
+            (Action::Namespace, open_single_namespace.into()),
+            (Action::Context, open_context.into()),
+            (Action::Help, open_help.into()),
+            (Action::Yaml, open_yaml.into()),
+            (Action::Describe, open_describe.into()),
+            (Action::OwnerTree, open_owner_tree.into()),
+            (Action::Alerts, open_alerts.into()),
+            (Action::Errors, open_errors.into()),
+            (Action::Presets, open_presets.into()),
+            (Action::Quit, fn_close.into()),
+        ];
 
-        let builder = builder.action('q', fn_close).action(KeyCode::Esc, fn_close);
+        let builder = actions
+            .into_iter()
+            .fold(builder, |builder, (action, callback)| {
+                keybindings
+                    .keys(action)
+                    .into_iter()
+                    .fold(builder, |builder, key| {
+                        builder.action(key, callback.clone())
+                    })
+            });
 
-        let context = self.context.clone();
-        let namespaces = self.namespaces.clone();
+        let context = self.state.context.clone();
+        let namespaces = self.state.namespaces.clone();
 
         let header = Header::new_callback(2, move || {
             let context = context.borrow();
@@ -126,23 +198,41 @@ impl WindowInit {
             ])
         });
 
-        let builder = builder.header(header);
+        let builder = builder
+            .header(header)
+            .toast(alert_toast(self.state.alerts.clone()))
+            .status_bar(status_bar(self.state.errors.clone()));
 
-        builder.build()
+        let mut window = builder.build();
+
+        if let Some(initial_tab) = session.tab.or(self.config.initial_tab) {
+            window.activate_tab_by_id(initial_tab.tab_id());
+        }
+
+        if let Some(query) = &session.log_query {
+            window
+                .find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+                .update_widget_item(Item::Single(query.to_string().into()));
+        }
+
+        window
     }
 
     fn tabs_popups(&self) -> (Vec<Tab<'static>>, Vec<Popup<'static>>) {
-        let clipboard = Some(Rc::new(RefCell::new(Clipboard::new())));
+        let clipboard = self.state.clipboard.clone();
 
         let PodTab {
             tab: pod_tab,
             log_query_help_popup,
+            pod_detail_popup,
         } = PodTab::new(
             "Pod",
             &self.tx,
             &clipboard,
             self.split_mode,
-            self.namespaces.clone(),
+            self.state.namespaces.clone(),
+            self.config.log.prefix_type,
+            self.state.pod_timeline.clone(),
         );
 
         let ConfigTab { tab: config_tab } =
@@ -151,19 +241,45 @@ impl WindowInit {
         let NetworkTab { tab: network_tab } =
             NetworkTab::new("Network", &self.tx, &clipboard, self.split_mode);
 
-        let EventTab { tab: event_tab } = EventTab::new("Event", &clipboard);
+        let EventTab { tab: event_tab } = EventTab::new("Event", &self.tx);
+
+        let WorkloadTab { tab: workload_tab } =
+            WorkloadTab::new("Workload", &self.tx, &clipboard, self.split_mode);
 
         let ListTab {
             tab: list_tab,
             popup: list_popup,
-        } = ListTab::new("List", &self.tx, &clipboard);
+        } = ListTab::new("List", &self.tx, &self.state.list_tables);
+
+        let JsonPathPopup {
+            popup: yaml_json_path_popup,
+            opener: json_path_opener,
+        } = JsonPathPopup::new();
+
+        let SavePopup {
+            popup: yaml_save_popup,
+            opener: save_opener,
+        } = SavePopup::new(&self.tx);
+
+        let EditPopup {
+            popup: edit_popup,
+            opener: edit_opener,
+        } = EditPopup::new(&self.tx, &self.state.edit);
 
         let YamlTab {
             tab: yaml_tab,
             kind_popup: yaml_kind_popup,
             name_popup: yaml_name_popup,
             not_found_popup: yaml_not_found_popup,
-        } = YamlTab::new("Yaml", &self.tx, &clipboard);
+        } = YamlTab::new(
+            "Yaml",
+            &self.tx,
+            &clipboard,
+            self.state.yaml_diff.clone(),
+            &json_path_opener,
+            &save_opener,
+            &edit_opener,
+        );
 
         let ContextPopup {
             popup: context_popup,
@@ -177,9 +293,34 @@ impl WindowInit {
             popup: multiple_namespaces_popup,
         } = MultipleNamespacesPopup::new(&self.tx);
 
+        let PresetPopup {
+            popup: preset_popup,
+            save_popup: preset_save_popup,
+        } = PresetPopup::new(&self.tx, &self.state.presets, &self.state.namespaces);
+
         let HelpPopup { popup: help_popup } = HelpPopup::new();
 
-        let YamlPopup { popup: yaml_popup } = YamlPopup::new(&clipboard);
+        let YamlPopup { popup: yaml_popup } = YamlPopup::new(
+            &clipboard,
+            self.state.yaml_popup_diff.clone(),
+            &json_path_opener,
+            &save_opener,
+            &edit_opener,
+        );
+
+        let DescribePopup {
+            popup: describe_popup,
+        } = DescribePopup::new(&clipboard);
+
+        let OwnerTreePopup {
+            popup: owner_tree_popup,
+        } = OwnerTreePopup::new();
+
+        let AlertPopup { popup: alert_popup } = AlertPopup::new(&self.state.alerts);
+
+        let ErrorHistoryPopup {
+            popup: error_history_popup,
+        } = ErrorHistoryPopup::new(&self.state.errors);
 
         // Init Window
         let tabs = vec![
@@ -189,6 +330,7 @@ impl WindowInit {
             event_tab,
             list_tab,
             yaml_tab,
+            workload_tab,
         ];
 
         let popups = vec![
@@ -196,78 +338,130 @@ impl WindowInit {
             Popup::new(single_namespace_popup),
             Popup::new(multiple_namespaces_popup),
             Popup::new(list_popup),
+            Popup::new(preset_popup),
+            Popup::with_height(preset_save_popup, 3),
             Popup::new(yaml_kind_popup),
             Popup::new(yaml_name_popup),
             Popup::new(yaml_not_found_popup),
+            Popup::with_height(yaml_json_path_popup, 3),
+            Popup::with_height(yaml_save_popup, 3),
             Popup::new(help_popup),
             Popup::new(log_query_help_popup),
+            Popup::new(pod_detail_popup),
             Popup::new(yaml_popup),
+            Popup::new(describe_popup),
+            Popup::new(owner_tree_popup),
+            Popup::new(edit_popup),
+            Popup::new(alert_popup),
+            Popup::new(error_history_popup),
         ];
 
         (tabs, popups)
     }
 }
 
-fn open_yaml(tx: Sender<Message>) -> impl CallbackFn {
-    move |w: &mut Window| {
-        let widget = w.active_tab().active_widget();
+/// 終了するときのタブとログのクエリをセッションに書き込む
+pub fn save_session(window: &Window, session: &mut Session) {
+    session.tab = InitialTab::from_tab_id(window.active_tab_id());
 
-        match widget.id() {
-            POD_WIDGET_ID | CONFIG_WIDGET_ID | NETWORK_WIDGET_ID => {}
-            _ => {
-                return EventResult::Ignore;
-            }
-        }
+    session.log_query = match window.find_widget(POD_LOG_QUERY_WIDGET_ID).widget_item() {
+        Some(SelectedItem::Literal { item, .. }) => Some(item),
+        _ => None,
+    };
+}
 
-        let Some(SelectedItem::TableRow { metadata, .. }) = widget.widget_item() else {
-            return EventResult::Ignore;
-        };
+/// 選択した行のリソース
+///
+/// Podタブ以外はメタデータのkindとapiVersionをAPIリソースから解決するため、カスタムリソースも対象になる
+fn selected_resource(w: &mut Window) -> Option<GetRequest> {
+    let widget = w.active_tab().active_widget();
+
+    let Some(SelectedItem::TableRow {
+        metadata: Some(metadata),
+        ..
+    }) = widget.widget_item()
+    else {
+        return None;
+    };
+
+    let (kind, api_version) = match widget.id() {
+        POD_WIDGET_ID => ("Pod", Some("v1".to_string())),
+        _ => (
+            metadata.get("kind")?.as_str(),
+            metadata.get("api_version").cloned(),
+        ),
+    };
+
+    Some(GetRequest {
+        name: metadata.get("name")?.to_string(),
+        namespace: metadata.get("namespace").cloned(),
+        kind: kind.to_string(),
+        api_version,
+    })
+}
 
-        let Some(ref metadata) = metadata else {
+/// 選択した行のリソースのyamlをポップアップで表示する
+fn open_yaml(tx: Sender<Message>) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let Some(req) = selected_resource(w) else {
             return EventResult::Ignore;
         };
 
-        let Some(ref namespace) = metadata.get("namespace") else {
-            return EventResult::Ignore;
-        };
+        tx.send(req.into())
+            .expect("Failed to send YamlMessage::Request");
+
+        w.widget_clear(YAML_POPUP_ID);
+        w.open_popup(YAML_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
 
-        let Some(ref name) = metadata.get("name") else {
+/// 選択した行のリソースを`kubectl describe`のようにポップアップで表示する
+fn open_describe(tx: Sender<Message>) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let Some(req) = selected_resource(w) else {
             return EventResult::Ignore;
         };
 
-        let kind = match widget.id() {
-            POD_WIDGET_ID => GetYamlKind::Pod,
-            CONFIG_WIDGET_ID => match metadata.get("kind").map(|v| v.as_str()) {
-                Some("ConfigMap") => GetYamlKind::ConfigMap,
-                Some("Secret") => GetYamlKind::Secret,
-                _ => {
-                    return EventResult::Ignore;
-                }
-            },
-            NETWORK_WIDGET_ID => match metadata.get("kind").map(|v| v.as_str()) {
-                Some("Ingress") => GetYamlKind::Ingress,
-                Some("Service") => GetYamlKind::Service,
-                Some("Pod") => GetYamlKind::Pod,
-                Some("NetworkPolicy") => GetYamlKind::NetworkPolicy,
-                _ => {
-                    return EventResult::Ignore;
-                }
-            },
-            _ => return EventResult::Ignore,
+        tx.send(DescribeMessage::Request(req).into())
+            .expect("Failed to send DescribeMessage::Request");
+
+        w.widget_clear(DESCRIBE_POPUP_ID);
+        w.open_popup(DESCRIBE_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// 選択した行のリソースからownerReferencesを辿ったツリーをポップアップで表示する
+///
+/// ownerReferencesは同じnamespaceのリソースを指すため、namespaceがない行は対象外にする
+fn open_owner_tree(tx: Sender<Message>) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let Some(GetRequest {
+            name,
+            namespace: Some(namespace),
+            kind,
+            api_version,
+        }) = selected_resource(w)
+        else {
+            return EventResult::Ignore;
         };
 
         tx.send(
-            GetRequest {
-                name: name.to_string(),
-                namespace: namespace.to_string(),
+            OwnerTreeTarget {
                 kind,
+                api_version,
+                name,
+                namespace,
             }
             .into(),
         )
-        .expect("Failed to send YamlMessage::Request");
+        .expect("Failed to send WorkloadRequest::OwnerTree");
 
-        w.widget_clear(YAML_POPUP_ID);
-        w.open_popup(YAML_POPUP_ID);
+        w.widget_clear(OWNER_TREE_POPUP_ID);
+        w.open_popup(OWNER_TREE_POPUP_ID);
 
         EventResult::Nop
     }
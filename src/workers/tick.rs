@@ -8,6 +8,8 @@ use std::{
 
 use crate::{logger, message::Message, panic_set_hook};
 
+use super::interval::current_period;
+
 use anyhow::Result;
 use crossbeam::channel::Sender;
 use tokio::time;
@@ -49,7 +51,7 @@ impl Tick {
 
     fn tick(&self) -> Result<()> {
         while !self.is_terminated.load(Ordering::Relaxed) {
-            sleep(self.duration);
+            sleep(current_period(self.duration));
 
             self.tx.send(Message::Tick)?;
         }
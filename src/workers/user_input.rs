@@ -1,8 +1,9 @@
 use std::{
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        Arc, Mutex,
     },
+    thread,
     time::Duration,
 };
 
@@ -11,11 +12,43 @@ use crossbeam::channel::Sender;
 use crossterm::event::{poll, read, Event as CEvent, KeyEvent, KeyEventKind};
 
 use crate::{
-    logger,
+    disable_raw_mode, enable_raw_mode, logger,
     message::{Message, UserEvent},
     panic_set_hook,
 };
 
+use super::interval::set_focused;
+
+/// 端末を明け渡している間は入力を読まない
+static PAUSED: AtomicBool = AtomicBool::new(false);
+
+/// 入力を待っている間だけ保持する（明け渡すときは待ち終わるまで待つ）
+static POLLING: Mutex<()> = Mutex::new(());
+
+/// 入力を待つ間隔（端末を明け渡すまでの最大の待ち時間）
+const POLL_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// TUIを一時停止して端末を外部のプロセス（エディタなど）に明け渡す
+///
+/// 入力のスレッドがキー入力を横取りしないよう、入力を待ち終わってから端末の設定を戻す
+pub fn suspend_terminal<T>(f: impl FnOnce() -> T) -> T {
+    PAUSED.store(true, Ordering::Relaxed);
+
+    let guard = POLLING.lock().expect("Failed to lock POLLING");
+
+    disable_raw_mode!();
+
+    let ret = f();
+
+    enable_raw_mode!();
+
+    drop(guard);
+
+    PAUSED.store(false, Ordering::Relaxed);
+
+    ret
+}
+
 /// ユーザー入力を受け付けるワーカースレッドを生成する構造体
 /// イベントデータはチャネルを介してメインスレッドに送信される
 pub struct UserInput {
@@ -50,27 +83,44 @@ impl UserInput {
 
     fn poll(&self) -> Result<()> {
         while !self.is_terminated.load(Ordering::Relaxed) {
-            if let Ok(true) = poll(Duration::from_secs(1)) {
-                let ev = read()?;
-
-                logger!(debug, "{:?}", ev);
-
-                match ev {
-                    CEvent::Key(ev) => {
-                        if let KeyEvent {
-                            kind: KeyEventKind::Press | KeyEventKind::Repeat,
-                            ..
-                        } = ev
-                        {
-                            self.tx.send(Message::User(UserEvent::Key(ev)))?
-                        }
+            if PAUSED.load(Ordering::Relaxed) {
+                thread::sleep(POLL_TIMEOUT);
+                continue;
+            }
+
+            // 送信する間は保持しない（チャネルが詰まったときに描画スレッドがロックを待って止まるため）
+            let ev = {
+                let _guard = POLLING.lock().expect("Failed to lock POLLING");
+
+                match poll(POLL_TIMEOUT) {
+                    Ok(true) => read()?,
+                    _ => continue,
+                }
+            };
+
+            logger!(debug, "{:?}", ev);
+
+            match ev {
+                CEvent::Key(ev) => {
+                    if let KeyEvent {
+                        kind: KeyEventKind::Press | KeyEventKind::Repeat,
+                        ..
+                    } = ev
+                    {
+                        self.tx.send(Message::User(UserEvent::Key(ev)))?
                     }
-                    CEvent::Mouse(ev) => self.tx.send(Message::User(UserEvent::Mouse(ev)))?,
-                    CEvent::Resize(..) => {}
-                    CEvent::FocusGained => self.tx.send(UserEvent::FocusGained.into())?,
-                    CEvent::FocusLost => self.tx.send(UserEvent::FocusLost.into())?,
-                    CEvent::Paste(_) => {}
                 }
+                CEvent::Mouse(ev) => self.tx.send(Message::User(UserEvent::Mouse(ev)))?,
+                CEvent::Resize(..) => {}
+                CEvent::FocusGained => {
+                    set_focused(true);
+                    self.tx.send(UserEvent::FocusGained.into())?
+                }
+                CEvent::FocusLost => {
+                    set_focused(false);
+                    self.tx.send(UserEvent::FocusLost.into())?
+                }
+                CEvent::Paste(_) => {}
             }
         }
 